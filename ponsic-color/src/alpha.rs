@@ -0,0 +1,222 @@
+use crate::Color;
+
+/// 以 255 为满值的两个分量相乘，结果四舍五入
+const fn mul(a: u8, b: u8) -> u8 {
+    let t = a as u32 * b as u32 + 128;
+    ((t + (t >> 8)) >> 8) as u8
+}
+
+/// 将预乘的分量除以透明度，结果四舍五入
+const fn div(c: u8, alpha: u8) -> u8 {
+    let (c, a) = (c as u32, alpha as u32);
+    let v = (c * 255 + a / 2) / a;
+    if v > 255 { 255 } else { v as u8 }
+}
+
+/// 预乘透明度的 RGBA 颜色类型
+///
+/// 各颜色分量已乘以透明度，因而不大于透明度；内存布局与 \[`Color`\] 相同，
+/// 与分层窗口所用的 32 位预乘 DIB 的像素格式一致
+#[repr(C, align(4))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PremultipliedColor {
+    pub blue: u8,
+    pub green: u8,
+    pub red: u8,
+    pub alpha: u8,
+}
+
+/// Porter-Duff 合成运算
+///
+/// 运算中的源指 `self`，目标指参数 `dst`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CompositeOp {
+    /// 结果完全透明
+    Clear,
+    /// 只保留源
+    Src,
+    /// 只保留目标
+    Dst,
+    /// 源覆盖于目标之上
+    #[default]
+    SrcOver,
+    /// 目标覆盖于源之上
+    DstOver,
+    /// 源中与目标重叠的部分
+    SrcIn,
+    /// 目标中与源重叠的部分
+    DstIn,
+    /// 源中与目标不重叠的部分
+    SrcOut,
+    /// 目标中与源不重叠的部分
+    DstOut,
+    /// 源中与目标重叠的部分覆盖于目标之上
+    SrcAtop,
+    /// 目标中与源重叠的部分覆盖于源之上
+    DstAtop,
+    /// 源与目标各自不重叠的部分
+    Xor,
+}
+
+impl CompositeOp {
+    /// 返回源与目标的合成系数，以 255 为满值
+    const fn factors(self, src_alpha: u8, dst_alpha: u8) -> (u8, u8) {
+        let (sa, da) = (src_alpha, dst_alpha);
+        match self {
+            CompositeOp::Clear => (0, 0),
+            CompositeOp::Src => (255, 0),
+            CompositeOp::Dst => (0, 255),
+            CompositeOp::SrcOver => (255, 255 - sa),
+            CompositeOp::DstOver => (255 - da, 255),
+            CompositeOp::SrcIn => (da, 0),
+            CompositeOp::DstIn => (0, sa),
+            CompositeOp::SrcOut => (255 - da, 0),
+            CompositeOp::DstOut => (0, 255 - sa),
+            CompositeOp::SrcAtop => (da, 255 - sa),
+            CompositeOp::DstAtop => (255 - da, sa),
+            CompositeOp::Xor => (255 - da, 255 - sa),
+        }
+    }
+}
+
+impl PremultipliedColor {
+    /// 完全透明的颜色
+    pub const TRANSPARENT: Self = Self {
+        blue: 0,
+        green: 0,
+        red: 0,
+        alpha: 0,
+    };
+
+    /// 转换为未经预乘的颜色
+    pub const fn unpremultiply(self) -> Color {
+        let a = self.alpha;
+        if a == 0 {
+            return Color::TRANSPARENT;
+        }
+        Color::rgba(div(self.red, a), div(self.green, a), div(self.blue, a), a)
+    }
+
+    /// 以指定的 Porter-Duff 运算将 `self` 与 `dst` 合成
+    pub const fn composite(self, dst: Self, op: CompositeOp) -> Self {
+        let (fs, fd) = op.factors(self.alpha, dst.alpha);
+        Self {
+            blue: mul(self.blue, fs).saturating_add(mul(dst.blue, fd)),
+            green: mul(self.green, fs).saturating_add(mul(dst.green, fd)),
+            red: mul(self.red, fs).saturating_add(mul(dst.red, fd)),
+            alpha: mul(self.alpha, fs).saturating_add(mul(dst.alpha, fd)),
+        }
+    }
+}
+
+impl Color {
+    /// 转换为预乘透明度的颜色
+    pub const fn premultiply(self) -> PremultipliedColor {
+        PremultipliedColor {
+            blue: mul(self.blue, self.alpha),
+            green: mul(self.green, self.alpha),
+            red: mul(self.red, self.alpha),
+            alpha: self.alpha,
+        }
+    }
+
+    /// 以指定的 Porter-Duff 运算将 `self` 与 `dst` 合成
+    pub const fn composite(self, dst: Color, op: CompositeOp) -> Color {
+        // 不透明的源覆盖于目标之上是最常见的情形
+        if let CompositeOp::SrcOver = op
+            && self.alpha == 255
+        {
+            return self;
+        }
+        self.premultiply()
+            .composite(dst.premultiply(), op)
+            .unpremultiply()
+    }
+
+    /// 将 `self` 覆盖于 `dst` 之上
+    pub const fn over(self, dst: Color) -> Color {
+        self.composite(dst, CompositeOp::SrcOver)
+    }
+
+    /// 返回 `self` 中与 `dst` 重叠的部分
+    pub const fn inside(self, dst: Color) -> Color {
+        self.composite(dst, CompositeOp::SrcIn)
+    }
+
+    /// 返回 `self` 中与 `dst` 不重叠的部分
+    pub const fn outside(self, dst: Color) -> Color {
+        self.composite(dst, CompositeOp::SrcOut)
+    }
+
+    /// 将 `self` 中与 `dst` 重叠的部分覆盖于 `dst` 之上
+    pub const fn atop(self, dst: Color) -> Color {
+        self.composite(dst, CompositeOp::SrcAtop)
+    }
+
+    /// 返回 `self` 与 `dst` 各自不重叠的部分
+    pub const fn xor(self, dst: Color) -> Color {
+        self.composite(dst, CompositeOp::Xor)
+    }
+}
+
+impl From<Color> for PremultipliedColor {
+    fn from(color: Color) -> Self {
+        color.premultiply()
+    }
+}
+
+impl From<PremultipliedColor> for Color {
+    fn from(color: PremultipliedColor) -> Self {
+        color.unpremultiply()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_premultiply() {
+        let color = Color::rgba(200, 100, 50, 128);
+        let pre = color.premultiply();
+        assert_eq!(
+            (pre.red, pre.green, pre.blue, pre.alpha),
+            (100, 50, 25, 128)
+        );
+        assert_eq!(pre.unpremultiply(), Color::rgba(199, 100, 50, 128));
+        assert_eq!(Color::RED.premultiply().unpremultiply(), Color::RED);
+        assert_eq!(
+            Color::rgba(10, 20, 30, 0).premultiply().unpremultiply(),
+            Color::TRANSPARENT
+        );
+    }
+
+    #[test]
+    fn test_over() {
+        assert_eq!(Color::RED.over(Color::BLUE), Color::RED);
+        assert_eq!(Color::TRANSPARENT.over(Color::BLUE), Color::BLUE);
+        let half = Color::rgba(255, 0, 0, 128);
+        assert_eq!(half.over(Color::BLUE), Color::new(128, 0, 127));
+        // 两个半透明颜色叠加后的透明度为 1 - (1 - 0.5)²
+        let result = half.over(Color::rgba(0, 0, 255, 128));
+        assert_eq!(result.alpha, 192);
+        assert_eq!(result.red, 170);
+    }
+
+    #[test]
+    fn test_porter_duff() {
+        let src = Color::rgba(255, 0, 0, 255);
+        let dst = Color::rgba(0, 0, 255, 128);
+        assert_eq!(src.inside(dst), Color::rgba(255, 0, 0, 128));
+        assert_eq!(src.outside(dst), Color::rgba(255, 0, 0, 127));
+        assert_eq!(src.atop(dst), Color::rgba(255, 0, 0, 128));
+        assert_eq!(src.xor(dst), Color::rgba(255, 0, 0, 127));
+        assert_eq!(dst.xor(src), Color::rgba(255, 0, 0, 127));
+        assert_eq!(src.composite(dst, CompositeOp::Clear), Color::TRANSPARENT);
+        assert_eq!(src.composite(dst, CompositeOp::Dst), dst);
+        assert_eq!(
+            src.composite(dst, CompositeOp::DstOver),
+            Color::rgba(127, 0, 128, 255)
+        );
+    }
+}
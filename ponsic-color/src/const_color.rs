@@ -148,3 +148,152 @@ impl Color {
     pub const WHITESMOKE: Self = Self::new(0xf5, 0xf5, 0xf5);
     pub const YELLOWGREEN: Self = Self::new(0x9a, 0xcd, 0x32);
 }
+
+/// CSS 颜色名称及其对应的颜色，按 CSS 规范中的顺序排列
+pub const NAMED_COLORS: &[(&str, Color)] = &[
+    ("black", Color::BLACK),
+    ("silver", Color::SILVER),
+    ("gray", Color::GRAY),
+    ("white", Color::WHITE),
+    ("maroon", Color::MAROON),
+    ("red", Color::RED),
+    ("purple", Color::PURPLE),
+    ("fuchsia", Color::FUCHSIA),
+    ("green", Color::GREEN),
+    ("lime", Color::LIME),
+    ("olive", Color::OLIVE),
+    ("yellow", Color::YELLOW),
+    ("navy", Color::NAVY),
+    ("blue", Color::BLUE),
+    ("teal", Color::TEAL),
+    ("aqua", Color::AQUA),
+    ("aliceblue", Color::ALICEBLUE),
+    ("antiquewhite", Color::ANTIQUEWHITE),
+    ("aquamarine", Color::AQUAMARINE),
+    ("azure", Color::AZURE),
+    ("beige", Color::BEIGE),
+    ("bisque", Color::BISQUE),
+    ("blanchedalmond", Color::BLANCHEDALMOND),
+    ("blueviolet", Color::BLUEVIOLET),
+    ("brown", Color::BROWN),
+    ("burlywood", Color::BURLYWOOD),
+    ("cadetblue", Color::CADETBLUE),
+    ("chartreuse", Color::CHARTREUSE),
+    ("chocolate", Color::CHOCOLATE),
+    ("coral", Color::CORAL),
+    ("cornflowerblue", Color::CORNFLOWERBLUE),
+    ("cornsilk", Color::CORNSILK),
+    ("crimson", Color::CRIMSON),
+    ("darkblue", Color::DARKBLUE),
+    ("darkcyan", Color::DARKCYAN),
+    ("darkgoldenrod", Color::DARKGOLDENROD),
+    ("darkgray", Color::DARKGRAY),
+    ("darkgreen", Color::DARKGREEN),
+    ("darkgrey", Color::DARKGREY),
+    ("darkkhaki", Color::DARKKHAKI),
+    ("darkmagenta", Color::DARKMAGENTA),
+    ("darkolivegreen", Color::DARKOLIVEGREEN),
+    ("darkorange", Color::DARKORANGE),
+    ("darkorchid", Color::DARKORCHID),
+    ("darkred", Color::DARKRED),
+    ("darksalmon", Color::DARKSALMON),
+    ("darkseagreen", Color::DARKSEAGREEN),
+    ("darkslateblue", Color::DARKSLATEBLUE),
+    ("darkslategray", Color::DARKSLATEGRAY),
+    ("darkslategrey", Color::DARKSLATEGREY),
+    ("darkturquoise", Color::DARKTURQUOISE),
+    ("darkviolet", Color::DARKVIOLET),
+    ("deeppink", Color::DEEPPINK),
+    ("deepskyblue", Color::DEEPSKYBLUE),
+    ("dimgray", Color::DIMGRAY),
+    ("dimgrey", Color::DIMGREY),
+    ("dodgerblue", Color::DODGERBLUE),
+    ("firebrick", Color::FIREBRICK),
+    ("floralwhite", Color::FLORALWHITE),
+    ("forestgreen", Color::FORESTGREEN),
+    ("gainsboro", Color::GAINSBORO),
+    ("ghostwhite", Color::GHOSTWHITE),
+    ("gold", Color::GOLD),
+    ("goldenrod", Color::GOLDENROD),
+    ("greenyellow", Color::GREENYELLOW),
+    ("honeydew", Color::HONEYDEW),
+    ("hotpink", Color::HOTPINK),
+    ("indianred", Color::INDIANRED),
+    ("indigo", Color::INDIGO),
+    ("ivory", Color::IVORY),
+    ("khaki", Color::KHAKI),
+    ("lavender", Color::LAVENDER),
+    ("lavenderblush", Color::LAVENDERBLUSH),
+    ("lawngreen", Color::LAWNGREEN),
+    ("lemonchiffon", Color::LEMONCHIFFON),
+    ("lightblue", Color::LIGHTBLUE),
+    ("lightcoral", Color::LIGHTCORAL),
+    ("lightcyan", Color::LIGHTCYAN),
+    ("lightgoldenrodyellow", Color::LIGHTGOLDENRODYELLOW),
+    ("lightgray", Color::LIGHTGRAY),
+    ("lightgreen", Color::LIGHTGREEN),
+    ("lightgrey", Color::LIGHTGREY),
+    ("lightpink", Color::LIGHTPINK),
+    ("lightsalmon", Color::LIGHTSALMON),
+    ("lightseagreen", Color::LIGHTSEAGREEN),
+    ("lightskyblue", Color::LIGHTSKYBLUE),
+    ("lightslategray", Color::LIGHTSLATEGRAY),
+    ("lightslategrey", Color::LIGHTSLATEGREY),
+    ("lightsteelblue", Color::LIGHTSTEELBLUE),
+    ("lightyellow", Color::LIGHTYELLOW),
+    ("limegreen", Color::LIMEGREEN),
+    ("linen", Color::LINEN),
+    ("mediumaquamarine", Color::MEDIUMAQUAMARINE),
+    ("mediumblue", Color::MEDIUMBLUE),
+    ("mediumorchid", Color::MEDIUMORCHID),
+    ("mediumpurple", Color::MEDIUMPURPLE),
+    ("mediumseagreen", Color::MEDIUMSEAGREEN),
+    ("mediumslateblue", Color::MEDIUMSLATEBLUE),
+    ("mediumspringgreen", Color::MEDIUMSPRINGGREEN),
+    ("mediumturquoise", Color::MEDIUMTURQUOISE),
+    ("mediumvioletred", Color::MEDIUMVIOLETRED),
+    ("midnightblue", Color::MIDNIGHTBLUE),
+    ("mintcream", Color::MINTCREAM),
+    ("mistyrose", Color::MISTYROSE),
+    ("moccasin", Color::MOCCASIN),
+    ("navajowhite", Color::NAVAJOWHITE),
+    ("oldlace", Color::OLDLACE),
+    ("olivedrab", Color::OLIVEDRAB),
+    ("orange", Color::ORANGE),
+    ("orangered", Color::ORANGERED),
+    ("orchid", Color::ORCHID),
+    ("palegoldenrod", Color::PALEGOLDENROD),
+    ("palegreen", Color::PALEGREEN),
+    ("paleturquoise", Color::PALETURQUOISE),
+    ("palevioletred", Color::PALEVIOLETRED),
+    ("papayawhip", Color::PAPAYAWHIP),
+    ("peachpuff", Color::PEACHPUFF),
+    ("peru", Color::PERU),
+    ("pink", Color::PINK),
+    ("plum", Color::PLUM),
+    ("powderblue", Color::POWDERBLUE),
+    ("rebeccapurple", Color::REBECCAPURPLE),
+    ("rosybrown", Color::ROSYBROWN),
+    ("royalblue", Color::ROYALBLUE),
+    ("saddlebrown", Color::SADDLEBROWN),
+    ("salmon", Color::SALMON),
+    ("sandybrown", Color::SANDYBROWN),
+    ("seagreen", Color::SEAGREEN),
+    ("seashell", Color::SEASHELL),
+    ("sienna", Color::SIENNA),
+    ("skyblue", Color::SKYBLUE),
+    ("slateblue", Color::SLATEBLUE),
+    ("slategray", Color::SLATEGRAY),
+    ("slategrey", Color::SLATEGREY),
+    ("snow", Color::SNOW),
+    ("springgreen", Color::SPRINGGREEN),
+    ("steelblue", Color::STEELBLUE),
+    ("tan", Color::TAN),
+    ("thistle", Color::THISTLE),
+    ("tomato", Color::TOMATO),
+    ("turquoise", Color::TURQUOISE),
+    ("violet", Color::VIOLET),
+    ("wheat", Color::WHEAT),
+    ("whitesmoke", Color::WHITESMOKE),
+    ("yellowgreen", Color::YELLOWGREEN),
+];
@@ -0,0 +1,105 @@
+use crate::Color;
+
+/// WCAG 2.x 的对比度等级
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ContrastLevel {
+    /// AA 级正文，对比度不低于 4.5
+    AA,
+    /// AA 级大号文本，对比度不低于 3
+    AALarge,
+    /// AAA 级正文，对比度不低于 7
+    AAA,
+    /// AAA 级大号文本，对比度不低于 4.5
+    AAALarge,
+}
+
+impl ContrastLevel {
+    /// 返回此等级要求的最低对比度
+    pub const fn min_ratio(self) -> f32 {
+        match self {
+            ContrastLevel::AALarge => 3.0,
+            ContrastLevel::AA | ContrastLevel::AAALarge => 4.5,
+            ContrastLevel::AAA => 7.0,
+        }
+    }
+}
+
+impl Color {
+    /// 返回 WCAG 2.x 定义的相对亮度，范围为 0 至 1，透明度被忽略
+    pub fn relative_luminance(self) -> f32 {
+        let c = self.into_linear();
+        0.2126 * c.red + 0.7152 * c.green + 0.0722 * c.blue
+    }
+
+    /// 返回两颜色间 WCAG 2.x 定义的对比度，范围为 1 至 21，透明度被忽略
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// 判断以 `self` 为前景、`background` 为背景时是否满足指定的对比度等级
+    ///
+    /// 半透明的前景先与背景合成再计算对比度
+    pub fn meets_contrast(self, background: Color, level: ContrastLevel) -> bool {
+        let foreground = self.over(background.with_alpha(255));
+        foreground.contrast_ratio(background) >= level.min_ratio()
+    }
+
+    /// 返回在以 `self` 为背景时更清晰的前景色，即黑色或白色之一
+    pub fn readable_foreground(self) -> Color {
+        if Color::BLACK.contrast_ratio(self) >= Color::WHITE.contrast_ratio(self) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+
+    /// 返回候选颜色中在以 `self` 为背景时对比度最高的颜色，没有候选时返回 `None`
+    ///
+    /// 对比度相同时取靠前的颜色
+    pub fn most_readable(self, candidates: &[Color]) -> Option<Color> {
+        candidates.iter().copied().reduce(|best, c| {
+            if c.contrast_ratio(self) > best.contrast_ratio(self) {
+                c
+            } else {
+                best
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast() {
+        assert_eq!(Color::BLACK.relative_luminance(), 0.0);
+        assert!((Color::WHITE.relative_luminance() - 1.0).abs() < 1e-6);
+        assert!((Color::BLACK.contrast_ratio(Color::WHITE) - 21.0).abs() < 1e-4);
+        assert!((Color::WHITE.contrast_ratio(Color::BLACK) - 21.0).abs() < 1e-4);
+        assert_eq!(Color::RED.contrast_ratio(Color::RED), 1.0);
+        // #777777 在白色背景上略低于 AA 级的要求
+        let gray = Color::new(0x77, 0x77, 0x77);
+        assert!((gray.contrast_ratio(Color::WHITE) - 4.48).abs() < 0.01);
+        assert!(!gray.meets_contrast(Color::WHITE, ContrastLevel::AA));
+        assert!(gray.meets_contrast(Color::WHITE, ContrastLevel::AALarge));
+        assert!(Color::BLACK.meets_contrast(Color::WHITE, ContrastLevel::AAA));
+        assert!(
+            !Color::BLACK
+                .with_alpha(40)
+                .meets_contrast(Color::WHITE, ContrastLevel::AA)
+        );
+    }
+
+    #[test]
+    fn test_readable() {
+        assert_eq!(Color::NAVY.readable_foreground(), Color::WHITE);
+        assert_eq!(Color::YELLOW.readable_foreground(), Color::BLACK);
+        assert_eq!(
+            Color::WHITE.most_readable(&[Color::YELLOW, Color::NAVY, Color::GRAY]),
+            Some(Color::NAVY)
+        );
+        assert_eq!(Color::WHITE.most_readable(&[]), None);
+    }
+}
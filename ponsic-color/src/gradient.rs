@@ -0,0 +1,321 @@
+use crate::{Color, ColorHSL, ColorLinearRGB, ColorOKLab, hsl_to_rgb, rounded_rgb};
+
+/// 颜色插值所用的颜色空间
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum InterpolationSpace {
+    /// 直接在 sRGB 编码的分量上插值
+    #[default]
+    Srgb,
+    /// 在线性 sRGB 空间中插值，混合结果符合物理上的光强叠加
+    LinearRgb,
+    /// 在 HSL 空间中插值，色相沿较短的方向变化
+    Hsl,
+    /// 在 OKLab 空间中插值，明度变化在感知上较为均匀
+    OkLab,
+}
+
+/// 渐变在 `[0, 1]` 之外的扩展方式
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum SpreadMode {
+    /// 以两端的颜色填充
+    #[default]
+    Pad,
+    /// 重复整个渐变
+    Repeat,
+    /// 交替正向与反向重复渐变
+    Reflect,
+}
+
+impl SpreadMode {
+    /// 将任意位置映射至 `[0, 1]`
+    pub fn apply(self, t: f32) -> f32 {
+        if t.is_nan() {
+            return 0.0;
+        }
+        match self {
+            SpreadMode::Pad => t.clamp(0.0, 1.0),
+            SpreadMode::Repeat => t.rem_euclid(1.0),
+            SpreadMode::Reflect => {
+                let t = t.rem_euclid(2.0);
+                if t > 1.0 { 2.0 - t } else { t }
+            }
+        }
+    }
+}
+
+impl InterpolationSpace {
+    /// 返回颜色在此空间中的坐标，HSL 空间的色相位于第一个分量
+    fn coords(self, color: Color) -> [f32; 3] {
+        match self {
+            InterpolationSpace::Srgb => [
+                color.red as f32 / 255.0,
+                color.green as f32 / 255.0,
+                color.blue as f32 / 255.0,
+            ],
+            InterpolationSpace::LinearRgb => {
+                let c = color.into_linear();
+                [c.red, c.green, c.blue]
+            }
+            InterpolationSpace::Hsl => {
+                let c = color.into_hsl();
+                [c.hue, c.saturation, c.lightness]
+            }
+            InterpolationSpace::OkLab => {
+                let c = color.into_oklab();
+                [c.lightness, c.a, c.b]
+            }
+        }
+    }
+
+    fn to_color(self, [x, y, z]: [f32; 3]) -> Color {
+        match self {
+            InterpolationSpace::Srgb => {
+                let f = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+                Color::new(f(x), f(y), f(z))
+            }
+            InterpolationSpace::LinearRgb => Color::from_linear(ColorLinearRGB {
+                red: x,
+                green: y,
+                blue: z,
+            }),
+            InterpolationSpace::Hsl => rounded_rgb(hsl_to_rgb(ColorHSL {
+                hue: x.rem_euclid(360.0) % 360.0,
+                saturation: y.clamp(0.0, 1.0),
+                lightness: z.clamp(0.0, 1.0),
+            })),
+            InterpolationSpace::OkLab => Color::from_oklab(ColorOKLab {
+                lightness: x,
+                a: y,
+                b: z,
+            }),
+        }
+    }
+
+    /// 在此空间中对两颜色插值，`t` 为 0 时得到 `from`，为 1 时得到 `to`
+    ///
+    /// 颜色分量以预乘透明度的形式插值，避免与透明颜色混合时出现暗边
+    pub fn interpolate(self, from: Color, to: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (mut a, mut b) = (self.coords(from), self.coords(to));
+        let (alpha_a, alpha_b) = (from.alpha as f32 / 255.0, to.alpha as f32 / 255.0);
+        let alpha = alpha_a + (alpha_b - alpha_a) * t;
+
+        // HSL 的色相不参与预乘
+        let first = if self == InterpolationSpace::Hsl {
+            // 无彩色的色相没有意义，沿用另一端的色相
+            if a[1] == 0.0 {
+                a[0] = b[0];
+            } else if b[1] == 0.0 {
+                b[0] = a[0];
+            }
+            if b[0] - a[0] > 180.0 {
+                b[0] -= 360.0;
+            } else if a[0] - b[0] > 180.0 {
+                b[0] += 360.0;
+            }
+            1
+        } else {
+            0
+        };
+
+        let mut result = [0.0; 3];
+        for i in 0..3 {
+            result[i] = if i < first || alpha == 0.0 {
+                a[i] + (b[i] - a[i]) * t
+            } else {
+                let (pa, pb) = (a[i] * alpha_a, b[i] * alpha_b);
+                (pa + (pb - pa) * t) / alpha
+            };
+        }
+        self.to_color(result)
+            .with_alpha((alpha * 255.0).round() as u8)
+    }
+}
+
+impl Color {
+    /// 在指定颜色空间中将 `self` 与 `other` 按比例 `t` 混合
+    pub fn mix(self, other: Color, t: f32, space: InterpolationSpace) -> Color {
+        space.interpolate(self, other, t)
+    }
+}
+
+/// 渐变的色标
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GradientStop {
+    /// 色标的位置，范围为 0 至 1
+    pub offset: f32,
+    pub color: Color,
+}
+
+/// 由若干有序色标组成的颜色渐变
+///
+/// 位置相同的两个色标构成颜色的突变，恰好位于突变处时取后一个色标的颜色
+///
+/// # Examples
+/// ```
+/// use ponsic_color::{Color, Gradient, SpreadMode};
+///
+/// let gradient = Gradient::new()
+///     .add_stop(0.0, Color::BLACK)
+///     .add_stop(1.0, Color::WHITE)
+///     .set_spread(SpreadMode::Reflect);
+/// assert_eq!(gradient.color_at(0.5), Color::new(128, 128, 128));
+/// assert_eq!(gradient.color_at(1.5), gradient.color_at(0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    stops: Vec<GradientStop>,
+    space: InterpolationSpace,
+    spread: SpreadMode,
+}
+
+impl Gradient {
+    /// 创建没有色标的渐变
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建由若干颜色等距排列而成的渐变
+    pub fn from_colors(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        colors
+            .iter()
+            .enumerate()
+            .fold(Self::new(), |gradient, (i, &color)| {
+                gradient.add_stop(i as f32 / last, color)
+            })
+    }
+
+    /// 添加色标，位置被限制在 `[0, 1]` 内
+    ///
+    /// 色标按位置排序，位置相同的色标保持添加的顺序
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        let offset = if offset.is_nan() {
+            0.0
+        } else {
+            offset.clamp(0.0, 1.0)
+        };
+        let index = self.stops.partition_point(|s| s.offset <= offset);
+        self.stops.insert(index, GradientStop { offset, color });
+        self
+    }
+
+    /// 设置插值所用的颜色空间
+    pub fn set_space(mut self, space: InterpolationSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// 设置扩展方式
+    pub fn set_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// 返回按位置排序的色标
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// 返回插值所用的颜色空间
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    /// 返回扩展方式
+    pub fn spread(&self) -> SpreadMode {
+        self.spread
+    }
+
+    /// 返回渐变在位置 `t` 处的颜色，没有色标时返回透明色
+    pub fn color_at(&self, t: f32) -> Color {
+        let t = self.spread.apply(t);
+        let k = self.stops.partition_point(|s| s.offset <= t);
+        match (
+            k.checked_sub(1).map(|i| self.stops[i]),
+            self.stops.get(k).copied(),
+        ) {
+            (None, None) => Color::TRANSPARENT,
+            (Some(stop), None) | (None, Some(stop)) => stop.color,
+            (Some(a), Some(b)) => {
+                let f = (t - a.offset) / (b.offset - a.offset);
+                self.space.interpolate(a.color, b.color, f)
+            }
+        }
+    }
+
+    /// 在 `[0, 1]` 上等距采样 `n` 个颜色，可用作查找表或图例
+    pub fn sample(&self, n: usize) -> Vec<Color> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.color_at(i as f32 / last)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() {
+        let (black, white) = (Color::BLACK, Color::WHITE);
+        assert_eq!(
+            black.mix(white, 0.5, InterpolationSpace::Srgb),
+            Color::new(128, 128, 128)
+        );
+        assert_eq!(
+            black.mix(white, 0.5, InterpolationSpace::LinearRgb),
+            Color::new(188, 188, 188)
+        );
+        let mid = black.mix(white, 0.5, InterpolationSpace::OkLab);
+        assert!((mid.into_oklab().lightness - 0.5).abs() < 0.005);
+        // 红色与蓝色之间的较短色相路径经过品红
+        assert_eq!(
+            Color::RED.mix(Color::BLUE, 0.5, InterpolationSpace::Hsl),
+            Color::FUCHSIA
+        );
+        assert_eq!(
+            Color::WHITE.mix(Color::BLUE, 0.0, InterpolationSpace::Hsl),
+            Color::WHITE
+        );
+    }
+
+    #[test]
+    fn test_interpolate_alpha() {
+        let half = Color::TRANSPARENT.mix(Color::RED, 0.5, InterpolationSpace::Srgb);
+        assert_eq!(half, Color::rgba(255, 0, 0, 128));
+    }
+
+    #[test]
+    fn test_stops() {
+        let gradient = Gradient::new()
+            .add_stop(1.0, Color::BLUE)
+            .add_stop(0.0, Color::RED)
+            .add_stop(0.5, Color::RED)
+            .add_stop(0.5, Color::LIME);
+        let offsets: Vec<f32> = gradient.stops().iter().map(|s| s.offset).collect();
+        assert_eq!(offsets, [0.0, 0.5, 0.5, 1.0]);
+        assert_eq!(gradient.color_at(0.25), Color::RED);
+        assert_eq!(gradient.color_at(0.5), Color::LIME);
+        assert_eq!(gradient.color_at(0.75), Color::new(0, 128, 128));
+        assert_eq!(gradient.color_at(2.0), Color::BLUE);
+        assert_eq!(gradient.color_at(-1.0), Color::RED);
+        assert_eq!(Gradient::new().color_at(0.5), Color::TRANSPARENT);
+        assert_eq!(
+            Gradient::from_colors(&[Color::RED]).sample(3),
+            [Color::RED; 3]
+        );
+    }
+
+    #[test]
+    fn test_spread() {
+        let gradient = Gradient::from_colors(&[Color::BLACK, Color::WHITE]);
+        let gray = |v| Color::new(v, v, v);
+        let repeat = gradient.clone().set_spread(SpreadMode::Repeat);
+        assert_eq!(repeat.color_at(1.25), gray(64));
+        assert_eq!(repeat.color_at(-0.25), gray(191));
+        let reflect = gradient.clone().set_spread(SpreadMode::Reflect);
+        assert_eq!(reflect.color_at(1.25), gray(191));
+        assert_eq!(reflect.color_at(-0.25), gray(64));
+        assert_eq!(gradient.sample(3), [gray(0), gray(128), gray(255)]);
+    }
+}
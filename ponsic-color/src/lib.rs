@@ -1,13 +1,31 @@
+mod alpha;
 mod const_color;
-
-/// RGB 颜色类型
+mod contrast;
+mod gradient;
+mod palette;
+mod quantize;
+mod space;
+mod text;
+
+pub use alpha::*;
+pub use const_color::NAMED_COLORS;
+pub use contrast::ContrastLevel;
+pub use gradient::*;
+pub use quantize::{Dither, Palette};
+pub use space::*;
+pub use text::ParseColorError;
+
+/// RGBA 颜色类型
+///
+/// 颜色分量未经透明度预乘，透明度为 255 时完全不透明；
+/// 内存布局依次为蓝、绿、红、透明度，与 32 位 DIB 的像素格式相同
 #[repr(C, align(4))]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Color {
-    _ph: u8,
     pub blue: u8,
     pub green: u8,
     pub red: u8,
+    pub alpha: u8,
 }
 
 /// HSV 颜色类型
@@ -25,15 +43,51 @@ pub struct ColorHSL {
 }
 
 impl Color {
-    /// 创建一个颜色
+    /// 完全透明的颜色
+    pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
+
+    /// 创建一个不透明的颜色
     pub const fn new(red: u8, green: u8, blue: u8) -> Color {
+        Self::rgba(red, green, blue, 255)
+    }
+
+    /// 创建一个具有指定透明度的颜色
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
         Color {
-            _ph: 0,
             blue,
             green,
             red,
+            alpha,
         }
     }
+
+    /// 返回具有指定透明度的同色
+    pub const fn with_alpha(self, alpha: u8) -> Color {
+        Self::rgba(self.red, self.green, self.blue, alpha)
+    }
+
+    /// 判断颜色是否完全不透明
+    pub const fn is_opaque(self) -> bool {
+        self.alpha == 255
+    }
+
+    /// 转换为 `0xAARRGGBB` 格式的整数
+    pub const fn to_argb(self) -> u32 {
+        (self.alpha as u32) << 24
+            | (self.red as u32) << 16
+            | (self.green as u32) << 8
+            | self.blue as u32
+    }
+
+    /// 由 `0xAARRGGBB` 格式的整数创建颜色
+    pub const fn from_argb(argb: u32) -> Color {
+        Self::rgba(
+            (argb >> 16) as u8,
+            (argb >> 8) as u8,
+            argb as u8,
+            (argb >> 24) as u8,
+        )
+    }
 }
 
 impl Color {
@@ -112,75 +166,77 @@ impl Color {
 
     /// 将 HSV 颜色转换为 RGB 颜色
     pub const fn from_hsv(hsv: ColorHSV) -> Color {
-        let chroma = hsv.value * hsv.saturation;
-        let hue_prime = hsv.hue / 60.0;
-        let x = chroma * (1.0 - (hue_prime % 2.0 - 1.0).abs());
-
-        let (r, g, b) = if hue_prime < 1.0 {
-            (chroma, x, 0.0)
-        } else if hue_prime < 2.0 {
-            (x, chroma, 0.0)
-        } else if hue_prime < 3.0 {
-            (0.0, chroma, x)
-        } else if hue_prime < 4.0 {
-            (0.0, x, chroma)
-        } else if hue_prime < 5.0 {
-            (x, 0.0, chroma)
-        } else {
-            (chroma, 0.0, x)
-        };
-
-        let m = hsv.value - chroma;
-
-        Color::new(
-            ((r + m) * 255.0) as u8,
-            ((g + m) * 255.0) as u8,
-            ((b + m) * 255.0) as u8,
-        )
+        let (r, g, b) = hsv_to_rgb(hsv);
+        Color::new((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
     }
 
     /// 将 HSL 颜色转换为 RGB 颜色
     pub const fn from_hsl(hsl: ColorHSL) -> Color {
-        let chroma = (1.0 - (2.0 * hsl.lightness - 1.0).abs()) * hsl.saturation;
-        let hue_prime = hsl.hue / 60.0;
-        let x = chroma * (1.0 - (hue_prime % 2.0 - 1.0).abs());
-
-        let (r, g, b) = if hue_prime < 1.0 {
-            (chroma, x, 0.0)
-        } else if hue_prime < 2.0 {
-            (x, chroma, 0.0)
-        } else if hue_prime < 3.0 {
-            (0.0, chroma, x)
-        } else if hue_prime < 4.0 {
-            (0.0, x, chroma)
-        } else if hue_prime < 5.0 {
-            (x, 0.0, chroma)
-        } else {
-            (chroma, 0.0, x)
-        };
-
-        let m = hsl.lightness - chroma / 2.0;
+        let (r, g, b) = hsl_to_rgb(hsl);
+        Color::new((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+    }
+}
 
-        Color::new(
-            ((r + m) * 255.0) as u8,
-            ((g + m) * 255.0) as u8,
-            ((b + m) * 255.0) as u8,
-        )
+/// 由色相与色度计算不含亮度偏移的 RGB 分量
+const fn hue_to_rgb(hue: f32, chroma: f32) -> (f32, f32, f32) {
+    let hue_prime = hue / 60.0;
+    let x = chroma * (1.0 - (hue_prime % 2.0 - 1.0).abs());
+
+    if hue_prime < 1.0 {
+        (chroma, x, 0.0)
+    } else if hue_prime < 2.0 {
+        (x, chroma, 0.0)
+    } else if hue_prime < 3.0 {
+        (0.0, chroma, x)
+    } else if hue_prime < 4.0 {
+        (0.0, x, chroma)
+    } else if hue_prime < 5.0 {
+        (x, 0.0, chroma)
+    } else {
+        (chroma, 0.0, x)
     }
 }
 
+/// 将 HSV 颜色转换为 `[0, 1]` 范围内的 RGB 分量
+pub(crate) const fn hsv_to_rgb(hsv: ColorHSV) -> (f32, f32, f32) {
+    let chroma = hsv.value * hsv.saturation;
+    let (r, g, b) = hue_to_rgb(hsv.hue, chroma);
+    let m = hsv.value - chroma;
+    (r + m, g + m, b + m)
+}
+
+/// 将 HSL 颜色转换为 `[0, 1]` 范围内的 RGB 分量
+pub(crate) const fn hsl_to_rgb(hsl: ColorHSL) -> (f32, f32, f32) {
+    let chroma = (1.0 - (2.0 * hsl.lightness - 1.0).abs()) * hsl.saturation;
+    let (r, g, b) = hue_to_rgb(hsl.hue, chroma);
+    let m = hsl.lightness - chroma / 2.0;
+    (r + m, g + m, b + m)
+}
+
+/// 将 `[0, 1]` 范围内的 RGB 分量四舍五入为颜色
+///
+/// 与 \[`Color::from_hsv()`\] 等转换的截断不同，反复转换时颜色不会逐渐变暗
+pub(crate) fn rounded_rgb((r, g, b): (f32, f32, f32)) -> Color {
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::new(channel(r), channel(g), channel(b))
+}
+
+/// 转换为 `COLORREF` 格式（`0x00BBGGRR`）的整数，透明度被忽略
+#[allow(clippy::from_over_into)]
 impl Into<u32> for Color {
     fn into(self) -> u32 {
-        unsafe { std::mem::transmute(self) }
+        self.red as u32 | (self.green as u32) << 8 | (self.blue as u32) << 16
     }
 }
 
+/// 由 `COLORREF` 格式（`0x00BBGGRR`）的整数创建不透明的颜色，最高字节被忽略
 impl From<u32> for Color {
     fn from(color: u32) -> Self {
-        unsafe { std::mem::transmute(color & 0x00ffffff) }
+        Color::new(color as u8, (color >> 8) as u8, (color >> 16) as u8)
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<ColorHSV> for Color {
     fn into(self) -> ColorHSV {
         self.into_hsv()
@@ -193,6 +249,7 @@ impl From<ColorHSV> for Color {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<ColorHSL> for Color {
     fn into(self) -> ColorHSL {
         self.into_hsl()
@@ -209,6 +266,16 @@ impl From<ColorHSL> for Color {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_color_u32() {
+        let color = Color::rgba(0x12, 0x34, 0x56, 0x78);
+        assert_eq!(Into::<u32>::into(color), 0x00563412);
+        assert_eq!(Color::from(0xff563412), Color::new(0x12, 0x34, 0x56));
+        assert_eq!(color.to_argb(), 0x78123456);
+        assert_eq!(Color::from_argb(0x78123456), color);
+        assert_eq!(std::mem::size_of::<Color>(), 4);
+    }
+
     #[test]
     fn test_color_hsv() {
         let color = Color::new(255, 0, 0);
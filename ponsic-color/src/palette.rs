@@ -0,0 +1,92 @@
+use crate::{Color, ColorHSL, InterpolationSpace, hsl_to_rgb, rounded_rgb};
+
+impl Color {
+    /// 将颜色向白色混合，`amount` 为 0 时不变，为 1 时得到白色
+    pub fn tint(self, amount: f32) -> Color {
+        let white = Color::WHITE.with_alpha(self.alpha);
+        self.mix(white, amount, InterpolationSpace::Srgb)
+    }
+
+    /// 将颜色向黑色混合，`amount` 为 0 时不变，为 1 时得到黑色
+    pub fn shade(self, amount: f32) -> Color {
+        let black = Color::BLACK.with_alpha(self.alpha);
+        self.mix(black, amount, InterpolationSpace::Srgb)
+    }
+
+    /// 按比例降低 HSL 饱和度，`amount` 为 1 时得到明度相同的灰色
+    pub fn desaturate(self, amount: f32) -> Color {
+        let hsl = self.into_hsl();
+        let saturation = hsl.saturation * (1.0 - amount.clamp(0.0, 1.0));
+        rounded_rgb(hsl_to_rgb(ColorHSL { saturation, ..hsl })).with_alpha(self.alpha)
+    }
+
+    /// 将色相旋转 `degrees` 度，饱和度、明度与透明度不变
+    pub fn rotate_hue(self, degrees: f32) -> Color {
+        let hsl = self.into_hsl();
+        let hue = (hsl.hue + degrees).rem_euclid(360.0) % 360.0;
+        rounded_rgb(hsl_to_rgb(ColorHSL { hue, ..hsl })).with_alpha(self.alpha)
+    }
+
+    /// 返回补色，即色相相差 180° 的颜色
+    pub fn complement(self) -> Color {
+        self.rotate_hue(180.0)
+    }
+
+    /// 返回以自身为中心、色相依次相差 `angle` 度的三个邻近色
+    pub fn analogous(self, angle: f32) -> [Color; 3] {
+        [self.rotate_hue(-angle), self, self.rotate_hue(angle)]
+    }
+
+    /// 返回以自身为首、色相依次相差 120° 的三个颜色
+    pub fn triadic(self) -> [Color; 3] {
+        [self, self.rotate_hue(120.0), self.rotate_hue(240.0)]
+    }
+
+    /// 返回自身至白色之间等距排列的 `n` 个颜色，首项为自身
+    pub fn tints(self, n: usize) -> Vec<Color> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.tint(i as f32 / last)).collect()
+    }
+
+    /// 返回自身至黑色之间等距排列的 `n` 个颜色，首项为自身
+    pub fn shades(self, n: usize) -> Vec<Color> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.shade(i as f32 / last)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tint_shade() {
+        assert_eq!(Color::RED.tint(0.5), Color::new(255, 128, 128));
+        assert_eq!(Color::RED.shade(0.5), Color::new(128, 0, 0));
+        assert_eq!(
+            Color::RED.with_alpha(100).tint(1.0),
+            Color::WHITE.with_alpha(100)
+        );
+        assert_eq!(
+            Color::BLUE.shades(3),
+            [Color::BLUE, Color::new(0, 0, 128), Color::BLACK]
+        );
+        assert_eq!(Color::BLUE.tints(1), [Color::BLUE]);
+    }
+
+    #[test]
+    fn test_hue() {
+        assert_eq!(Color::RED.desaturate(1.0), Color::new(128, 128, 128));
+        assert_eq!(Color::RED.desaturate(0.0), Color::RED);
+        assert_eq!(Color::RED.complement(), Color::AQUA);
+        assert_eq!(
+            Color::BLUE.with_alpha(9).complement(),
+            Color::YELLOW.with_alpha(9)
+        );
+        assert_eq!(Color::RED.triadic(), [Color::RED, Color::LIME, Color::BLUE]);
+        assert_eq!(
+            Color::LIME.analogous(60.0),
+            [Color::YELLOW, Color::LIME, Color::AQUA]
+        );
+    }
+}
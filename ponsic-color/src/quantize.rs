@@ -0,0 +1,306 @@
+use crate::{Color, NAMED_COLORS};
+use std::collections::HashMap;
+
+/// 将颜色映射至调色板时所用的抖动方式
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Dither {
+    /// 不抖动，每个像素取最接近的颜色
+    #[default]
+    None,
+    /// Floyd–Steinberg 误差扩散
+    FloydSteinberg,
+    /// 4 × 4 Bayer 矩阵有序抖动
+    Ordered,
+}
+
+/// 4 × 4 Bayer 矩阵
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// 有序抖动在每个颜色分量上施加的最大偏移
+const ORDERED_SPREAD: f32 = 32.0;
+
+/// 由有限个颜色组成的调色板
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+fn channels(color: Color) -> [f32; 4] {
+    [color.red, color.green, color.blue, color.alpha].map(|v| v as f32)
+}
+
+fn distance(a: [f32; 4], b: [f32; 4]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// 中位切分中的一组颜色及其出现次数
+struct ColorBox {
+    colors: Vec<(Color, usize)>,
+}
+
+impl ColorBox {
+    /// 返回分量范围最大的通道及其范围
+    fn widest_channel(&self) -> (usize, u8) {
+        (0..4)
+            .map(|i| {
+                let values = self.colors.iter().map(|&(c, _)| channels(c)[i] as u8);
+                let (min, max) = values.fold((255, 0), |(lo, hi), v| (v.min(lo), v.max(hi)));
+                (i, max.saturating_sub(min))
+            })
+            .max_by_key(|&(i, range)| (range, std::cmp::Reverse(i)))
+            .unwrap_or((0, 0))
+    }
+
+    /// 沿范围最大的通道在加权中位数处切分
+    fn split(mut self) -> (ColorBox, ColorBox) {
+        let (channel, _) = self.widest_channel();
+        self.colors
+            .sort_by_key(|&(c, _)| (channels(c)[channel] as u8, c));
+        let total: usize = self.colors.iter().map(|&(_, n)| n).sum();
+        let mut count = 0;
+        let mut index = 1;
+        for (i, &(_, n)) in self.colors.iter().enumerate() {
+            count += n;
+            if count * 2 >= total {
+                index = i + 1;
+                break;
+            }
+        }
+        let index = index.min(self.colors.len() - 1);
+        let rest = self.colors.split_off(index);
+        (self, ColorBox { colors: rest })
+    }
+
+    /// 返回按出现次数加权的平均颜色
+    fn average(&self) -> Color {
+        let mut sum = [0.0f64; 4];
+        let mut total = 0.0;
+        for &(color, n) in &self.colors {
+            for (s, v) in sum.iter_mut().zip(channels(color)) {
+                *s += v as f64 * n as f64;
+            }
+            total += n as f64;
+        }
+        let [r, g, b, a] = sum.map(|s| (s / total).round() as u8);
+        Color::rgba(r, g, b, a)
+    }
+}
+
+impl Palette {
+    /// 由给定的颜色创建调色板
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self { colors }
+    }
+
+    /// 以中位切分算法由像素生成至多 `max_colors` 个颜色的调色板
+    ///
+    /// 每次选取分量范围最大的一组，沿该分量在按像素数加权的中位数处一分为二，
+    /// 最终每组以按像素数加权的平均颜色表示；结果按颜色排序，与像素的顺序无关
+    pub fn median_cut(pixels: &[Color], max_colors: usize) -> Self {
+        let mut counts = HashMap::new();
+        for &pixel in pixels {
+            *counts.entry(pixel).or_insert(0) += 1;
+        }
+        let mut colors: Vec<(Color, usize)> = counts.into_iter().collect();
+        colors.sort();
+        if colors.is_empty() || max_colors == 0 {
+            return Self::default();
+        }
+
+        let mut boxes = vec![ColorBox { colors }];
+        while boxes.len() < max_colors {
+            let Some(index) = (0..boxes.len())
+                .filter(|&i| boxes[i].colors.len() > 1)
+                .max_by_key(|&i| (boxes[i].widest_channel().1, std::cmp::Reverse(i)))
+            else {
+                break;
+            };
+            let (a, b) = boxes.swap_remove(index).split();
+            boxes.push(a);
+            boxes.push(b);
+        }
+        let mut colors: Vec<Color> = boxes.iter().map(ColorBox::average).collect();
+        colors.sort();
+        colors.dedup();
+        Self { colors }
+    }
+
+    /// 返回调色板中的颜色
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// 返回调色板中颜色的数量
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// 判断调色板是否为空
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    fn nearest_to(&self, value: [f32; 4]) -> usize {
+        let mut best = (0, f32::INFINITY);
+        for (i, &color) in self.colors.iter().enumerate() {
+            let d = distance(value, channels(color));
+            if d < best.1 {
+                best = (i, d);
+            }
+        }
+        best.0
+    }
+
+    /// 返回在 RGBA 空间中与颜色最接近的调色板颜色的索引，调色板为空时返回 `None`
+    pub fn nearest(&self, color: Color) -> Option<usize> {
+        (!self.is_empty()).then(|| self.nearest_to(channels(color)))
+    }
+
+    /// 将按行排列、每行 `width` 个像素的图像映射为调色板索引
+    ///
+    /// 像素数不必是 `width` 的整数倍，最后一行可以不完整；
+    /// 调色板为空或 `width` 为 0 时返回空列表
+    pub fn remap(&self, pixels: &[Color], width: usize, dither: Dither) -> Vec<usize> {
+        if self.is_empty() || width == 0 {
+            return Vec::new();
+        }
+        match dither {
+            Dither::None => pixels
+                .iter()
+                .map(|&c| self.nearest_to(channels(c)))
+                .collect(),
+            Dither::Ordered => pixels
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| {
+                    let t = BAYER[i / width % 4][i % width % 4] as f32;
+                    let offset = ((t + 0.5) / 16.0 - 0.5) * ORDERED_SPREAD;
+                    let [r, g, b, a] = channels(c);
+                    self.nearest_to([r + offset, g + offset, b + offset, a])
+                })
+                .collect(),
+            Dither::FloydSteinberg => self.error_diffusion(pixels, width),
+        }
+    }
+
+    fn error_diffusion(&self, pixels: &[Color], width: usize) -> Vec<usize> {
+        let mut values: Vec<[f32; 4]> = pixels.iter().map(|&c| channels(c)).collect();
+        let mut indices = Vec::with_capacity(pixels.len());
+        for i in 0..values.len() {
+            let value = values[i].map(|v| v.clamp(0.0, 255.0));
+            let index = self.nearest_to(value);
+            indices.push(index);
+            let chosen = channels(self.colors[index]);
+            let (x, y) = (i % width, i / width);
+            for (dx, dy, weight) in [(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)] {
+                let nx = x as isize + dx;
+                if nx < 0 || nx >= width as isize {
+                    continue;
+                }
+                // 最后一行可能不完整，相邻像素不一定存在
+                let Some(target) = values.get_mut((y + dy) * width + nx as usize) else {
+                    continue;
+                };
+                for c in 0..4 {
+                    target[c] += (value[c] - chosen[c]) * weight / 16.0;
+                }
+            }
+        }
+        indices
+    }
+
+    /// 将图像映射为调色板颜色，参见 [`Palette::remap`]
+    pub fn apply(&self, pixels: &[Color], width: usize, dither: Dither) -> Vec<Color> {
+        self.remap(pixels, width, dither)
+            .into_iter()
+            .map(|i| self.colors[i])
+            .collect()
+    }
+}
+
+impl Color {
+    /// 返回在 OKLab 空间中与颜色最接近的 CSS 颜色名称，透明度被忽略
+    ///
+    /// 距离相同的名称中取规范中靠前的名称
+    pub fn nearest_name(self) -> &'static str {
+        let lab = self.into_oklab();
+        let mut best = ("", f32::INFINITY);
+        for &(name, color) in NAMED_COLORS {
+            let other = color.into_oklab();
+            let d = (lab.lightness - other.lightness).powi(2)
+                + (lab.a - other.a).powi(2)
+                + (lab.b - other.b).powi(2);
+            if d < best.1 {
+                best = (name, d);
+            }
+        }
+        best.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_cut() {
+        let mut pixels = vec![Color::RED; 4];
+        pixels.extend([Color::new(250, 0, 0); 4]);
+        pixels.extend([Color::BLUE; 4]);
+        pixels.extend([Color::LIME; 4]);
+        // 最先在红色分量上分为红色与蓝绿两组，再分开蓝绿两色
+        let palette = Palette::median_cut(&pixels, 3);
+        assert_eq!(
+            palette.colors(),
+            [Color::new(253, 0, 0), Color::LIME, Color::BLUE]
+        );
+
+        let mut reversed = pixels.clone();
+        reversed.reverse();
+        assert_eq!(Palette::median_cut(&reversed, 3), palette);
+        assert_eq!(Palette::median_cut(&pixels, 10).len(), 4);
+        assert!(Palette::median_cut(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_remap() {
+        let palette = Palette::new(vec![Color::BLACK, Color::WHITE]);
+        assert_eq!(palette.nearest(Color::new(100, 100, 100)), Some(0));
+        assert_eq!(Palette::default().nearest(Color::RED), None);
+
+        let gray = vec![Color::new(128, 128, 128); 64];
+        assert_eq!(palette.remap(&gray, 8, Dither::None), [1; 64]);
+        // 抖动后黑白像素各占约一半
+        for dither in [Dither::FloydSteinberg, Dither::Ordered] {
+            let white = palette
+                .remap(&gray, 8, dither)
+                .into_iter()
+                .filter(|&i| i == 1)
+                .count();
+            assert!((28..=36).contains(&white), "{dither:?}: {white}");
+        }
+        assert_eq!(
+            palette.apply(
+                &[Color::new(10, 0, 0), Color::new(200, 255, 255)],
+                2,
+                Dither::None
+            ),
+            [Color::BLACK, Color::WHITE]
+        );
+
+        // 最后一行不完整
+        let gray = [Color::new(128, 128, 128); 3];
+        for dither in [Dither::None, Dither::Ordered, Dither::FloydSteinberg] {
+            assert_eq!(palette.remap(&gray, 2, dither).len(), 3, "{dither:?}");
+        }
+        assert_eq!(palette.remap(&gray, 2, Dither::FloydSteinberg), [1, 0, 0]);
+    }
+
+    #[test]
+    fn test_nearest_name() {
+        assert_eq!(Color::RED.nearest_name(), "red");
+        assert_eq!(Color::new(254, 1, 1).nearest_name(), "red");
+        assert_eq!(Color::new(0, 255, 255).nearest_name(), "aqua");
+        assert_eq!(Color::new(250, 128, 115).nearest_name(), "salmon");
+    }
+}
@@ -0,0 +1,509 @@
+//! 感知均匀的颜色空间
+//!
+//! 所有转换均以 D65 白点的 sRGB 为基准；由这些颜色空间转换回 \[`Color`\] 时，
+//! 超出 sRGB 色域的分量被截断，得到的颜色完全不透明
+
+// 转换矩阵保留参考文献中的全部有效数字
+#![allow(clippy::excessive_precision)]
+
+use crate::Color;
+
+/// D65 白点的 XYZ 坐标
+const WHITE: ColorXYZ = ColorXYZ {
+    x: 0.95047,
+    y: 1.0,
+    z: 1.08883,
+};
+
+/// CIELAB 的分段阈值 6/29
+const DELTA: f32 = 6.0 / 29.0;
+
+/// 将 sRGB 编码的分量转换为线性分量，分量以 1 为满值
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// 将线性分量转换为 sRGB 编码的分量，分量以 1 为满值
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// 线性 sRGB 颜色类型，分量以 1 为满值
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ColorLinearRGB {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+/// CIE XYZ 颜色类型，白色的 `y` 为 1
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ColorXYZ {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// CIELAB 颜色类型，明度的范围为 0 至 100
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ColorLab {
+    pub lightness: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// CIELCh 颜色类型，即极坐标形式的 CIELAB，色相以度为单位
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ColorLCh {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+/// OKLab 颜色类型，明度的范围为 0 至 1
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ColorOKLab {
+    pub lightness: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// OKLCh 颜色类型，即极坐标形式的 OKLab，色相以度为单位
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ColorOKLCh {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+/// 将直角坐标 `(a, b)` 转换为色度与以度为单位的色相
+fn to_polar(a: f32, b: f32) -> (f32, f32) {
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+    (a.hypot(b), hue % 360.0)
+}
+
+/// 将色度与以度为单位的色相转换为直角坐标
+fn from_polar(chroma: f32, hue: f32) -> (f32, f32) {
+    let (sin, cos) = hue.to_radians().sin_cos();
+    (chroma * cos, chroma * sin)
+}
+
+impl ColorLinearRGB {
+    /// 判断颜色是否位于 sRGB 色域内
+    pub fn is_in_gamut(self) -> bool {
+        const EPSILON: f32 = 1e-4;
+        [self.red, self.green, self.blue]
+            .iter()
+            .all(|v| (-EPSILON..=1.0 + EPSILON).contains(v))
+    }
+
+    /// 转换为 CIE XYZ 颜色
+    pub fn into_xyz(self) -> ColorXYZ {
+        let (r, g, b) = (self.red, self.green, self.blue);
+        ColorXYZ {
+            x: 0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+            y: 0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+            z: 0.0193339 * r + 0.1191920 * g + 0.9503041 * b,
+        }
+    }
+
+    /// 转换为 OKLab 颜色
+    pub fn into_oklab(self) -> ColorOKLab {
+        let (r, g, b) = (self.red, self.green, self.blue);
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        ColorOKLab {
+            lightness: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+}
+
+impl ColorXYZ {
+    /// 转换为线性 sRGB 颜色，结果可能超出色域
+    pub fn into_linear(self) -> ColorLinearRGB {
+        let (x, y, z) = (self.x, self.y, self.z);
+        ColorLinearRGB {
+            red: 3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+            green: -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+            blue: 0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+        }
+    }
+
+    /// 转换为 CIELAB 颜色
+    pub fn into_lab(self) -> ColorLab {
+        let f = |t: f32| {
+            if t > DELTA * DELTA * DELTA {
+                t.cbrt()
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let (fx, fy, fz) = (
+            f(self.x / WHITE.x),
+            f(self.y / WHITE.y),
+            f(self.z / WHITE.z),
+        );
+        ColorLab {
+            lightness: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl ColorLab {
+    /// 转换为 CIE XYZ 颜色
+    pub fn into_xyz(self) -> ColorXYZ {
+        let f = |t: f32| {
+            if t > DELTA {
+                t * t * t
+            } else {
+                3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+            }
+        };
+        let fy = (self.lightness + 16.0) / 116.0;
+        ColorXYZ {
+            x: WHITE.x * f(fy + self.a / 500.0),
+            y: WHITE.y * f(fy),
+            z: WHITE.z * f(fy - self.b / 200.0),
+        }
+    }
+
+    /// 转换为 CIELCh 颜色
+    pub fn into_lch(self) -> ColorLCh {
+        let (chroma, hue) = to_polar(self.a, self.b);
+        ColorLCh {
+            lightness: self.lightness,
+            chroma,
+            hue,
+        }
+    }
+
+    /// 返回 CIE76 色差，即两颜色在 CIELAB 空间中的欧氏距离
+    pub fn delta_e76(self, other: ColorLab) -> f32 {
+        let (dl, da, db) = (
+            self.lightness - other.lightness,
+            self.a - other.a,
+            self.b - other.b,
+        );
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// 返回 CIEDE2000 色差，参数因子均取 1
+    pub fn delta_e2000(self, other: ColorLab) -> f32 {
+        let (l1, a1, b1) = (self.lightness, self.a, self.b);
+        let (l2, a2, b2) = (other.lightness, other.a, other.b);
+
+        let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+        let c7 = c_mean.powi(7);
+        let g = 0.5 * (1.0 - (c7 / (c7 + 25f32.powi(7))).sqrt());
+        let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+        let (c1, h1) = to_polar(a1, b1);
+        let (c2, h2) = to_polar(a2, b2);
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 <= h1 {
+            h2 - h1 + 360.0
+        } else {
+            h2 - h1 - 360.0
+        };
+        let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let cos = |deg: f32| deg.to_radians().cos();
+        let t = 1.0 - 0.17 * cos(h_mean - 30.0)
+            + 0.24 * cos(2.0 * h_mean)
+            + 0.32 * cos(3.0 * h_mean + 6.0)
+            - 0.20 * cos(4.0 * h_mean - 63.0);
+        let l50 = (l_mean - 50.0) * (l_mean - 50.0);
+        let sl = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+        let sc = 1.0 + 0.045 * c_mean;
+        let sh = 1.0 + 0.015 * c_mean * t;
+        let c7 = c_mean.powi(7);
+        let rc = 2.0 * (c7 / (c7 + 25f32.powi(7))).sqrt();
+        let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+        let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+        (l * l + c * c + h * h + rt * c * h).sqrt()
+    }
+}
+
+impl ColorLCh {
+    /// 转换为 CIELAB 颜色
+    pub fn into_lab(self) -> ColorLab {
+        let (a, b) = from_polar(self.chroma, self.hue);
+        ColorLab {
+            lightness: self.lightness,
+            a,
+            b,
+        }
+    }
+}
+
+impl ColorOKLab {
+    /// 转换为线性 sRGB 颜色，结果可能超出色域
+    pub fn into_linear(self) -> ColorLinearRGB {
+        let (l, a, b) = (self.lightness, self.a, self.b);
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        ColorLinearRGB {
+            red: 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            green: -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            blue: -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        }
+    }
+
+    /// 转换为 OKLCh 颜色
+    pub fn into_oklch(self) -> ColorOKLCh {
+        let (chroma, hue) = to_polar(self.a, self.b);
+        ColorOKLCh {
+            lightness: self.lightness,
+            chroma,
+            hue,
+        }
+    }
+}
+
+impl ColorOKLCh {
+    /// 转换为 OKLab 颜色
+    pub fn into_oklab(self) -> ColorOKLab {
+        let (a, b) = from_polar(self.chroma, self.hue);
+        ColorOKLab {
+            lightness: self.lightness,
+            a,
+            b,
+        }
+    }
+}
+
+impl Color {
+    /// 转换为线性 sRGB 颜色，透明度被忽略
+    pub fn into_linear(self) -> ColorLinearRGB {
+        let f = |v: u8| srgb_to_linear(v as f32 / 255.0);
+        ColorLinearRGB {
+            red: f(self.red),
+            green: f(self.green),
+            blue: f(self.blue),
+        }
+    }
+
+    /// 由线性 sRGB 颜色创建不透明的颜色，超出色域的分量被截断
+    pub fn from_linear(linear: ColorLinearRGB) -> Color {
+        let f = |v: f32| (linear_to_srgb(v).clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color::new(f(linear.red), f(linear.green), f(linear.blue))
+    }
+
+    /// 转换为 CIE XYZ 颜色
+    pub fn into_xyz(self) -> ColorXYZ {
+        self.into_linear().into_xyz()
+    }
+
+    /// 由 CIE XYZ 颜色创建不透明的颜色
+    pub fn from_xyz(xyz: ColorXYZ) -> Color {
+        Self::from_linear(xyz.into_linear())
+    }
+
+    /// 转换为 CIELAB 颜色
+    pub fn into_lab(self) -> ColorLab {
+        self.into_xyz().into_lab()
+    }
+
+    /// 由 CIELAB 颜色创建不透明的颜色
+    pub fn from_lab(lab: ColorLab) -> Color {
+        Self::from_xyz(lab.into_xyz())
+    }
+
+    /// 转换为 CIELCh 颜色
+    pub fn into_lch(self) -> ColorLCh {
+        self.into_lab().into_lch()
+    }
+
+    /// 由 CIELCh 颜色创建不透明的颜色
+    pub fn from_lch(lch: ColorLCh) -> Color {
+        Self::from_lab(lch.into_lab())
+    }
+
+    /// 转换为 OKLab 颜色
+    pub fn into_oklab(self) -> ColorOKLab {
+        self.into_linear().into_oklab()
+    }
+
+    /// 由 OKLab 颜色创建不透明的颜色
+    pub fn from_oklab(oklab: ColorOKLab) -> Color {
+        Self::from_linear(oklab.into_linear())
+    }
+
+    /// 转换为 OKLCh 颜色
+    pub fn into_oklch(self) -> ColorOKLCh {
+        self.into_oklab().into_oklch()
+    }
+
+    /// 由 OKLCh 颜色创建不透明的颜色
+    pub fn from_oklch(oklch: ColorOKLCh) -> Color {
+        Self::from_oklab(oklch.into_oklab())
+    }
+
+    /// 返回与 `other` 的 CIE76 色差，透明度被忽略
+    pub fn delta_e76(self, other: Color) -> f32 {
+        self.into_lab().delta_e76(other.into_lab())
+    }
+
+    /// 返回与 `other` 的 CIEDE2000 色差，透明度被忽略
+    pub fn delta_e2000(self, other: Color) -> f32 {
+        self.into_lab().delta_e2000(other.into_lab())
+    }
+}
+
+macro_rules! impl_from {
+    ($($space:ty => $into:ident, $from:ident;)*) => {
+        $(
+            impl From<Color> for $space {
+                fn from(color: Color) -> Self {
+                    color.$into()
+                }
+            }
+
+            impl From<$space> for Color {
+                fn from(color: $space) -> Self {
+                    Self::$from(color)
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    ColorLinearRGB => into_linear, from_linear;
+    ColorXYZ => into_xyz, from_xyz;
+    ColorLab => into_lab, from_lab;
+    ColorLCh => into_lch, from_lch;
+    ColorOKLab => into_oklab, from_oklab;
+    ColorOKLCh => into_oklch, from_oklch;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32, epsilon: f32) -> bool {
+        (a - b).abs() <= epsilon
+    }
+
+    #[test]
+    fn test_transfer() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!(close(srgb_to_linear(1.0), 1.0, 1e-6));
+        assert!(close(srgb_to_linear(0.5), 0.214041, 1e-5));
+        for i in 0..=255 {
+            let v = i as f32 / 255.0;
+            assert!(close(linear_to_srgb(srgb_to_linear(v)), v, 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_lab() {
+        let white = Color::WHITE.into_lab();
+        assert!(close(white.lightness, 100.0, 1e-3));
+        assert!(close(white.a, 0.0, 1e-3) && close(white.b, 0.0, 1e-3));
+        let red = Color::RED.into_lch();
+        assert!(close(red.lightness, 53.24, 0.01));
+        assert!(close(red.chroma, 104.55, 0.01));
+        assert!(close(red.hue, 40.0, 0.01));
+    }
+
+    #[test]
+    fn test_oklab() {
+        let white = Color::WHITE.into_oklab();
+        assert!(close(white.lightness, 1.0, 1e-4));
+        assert!(close(white.a, 0.0, 1e-4) && close(white.b, 0.0, 1e-4));
+        let red = Color::RED.into_oklch();
+        assert!(close(red.lightness, 0.62796, 1e-4));
+        assert!(close(red.chroma, 0.25768, 1e-4));
+        assert!(close(red.hue, 29.234, 0.01));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for color in [
+            Color::BLACK,
+            Color::WHITE,
+            Color::CORNFLOWERBLUE,
+            Color::new(1, 2, 3),
+            Color::new(250, 128, 4),
+        ] {
+            assert_eq!(Color::from_xyz(color.into_xyz()), color);
+            assert_eq!(Color::from_lab(color.into_lab()), color);
+            assert_eq!(Color::from_lch(color.into_lch()), color);
+            assert_eq!(Color::from_oklab(color.into_oklab()), color);
+            assert_eq!(Color::from_oklch(color.into_oklch()), color);
+        }
+        let out = ColorOKLCh {
+            lightness: 0.7,
+            chroma: 0.4,
+            hue: 150.0,
+        };
+        assert!(!out.into_oklab().into_linear().is_in_gamut());
+        assert!(Color::CORNFLOWERBLUE.into_linear().is_in_gamut());
+    }
+
+    #[test]
+    fn test_delta_e() {
+        assert_eq!(Color::RED.delta_e76(Color::RED), 0.0);
+        assert!(close(Color::BLACK.delta_e76(Color::WHITE), 100.0, 1e-3));
+        // Sharma 等人给出的 CIEDE2000 测试数据
+        let lab = |lightness, a, b| ColorLab { lightness, a, b };
+        for (x, y, expected) in [
+            (
+                lab(50.0, 2.6772, -79.7751),
+                lab(50.0, 0.0, -82.7485),
+                2.0425,
+            ),
+            (
+                lab(50.0, -1.3802, -84.2814),
+                lab(50.0, 0.0, -82.7485),
+                1.0000,
+            ),
+            (lab(50.0, 0.0, 0.0), lab(50.0, -1.0, 2.0), 2.3669),
+            (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+            (lab(50.0, 2.5, 0.0), lab(50.0, 0.0, -2.5), 4.3065),
+            (
+                lab(2.0776, 0.0795, -1.135),
+                lab(0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ] {
+            assert!(close(x.delta_e2000(y), expected, 1e-3));
+            assert!(close(y.delta_e2000(x), expected, 1e-3));
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! 颜色的文本格式
+//!
+//! 支持 `#rgb`、`#rgba`、`#rrggbb`、`#rrggbbaa` 形式的十六进制颜色，
+//! `rgb()`、`rgba()`、`hsl()`、`hsla()`、`hsv()`、`hsva()` 形式的颜色函数，
+//! 以及不区分大小写的 CSS 颜色名称与 `transparent`；
+//! 颜色函数的参数可以逗号分隔，也可以空格分隔并以 `/` 引出透明度
+
+use crate::{Color, ColorHSL, ColorHSV, NAMED_COLORS, hsl_to_rgb, hsv_to_rgb, rounded_rgb};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// 解析颜色字符串时发生的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseColorError {
+    message: &'static str,
+}
+
+impl ParseColorError {
+    /// 返回错误描述
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "颜色解析失败: {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+type Result<T> = std::result::Result<T, ParseColorError>;
+
+const fn error(message: &'static str) -> ParseColorError {
+    ParseColorError { message }
+}
+
+/// 格式化数值，至多保留 `digits` 位小数并去除末尾的零
+fn number(value: f32, digits: usize) -> String {
+    let text = format!("{:.*}", digits, value);
+    if text.contains('.') {
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" { "0" } else { text }.to_string()
+    } else {
+        text
+    }
+}
+
+impl Color {
+    /// 按名称查找 CSS 颜色，名称不区分大小写
+    pub fn from_name(name: &str) -> Option<Color> {
+        NAMED_COLORS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, color)| color)
+    }
+
+    /// 返回与颜色完全相同的 CSS 颜色名称
+    ///
+    /// 同一颜色具有多个名称时返回规范中靠前的名称，如 `aqua` 而非 `cyan`
+    pub fn name(self) -> Option<&'static str> {
+        NAMED_COLORS
+            .iter()
+            .find(|&&(_, color)| color == self)
+            .map(|&(name, _)| name)
+    }
+
+    /// 格式化为 `#rrggbb`，半透明颜色格式化为 `#rrggbbaa`
+    pub fn to_hex_string(self) -> String {
+        self.to_string()
+    }
+
+    /// 格式化为 `rgb(r, g, b)`，半透明颜色格式化为 `rgba(r, g, b, a)`
+    pub fn to_rgb_string(self) -> String {
+        if self.is_opaque() {
+            format!("rgb({}, {}, {})", self.red, self.green, self.blue)
+        } else {
+            format!(
+                "rgba({}, {}, {}, {})",
+                self.red,
+                self.green,
+                self.blue,
+                self.alpha_text()
+            )
+        }
+    }
+
+    /// 格式化为 `hsl(h, s%, l%)`，半透明颜色格式化为 `hsla(h, s%, l%, a)`
+    pub fn to_hsl_string(self) -> String {
+        let hsl = self.into_hsl();
+        self.function_text("hsl", hsl.hue, hsl.saturation, hsl.lightness)
+    }
+
+    /// 格式化为 `hsv(h, s%, v%)`，半透明颜色格式化为 `hsva(h, s%, v%, a)`
+    pub fn to_hsv_string(self) -> String {
+        let hsv = self.into_hsv();
+        self.function_text("hsv", hsv.hue, hsv.saturation, hsv.value)
+    }
+
+    fn alpha_text(self) -> String {
+        number(self.alpha as f32 / 255.0, 3)
+    }
+
+    fn function_text(self, name: &str, hue: f32, a: f32, b: f32) -> String {
+        let args = format!(
+            "{}, {}%, {}%",
+            number(hue, 1),
+            number(a * 100.0, 1),
+            number(b * 100.0, 1)
+        );
+        if self.is_opaque() {
+            format!("{}({})", name, args)
+        } else {
+            format!("{}a({}, {})", name, args, self.alpha_text())
+        }
+    }
+}
+
+/// 格式化为 `#rrggbb`，半透明颜色格式化为 `#rrggbbaa`
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)?;
+        if !self.is_opaque() {
+            write!(f, "{:02x}", self.alpha)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Color> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(error("无效的十六进制颜色"));
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap() * 17;
+    let byte = |i: usize| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+    match hex.len() {
+        3 => Ok(Color::new(digit(0), digit(1), digit(2))),
+        4 => Ok(Color::rgba(digit(0), digit(1), digit(2), digit(3))),
+        6 => Ok(Color::new(byte(0), byte(1), byte(2))),
+        8 => Ok(Color::rgba(byte(0), byte(1), byte(2), byte(3))),
+        _ => Err(error("十六进制颜色的位数应为 3、4、6 或 8")),
+    }
+}
+
+/// 将颜色函数的参数拆分为三个分量与可选的透明度
+fn split_args(args: &str) -> Result<Vec<&str>> {
+    let args: Vec<&str> = if args.contains(',') {
+        args.split(',').map(str::trim).collect()
+    } else {
+        let (components, alpha) = match args.split_once('/') {
+            Some((components, alpha)) => (components, Some(alpha.trim())),
+            None => (args, None),
+        };
+        let mut args: Vec<&str> = components.split_whitespace().collect();
+        if args.len() != 3 {
+            return Err(error("颜色函数的参数数目错误"));
+        }
+        args.extend(alpha);
+        args
+    };
+    if matches!(args.len(), 3 | 4) && args.iter().all(|a| !a.is_empty()) {
+        Ok(args)
+    } else {
+        Err(error("颜色函数的参数数目错误"))
+    }
+}
+
+fn parse_number(token: &str) -> Result<f32> {
+    token
+        .parse::<f32>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or(error("无效的数值"))
+}
+
+/// 解析 0 至 255 的数值或百分比
+fn parse_channel(token: &str) -> Result<u8> {
+    let value = match token.strip_suffix('%') {
+        Some(p) => parse_number(p)? / 100.0 * 255.0,
+        None => parse_number(token)?,
+    };
+    Ok(value.clamp(0.0, 255.0).round() as u8)
+}
+
+/// 解析 0 至 1 的数值或百分比
+fn parse_alpha(token: &str) -> Result<u8> {
+    let value = match token.strip_suffix('%') {
+        Some(p) => parse_number(p)? / 100.0,
+        None => parse_number(token)?,
+    };
+    Ok((value.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// 解析以度为单位的色相，结果位于 `[0, 360)`
+fn parse_hue(token: &str) -> Result<f32> {
+    let value = parse_number(token.strip_suffix("deg").unwrap_or(token))?;
+    Ok(value.rem_euclid(360.0) % 360.0)
+}
+
+/// 解析百分比，省略百分号时同样视为百分数
+fn parse_percent(token: &str) -> Result<f32> {
+    let value = parse_number(token.strip_suffix('%').unwrap_or(token))?;
+    Ok((value / 100.0).clamp(0.0, 1.0))
+}
+
+fn parse_function(name: &str, args: &str) -> Result<Color> {
+    let args = split_args(args)?;
+    let alpha = args.get(3).map_or(Ok(255), |a| parse_alpha(a))?;
+    let color = match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => Color::new(
+            parse_channel(args[0])?,
+            parse_channel(args[1])?,
+            parse_channel(args[2])?,
+        ),
+        "hsl" | "hsla" => rounded_rgb(hsl_to_rgb(ColorHSL {
+            hue: parse_hue(args[0])?,
+            saturation: parse_percent(args[1])?,
+            lightness: parse_percent(args[2])?,
+        })),
+        "hsv" | "hsva" => rounded_rgb(hsv_to_rgb(ColorHSV {
+            hue: parse_hue(args[0])?,
+            saturation: parse_percent(args[1])?,
+            value: parse_percent(args[2])?,
+        })),
+        _ => return Err(error("未知的颜色函数")),
+    };
+    Ok(color.with_alpha(alpha))
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Color> {
+        let s = s.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        if let Some((name, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            return parse_function(name.trim(), args);
+        }
+        if s.eq_ignore_ascii_case("transparent") {
+            return Ok(Color::TRANSPARENT);
+        }
+        Color::from_name(s).ok_or(error("未知的颜色名称"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Color {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("#f80"), Color::new(0xff, 0x88, 0x00));
+        assert_eq!(parse("#f808"), Color::rgba(0xff, 0x88, 0x00, 0x88));
+        assert_eq!(parse(" #6495ED "), Color::CORNFLOWERBLUE);
+        assert_eq!(parse("#12345678"), Color::rgba(0x12, 0x34, 0x56, 0x78));
+        assert_eq!(parse("rgb(255, 0, 128)"), Color::new(255, 0, 128));
+        assert_eq!(
+            parse("RGBA(100%, 0%, 50%, 0.5)"),
+            Color::rgba(255, 0, 128, 128)
+        );
+        assert_eq!(parse("rgb(0 128 255 / 25%)"), Color::rgba(0, 128, 255, 64));
+        assert_eq!(parse("hsl(120deg, 100%, 50%)"), Color::LIME);
+        assert_eq!(parse("hsla(-120, 100%, 50%, 0)"), Color::rgba(0, 0, 255, 0));
+        assert_eq!(parse("hsv(60 100% 100%)"), Color::YELLOW);
+        // 颜色函数四舍五入，而 `from_hsl()` 保持截断
+        assert_eq!(parse("hsl(0, 0%, 50%)"), Color::new(128, 128, 128));
+        let gray = ColorHSL {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.5,
+        };
+        assert_eq!(Color::from_hsl(gray), Color::new(127, 127, 127));
+        assert_eq!(parse("CornflowerBlue"), Color::CORNFLOWERBLUE);
+        assert_eq!(parse("transparent"), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        for s in [
+            "",
+            "#12",
+            "#12345g",
+            "rgb(1, 2)",
+            "rgb(1 2 3 4)",
+            "rgb(1, , 3)",
+            "rgb(a, b, c)",
+            "cmyk(1, 2, 3, 4)",
+            "rgb(1, 2, 3",
+            "notacolor",
+            "rgb(inf, 0, 0)",
+        ] {
+            assert!(s.parse::<Color>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let color = Color::new(51, 102, 153);
+        assert_eq!(color.to_string(), "#336699");
+        assert_eq!(color.with_alpha(128).to_hex_string(), "#33669980");
+        assert_eq!(color.to_rgb_string(), "rgb(51, 102, 153)");
+        assert_eq!(
+            color.with_alpha(128).to_rgb_string(),
+            "rgba(51, 102, 153, 0.502)"
+        );
+        assert_eq!(color.to_hsl_string(), "hsl(210, 50%, 40%)");
+        assert_eq!(color.to_hsv_string(), "hsv(210, 66.7%, 60%)");
+        assert_eq!(
+            Color::RED.with_alpha(0).to_hsl_string(),
+            "hsla(0, 100%, 50%, 0)"
+        );
+        assert_eq!(Color::new(0, 255, 255).name(), Some("aqua"));
+        assert_eq!(color.name(), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for color in [
+            Color::new(51, 102, 153),
+            Color::rgba(1, 2, 3, 4),
+            Color::rgba(200, 17, 90, 254),
+            Color::CORNFLOWERBLUE,
+        ] {
+            assert_eq!(parse(&color.to_hex_string()), color);
+            assert_eq!(parse(&color.to_rgb_string()), color);
+            assert_eq!(parse(&color.to_hsl_string()), color);
+            assert_eq!(parse(&color.to_hsv_string()), color);
+        }
+        for &(name, color) in NAMED_COLORS {
+            assert_eq!(parse(&name.to_uppercase()), color);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+mod png;
+mod pnm;
+mod zlib;
+
+use ponsic_color::Color;
+use ponsic_types::{Pointi as Point, Sizei};
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::Path;
+
+/// 图像文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Png,
+    /// 二进制 PPM（P6）
+    Ppm,
+    /// RGB 类型的 PAM（P7）
+    Pam,
+}
+
+impl ImageFormat {
+    /// 根据文件扩展名推断图像格式，不区分大小写
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "png" => Some(ImageFormat::Png),
+            "ppm" => Some(ImageFormat::Ppm),
+            "pam" => Some(ImageFormat::Pam),
+            _ => None,
+        }
+    }
+}
+
+/// 解码图像时发生的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageError {
+    message: &'static str,
+}
+
+impl ImageError {
+    /// 返回错误描述
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "图像解码失败: {}", self.message)
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// 内存中的位图
+///
+/// 像素按行优先排列；解码时灰度图像被扩展为 RGB，保存为 PPM 格式时透明度被忽略
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    size: Sizei,
+    pixels: Vec<Color>,
+}
+
+impl Bitmap {
+    /// 创建以指定颜色填充的位图
+    pub fn new(size: Sizei, color: Color) -> Self {
+        Self {
+            size,
+            pixels: vec![color; size.width as usize * size.height as usize],
+        }
+    }
+
+    /// 以按行优先排列的像素数据创建位图
+    ///
+    /// # Note
+    /// 若像素数量与尺寸不符，则返回 `None`
+    pub fn from_pixels(size: Sizei, pixels: Vec<Color>) -> Option<Self> {
+        (pixels.len() == size.width as usize * size.height as usize)
+            .then_some(Self { size, pixels })
+    }
+
+    /// 返回位图的尺寸
+    pub fn size(&self) -> Sizei {
+        self.size
+    }
+
+    /// 返回按行优先排列的像素数据
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    /// 返回按行优先排列的可变像素数据
+    pub fn pixels_mut(&mut self) -> &mut [Color] {
+        &mut self.pixels
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        if p.x < 0 || p.y < 0 || p.x as u32 >= self.size.width || p.y as u32 >= self.size.height {
+            None
+        } else {
+            Some(p.y as usize * self.size.width as usize + p.x as usize)
+        }
+    }
+
+    /// 返回指定位置的像素颜色
+    ///
+    /// # Note
+    /// 若位置超出位图，则返回 `None`
+    pub fn pixel(&self, p: Point) -> Option<Color> {
+        self.index(p).map(|i| self.pixels[i])
+    }
+
+    /// 设置指定位置的像素颜色，超出位图的位置将被忽略
+    pub fn set_pixel(&mut self, p: Point, color: Color) {
+        if let Some(i) = self.index(p) {
+            self.pixels[i] = color;
+        }
+    }
+
+    /// 以指定颜色填充整个位图
+    pub fn fill(&mut self, color: Color) {
+        self.pixels.fill(color);
+    }
+}
+
+// 编解码
+impl Bitmap {
+    /// 解码 PNG、PPM 或 PAM 图像，格式由文件头确定
+    pub fn decode(data: &[u8]) -> Result<Self, ImageError> {
+        let result = if data.starts_with(&png::SIGNATURE) {
+            png::decode(data)
+        } else {
+            pnm::decode(data)
+        };
+        result.map_err(|message| ImageError { message })
+    }
+
+    /// 以指定格式编码图像
+    ///
+    /// PNG 与 PAM 格式仅在含有透明像素时保存透明度通道
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Png => png::encode(self),
+            ImageFormat::Ppm => pnm::encode_ppm(self),
+            ImageFormat::Pam => pnm::encode_pam(self),
+        }
+    }
+
+    /// 从文件中读取图像
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::decode(&data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// 将图像写入文件，格式由文件扩展名确定
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(ImageFormat::from_extension)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "未知的图像格式"))?;
+        std::fs::write(path, self.encode(format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_test() {
+        let mut bitmap = Bitmap::new(Sizei::new(3, 2), Color::WHITE);
+        bitmap.set_pixel(Point::new(2, 1), Color::RED);
+        bitmap.set_pixel(Point::new(3, 1), Color::RED);
+        assert_eq!(bitmap.pixel(Point::new(2, 1)), Some(Color::RED));
+        assert_eq!(bitmap.pixel(Point::new(-1, 0)), None);
+        assert_eq!(bitmap.pixels()[5], Color::RED);
+        assert!(Bitmap::from_pixels(Sizei::new(2, 2), vec![Color::BLACK; 3]).is_none());
+    }
+
+    #[test]
+    fn save_and_load_test() {
+        let mut bitmap = Bitmap::new(Sizei::new(4, 3), Color::BLUE);
+        bitmap.set_pixel(Point::new(1, 1), Color::GREEN);
+        // 以进程号区分同时运行的多个测试进程
+        let name = format!("ponsic_bitmap_test_{}", std::process::id());
+        let dir = std::env::temp_dir();
+        for ext in ["png", "PPM", "pam"] {
+            let path = dir.join(format!("{}.{}", name, ext));
+            bitmap.save(&path).unwrap();
+            assert_eq!(Bitmap::load(&path).unwrap(), bitmap);
+            std::fs::remove_file(path).unwrap();
+        }
+        assert!(bitmap.save(dir.join(format!("{}.bmp", name))).is_err());
+    }
+}
@@ -0,0 +1,516 @@
+// PNG 编解码
+//
+// 解码支持全部颜色类型、位深度、tRNS 透明度与 Adam7 隔行扫描；
+// 编码输出 8 位 RGB 图像，含有透明像素时输出 8 位 RGBA 图像
+
+use super::{Bitmap, zlib};
+use ponsic_color::Color;
+use ponsic_types::Sizei;
+
+type Result<T> = std::result::Result<T, &'static str>;
+
+pub(crate) const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+};
+
+fn crc32(parts: &[&[u8]]) -> u32 {
+    let mut c = 0xffff_ffffu32;
+    for part in parts {
+        for &byte in *part {
+            c = CRC_TABLE[((c ^ byte as u32) & 0xff) as usize] ^ (c >> 8);
+        }
+    }
+    c ^ 0xffff_ffff
+}
+
+/// Adam7 各遍扫描的起始位置与步长：(x0, y0, dx, dy)
+const ADAM7: [(usize, usize, usize, usize); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+/// 图像头信息
+struct Header {
+    width: usize,
+    height: usize,
+    depth: u8,
+    color_type: u8,
+    interlace: bool,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() != 13 {
+            return Err("无效的 IHDR 块");
+        }
+        let width = u32::from_be_bytes(data[0..4].try_into().unwrap()) as usize;
+        let height = u32::from_be_bytes(data[4..8].try_into().unwrap()) as usize;
+        let (depth, color_type) = (data[8], data[9]);
+        let valid = match color_type {
+            0 => matches!(depth, 1 | 2 | 4 | 8 | 16),
+            3 => matches!(depth, 1 | 2 | 4 | 8),
+            2 | 4 | 6 => matches!(depth, 8 | 16),
+            _ => false,
+        };
+        if !valid {
+            return Err("不支持的图像格式");
+        }
+        // PNG 规范限定宽高不超过 2^31 - 1
+        if !(1..=0x7fff_ffff).contains(&width) || !(1..=0x7fff_ffff).contains(&height) {
+            return Err("无效的图像尺寸");
+        }
+        if data[10] != 0 || data[11] != 0 || data[12] > 1 {
+            return Err("不支持的压缩、过滤或隔行方式");
+        }
+        Ok(Self {
+            width,
+            height,
+            depth,
+            color_type,
+            interlace: data[12] == 1,
+        })
+    }
+
+    /// 每个像素的通道数
+    fn channels(&self) -> usize {
+        match self.color_type {
+            0 | 3 => 1,
+            4 => 2,
+            2 => 3,
+            _ => 4,
+        }
+    }
+
+    /// 每个像素占用的比特数
+    fn pixel_bits(&self) -> usize {
+        self.channels() * self.depth as usize
+    }
+
+    /// 宽度为 `width` 的扫描行的字节数，不含过滤类型字节
+    fn row_bytes(&self, width: usize) -> Option<usize> {
+        Some(width.checked_mul(self.pixel_bits())?.div_ceil(8))
+    }
+
+    /// 各遍扫描的起始位置与步长
+    fn passes(&self) -> &'static [(usize, usize, usize, usize)] {
+        if self.interlace {
+            &ADAM7
+        } else {
+            &[(0, 0, 1, 1)]
+        }
+    }
+
+    /// 解压后的图像数据的字节数，溢出时返回 `None`
+    fn data_len(&self) -> Option<usize> {
+        let mut total = 0usize;
+        for &(x0, y0, dx, dy) in self.passes() {
+            if x0 >= self.width || y0 >= self.height {
+                continue;
+            }
+            let rows = (self.height - y0).div_ceil(dy);
+            let len = self.row_bytes((self.width - x0).div_ceil(dx))?;
+            total = total.checked_add(rows.checked_mul(len.checked_add(1)?)?)?;
+        }
+        Some(total)
+    }
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// 就地还原一行经过过滤的扫描行
+fn unfilter(filter: u8, row: &mut [u8], prev: &[u8], bpp: usize) -> Result<()> {
+    for i in 0..row.len() {
+        let a = if i >= bpp { row[i - bpp] } else { 0 };
+        let b = prev[i];
+        let c = if i >= bpp { prev[i - bpp] } else { 0 };
+        row[i] = row[i].wrapping_add(match filter {
+            0 => 0,
+            1 => a,
+            2 => b,
+            3 => ((a as u16 + b as u16) / 2) as u8,
+            4 => paeth(a, b, c),
+            _ => return Err("无效的过滤类型"),
+        });
+    }
+    Ok(())
+}
+
+/// 读取扫描行中第 `index` 个样本，并将其缩放至 8 位
+fn sample(row: &[u8], index: usize, depth: u8) -> u8 {
+    match depth {
+        8 => row[index],
+        16 => row[index * 2],
+        _ => {
+            let depth = depth as usize;
+            let bit = index * depth;
+            let value = (row[bit / 8] >> (8 - depth - bit % 8)) & ((1 << depth) - 1);
+            (value as usize * 255 / ((1 << depth) - 1)) as u8
+        }
+    }
+}
+
+/// 读取扫描行中第 `index` 个样本的原始值，用于调色板索引与透明色比较
+fn raw_sample(row: &[u8], index: usize, depth: u8) -> usize {
+    if depth == 16 {
+        return u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]) as usize;
+    }
+    let depth = depth as usize;
+    let bit = index * depth;
+    ((row[bit / 8] >> (8 - depth - bit % 8)) as usize) & ((1 << depth) - 1)
+}
+
+/// 读取 tRNS 块中第 `index` 个 16 位透明色样本
+fn key_sample(transparency: &[u8], index: usize) -> Option<usize> {
+    let bytes = transparency.get(index * 2..index * 2 + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+}
+
+pub(crate) fn decode(data: &[u8]) -> Result<Bitmap> {
+    let mut rest = data.strip_prefix(&SIGNATURE).ok_or("不是 PNG 图像")?;
+    let mut header = None;
+    let mut palette: &[u8] = &[];
+    let mut transparency: &[u8] = &[];
+    let mut idat = Vec::new();
+    loop {
+        if rest.len() < 12 {
+            return Err("数据意外结束");
+        }
+        let len = u32::from_be_bytes(rest[0..4].try_into().unwrap()) as usize;
+        let kind = &rest[4..8];
+        let body = rest.get(8..8 + len).ok_or("数据意外结束")?;
+        let crc = rest.get(8 + len..12 + len).ok_or("数据意外结束")?;
+        if crc32(&[kind, body]) != u32::from_be_bytes(crc.try_into().unwrap()) {
+            return Err("块校验和不匹配");
+        }
+        rest = &rest[12 + len..];
+        match kind {
+            b"IHDR" => header = Some(Header::parse(body)?),
+            b"PLTE" => palette = body,
+            b"tRNS" => transparency = body,
+            b"IDAT" => idat.extend_from_slice(body),
+            b"IEND" => break,
+            // 名称首字母大写的块是解码所必需的
+            _ if kind[0].is_ascii_uppercase() => return Err("不支持的关键块"),
+            _ => {}
+        }
+    }
+    let header = header.ok_or("缺少 IHDR 块")?;
+    if header.color_type == 3 && palette.is_empty() {
+        return Err("缺少 PLTE 块");
+    }
+    let count = header.width.checked_mul(header.height);
+    let (Some(count), Some(data_len)) = (count, header.data_len()) else {
+        return Err("图像尺寸过大");
+    };
+    let raw = zlib::decompress(&idat)?;
+    // 先确认数据足够，再按图像尺寸分配像素
+    if raw.len() < data_len {
+        return Err("图像数据不足");
+    }
+
+    let bpp = header.pixel_bits().div_ceil(8);
+    let mut pixels = vec![Color::BLACK; count];
+    let mut pos = 0;
+    for &(x0, y0, dx, dy) in header.passes() {
+        if x0 >= header.width || y0 >= header.height {
+            continue;
+        }
+        let width = (header.width - x0).div_ceil(dx);
+        let len = header.row_bytes(width).ok_or("图像尺寸过大")?;
+        let mut prev = vec![0u8; len];
+        for y in (y0..header.height).step_by(dy) {
+            let filter = *raw.get(pos).ok_or("图像数据不足")?;
+            let mut row = raw
+                .get(pos + 1..pos + 1 + len)
+                .ok_or("图像数据不足")?
+                .to_vec();
+            pos += 1 + len;
+            unfilter(filter, &mut row, &prev, bpp)?;
+            for i in 0..width {
+                let c = i * header.channels();
+                let depth = header.depth;
+                let color = match header.color_type {
+                    0 => {
+                        let v = sample(&row, c, depth);
+                        let key = key_sample(transparency, 0);
+                        let alpha = if key == Some(raw_sample(&row, c, depth)) {
+                            0
+                        } else {
+                            255
+                        };
+                        Color::rgba(v, v, v, alpha)
+                    }
+                    4 => {
+                        let v = sample(&row, c, depth);
+                        Color::rgba(v, v, v, sample(&row, c + 1, depth))
+                    }
+                    3 => {
+                        let index = raw_sample(&row, i, depth);
+                        let rgb = palette
+                            .get(index * 3..index * 3 + 3)
+                            .ok_or("调色板索引越界")?;
+                        let alpha = transparency.get(index).copied().unwrap_or(255);
+                        Color::rgba(rgb[0], rgb[1], rgb[2], alpha)
+                    }
+                    2 => {
+                        let key = (0..3).map(|k| key_sample(transparency, k));
+                        let raw = (0..3).map(|k| Some(raw_sample(&row, c + k, depth)));
+                        let alpha = if !transparency.is_empty() && key.eq(raw) {
+                            0
+                        } else {
+                            255
+                        };
+                        Color::rgba(
+                            sample(&row, c, depth),
+                            sample(&row, c + 1, depth),
+                            sample(&row, c + 2, depth),
+                            alpha,
+                        )
+                    }
+                    _ => Color::rgba(
+                        sample(&row, c, depth),
+                        sample(&row, c + 1, depth),
+                        sample(&row, c + 2, depth),
+                        sample(&row, c + 3, depth),
+                    ),
+                };
+                pixels[y * header.width + x0 + i * dx] = color;
+            }
+            prev = row;
+        }
+    }
+    let size = Sizei::new(header.width as u32, header.height as u32);
+    Ok(Bitmap { size, pixels })
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], body: &[u8]) {
+    out.extend((body.len() as u32).to_be_bytes());
+    out.extend(kind);
+    out.extend(body);
+    out.extend(crc32(&[kind, body]).to_be_bytes());
+}
+
+pub(crate) fn encode(bitmap: &Bitmap) -> Vec<u8> {
+    let (width, height) = (bitmap.size.width as usize, bitmap.size.height as usize);
+    let alpha = bitmap.pixels.iter().any(|c| !c.is_opaque());
+    let (color_type, bpp) = if alpha { (6, 4) } else { (2, 3) };
+    let len = width * bpp;
+    let mut raw = Vec::with_capacity((len + 1) * height);
+    let mut prev = vec![0u8; len];
+    let mut candidate = vec![0u8; len];
+    for y in 0..height {
+        let row: Vec<u8> = bitmap.pixels[y * width..(y + 1) * width]
+            .iter()
+            .flat_map(|c| [c.red, c.green, c.blue, c.alpha].into_iter().take(bpp))
+            .collect();
+        // 选取使残差绝对值之和最小的过滤类型
+        let mut best = (u64::MAX, 0u8, Vec::new());
+        for filter in 0..5u8 {
+            for i in 0..len {
+                let a = if i >= bpp { row[i - bpp] } else { 0 };
+                let b = prev[i];
+                let c = if i >= bpp { prev[i - bpp] } else { 0 };
+                let predict = match filter {
+                    0 => 0,
+                    1 => a,
+                    2 => b,
+                    3 => ((a as u16 + b as u16) / 2) as u8,
+                    _ => paeth(a, b, c),
+                };
+                candidate[i] = row[i].wrapping_sub(predict);
+            }
+            let cost = candidate
+                .iter()
+                .map(|&v| (v as i8).unsigned_abs() as u64)
+                .sum();
+            if cost < best.0 {
+                best = (cost, filter, candidate.clone());
+            }
+        }
+        raw.push(best.1);
+        raw.extend(&best.2);
+        prev = row;
+    }
+
+    let mut out = SIGNATURE.to_vec();
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend((width as u32).to_be_bytes());
+    ihdr.extend((height as u32).to_be_bytes());
+    ihdr.extend([8, color_type, 0, 0, 0]);
+    write_chunk(&mut out, b"IHDR", &ihdr);
+    write_chunk(&mut out, b"IDAT", &zlib::compress(&raw));
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(header: [u8; 13], palette: Option<&[u8]>, raw: &[u8]) -> Vec<u8> {
+        let mut out = SIGNATURE.to_vec();
+        write_chunk(&mut out, b"IHDR", &header);
+        if let Some(palette) = palette {
+            write_chunk(&mut out, b"PLTE", palette);
+        }
+        write_chunk(&mut out, b"IDAT", &zlib::compress(raw));
+        write_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    fn ihdr(width: u32, height: u32, depth: u8, color_type: u8, interlace: u8) -> [u8; 13] {
+        let mut header = [0u8; 13];
+        header[0..4].copy_from_slice(&width.to_be_bytes());
+        header[4..8].copy_from_slice(&height.to_be_bytes());
+        header[8] = depth;
+        header[9] = color_type;
+        header[12] = interlace;
+        header
+    }
+
+    #[test]
+    fn crc_test() {
+        assert_eq!(crc32(&[b"IEND"]), 0xae42_6082);
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let size = Sizei::new(13, 7);
+        let pixels = (0..13 * 7)
+            .map(|i| Color::new(i as u8 * 2, 255 - i as u8, (i * i) as u8))
+            .collect();
+        let bitmap = Bitmap::from_pixels(size, pixels).unwrap();
+        assert_eq!(decode(&encode(&bitmap)).unwrap(), bitmap);
+    }
+
+    #[test]
+    fn gray_and_palette_test() {
+        // 2 × 2 的 1 位灰度图像，每行以过滤类型 0 开始
+        let gray = png(ihdr(2, 2, 1, 0, 0), None, &[0, 0b1000_0000, 0, 0b0100_0000]);
+        let bitmap = decode(&gray).unwrap();
+        assert_eq!(
+            bitmap.pixels(),
+            &[Color::WHITE, Color::BLACK, Color::BLACK, Color::WHITE]
+        );
+
+        let palette = [255, 0, 0, 0, 0, 255];
+        let indexed = png(ihdr(3, 1, 2, 3, 0), Some(&palette), &[0, 0b0001_0000]);
+        let bitmap = decode(&indexed).unwrap();
+        assert_eq!(bitmap.pixels(), &[Color::RED, Color::BLUE, Color::RED]);
+    }
+
+    #[test]
+    fn rgba16_and_filter_test() {
+        // 16 位 RGBA，第二个像素使用 Sub 过滤
+        let raw = [
+            1, 0x12, 0x00, 0x34, 0x00, 0x56, 0x00, 0xff, 0x00, //
+            0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ];
+        let bitmap = decode(&png(ihdr(2, 1, 16, 6, 0), None, &raw)).unwrap();
+        assert_eq!(
+            bitmap.pixels(),
+            &[Color::new(0x12, 0x34, 0x56), Color::new(0x13, 0x35, 0x57)]
+        );
+    }
+
+    #[test]
+    fn alpha_test() {
+        let pixels = vec![
+            Color::rgba(1, 2, 3, 0),
+            Color::rgba(4, 5, 6, 128),
+            Color::RED,
+        ];
+        let bitmap = Bitmap::from_pixels(Sizei::new(3, 1), pixels).unwrap();
+        let data = encode(&bitmap);
+        assert_eq!(data[25], 6);
+        assert_eq!(decode(&data).unwrap(), bitmap);
+
+        // 调色板与 RGB 图像的 tRNS 块
+        let mut out = SIGNATURE.to_vec();
+        write_chunk(&mut out, b"IHDR", &ihdr(2, 1, 8, 3, 0));
+        write_chunk(&mut out, b"PLTE", &[255, 0, 0, 0, 0, 255]);
+        write_chunk(&mut out, b"tRNS", &[64]);
+        write_chunk(&mut out, b"IDAT", &zlib::compress(&[0, 0, 1]));
+        write_chunk(&mut out, b"IEND", &[]);
+        let bitmap = decode(&out).unwrap();
+        assert_eq!(bitmap.pixels(), &[Color::rgba(255, 0, 0, 64), Color::BLUE]);
+
+        let mut out = SIGNATURE.to_vec();
+        write_chunk(&mut out, b"IHDR", &ihdr(2, 1, 8, 2, 0));
+        write_chunk(&mut out, b"tRNS", &[0, 9, 0, 8, 0, 7]);
+        write_chunk(&mut out, b"IDAT", &zlib::compress(&[0, 9, 8, 7, 9, 8, 6]));
+        write_chunk(&mut out, b"IEND", &[]);
+        let bitmap = decode(&out).unwrap();
+        assert_eq!(
+            bitmap.pixels(),
+            &[Color::rgba(9, 8, 7, 0), Color::new(9, 8, 6)]
+        );
+    }
+
+    #[test]
+    fn interlace_test() {
+        // 3 × 3 的 8 位灰度隔行图像，像素值依次为 0 至 8
+        // 各遍依次包含：(0,0)；无；无；(2,0)；(0,2) (2,2)；(1,0) (1,2)；第 1 行
+        let raw = [0, 0, 0, 2, 0, 6, 8, 0, 1, 0, 7, 0, 3, 4, 5];
+        let bitmap = decode(&png(ihdr(3, 3, 8, 0, 1), None, &raw)).unwrap();
+        let values: Vec<u8> = bitmap.pixels().iter().map(|c| c.red).collect();
+        assert_eq!(values, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn invalid_data_test() {
+        assert!(decode(b"not a png").is_err());
+        let mut data = encode(&Bitmap::new(Sizei::new(2, 2), Color::RED));
+        data[20] ^= 1;
+        assert!(decode(&data).is_err());
+    }
+
+    #[test]
+    fn oversized_test() {
+        // 超出规范的尺寸
+        assert!(decode(&png(ihdr(0x8000_0000, 1, 8, 2, 0), None, &[0])).is_err());
+        assert!(decode(&png(ihdr(0, 1, 8, 2, 0), None, &[0])).is_err());
+        // 尺寸合法但数据远少于尺寸所需，不应按尺寸分配内存
+        assert!(decode(&png(ihdr(0x7fff_ffff, 0x7fff_ffff, 8, 6, 0), None, &[0])).is_err());
+        assert!(decode(&png(ihdr(60000, 60000, 8, 6, 1), None, &[0, 0, 0])).is_err());
+        // 数据缺少最后一行
+        assert!(decode(&png(ihdr(2, 2, 8, 0, 0), None, &[0, 1, 2])).is_err());
+    }
+}
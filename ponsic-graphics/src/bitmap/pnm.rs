@@ -0,0 +1,254 @@
+// PPM（P3、P6）与 PAM（P7）编解码
+
+use super::Bitmap;
+use ponsic_color::Color;
+use ponsic_types::Sizei;
+
+type Result<T> = std::result::Result<T, &'static str>;
+
+/// 按空白分隔读取文件头中的记号，跳过以 `#` 开始的注释
+struct Header<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Header<'a> {
+    fn token(&mut self) -> Result<&'a str> {
+        loop {
+            match self.data.get(self.pos) {
+                Some(b'#') => {
+                    while self.data.get(self.pos).is_some_and(|&b| b != b'\n') {
+                        self.pos += 1;
+                    }
+                }
+                Some(b) if b.is_ascii_whitespace() => self.pos += 1,
+                Some(_) => break,
+                None => return Err("数据意外结束"),
+            }
+        }
+        let start = self.pos;
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|b| !b.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.data[start..self.pos]).map_err(|_| "无效的文件头")
+    }
+
+    fn number(&mut self) -> Result<u32> {
+        self.token()?.parse().map_err(|_| "无效的文件头")
+    }
+
+    /// 返回文件头之后的数据，文件头与数据之间以单个空白字符分隔
+    fn body(self) -> &'a [u8] {
+        &self.data[(self.pos + 1).min(self.data.len())..]
+    }
+}
+
+/// 将取值范围为 `0..=maxval` 的样本缩放至 8 位
+fn scale(value: u32, maxval: u32) -> u8 {
+    ((value * 255 + maxval / 2) / maxval) as u8
+}
+
+/// 读取二进制样本，`maxval` 大于 255 时每个样本占两个字节
+fn binary_samples(data: &[u8], count: usize, maxval: u32) -> Result<Vec<u8>> {
+    let width = if maxval > 255 { 2 } else { 1 };
+    let len = count.checked_mul(width).ok_or("图像尺寸过大")?;
+    let data = data.get(..len).ok_or("图像数据不足")?;
+    Ok(data
+        .chunks_exact(width)
+        .map(|s| {
+            let value = s.iter().fold(0u32, |v, &b| v << 8 | b as u32);
+            scale(value.min(maxval), maxval)
+        })
+        .collect())
+}
+
+/// 将样本按通道数转换为像素，灰度通道复制为 RGB
+fn to_pixels(samples: &[u8], depth: usize) -> Vec<Color> {
+    samples
+        .chunks_exact(depth)
+        .map(|s| match depth {
+            1 => Color::new(s[0], s[0], s[0]),
+            2 => Color::rgba(s[0], s[0], s[0], s[1]),
+            3 => Color::new(s[0], s[1], s[2]),
+            _ => Color::rgba(s[0], s[1], s[2], s[3]),
+        })
+        .collect()
+}
+
+fn bitmap(width: u32, height: u32, pixels: Vec<Color>) -> Result<Bitmap> {
+    if width == 0 || height == 0 {
+        return Err("无效的图像尺寸");
+    }
+    Ok(Bitmap {
+        size: Sizei::new(width, height),
+        pixels,
+    })
+}
+
+/// 返回图像的样本总数，溢出时返回错误
+fn sample_count(width: u32, height: u32, depth: usize) -> Result<usize> {
+    (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|n| n.checked_mul(depth))
+        .ok_or("图像尺寸过大")
+}
+
+fn check_maxval(maxval: u32) -> Result<u32> {
+    match maxval {
+        1..=65535 => Ok(maxval),
+        _ => Err("无效的最大样本值"),
+    }
+}
+
+pub(crate) fn decode(data: &[u8]) -> Result<Bitmap> {
+    let mut header = Header { data, pos: 0 };
+    match header.token()? {
+        "P3" | "P6" => decode_ppm(header),
+        "P7" => decode_pam(header),
+        _ => Err("不是 PPM 或 PAM 图像"),
+    }
+}
+
+fn decode_ppm(mut header: Header) -> Result<Bitmap> {
+    let binary = header.data.starts_with(b"P6");
+    let width = header.number()?;
+    let height = header.number()?;
+    let maxval = check_maxval(header.number()?)?;
+    let count = sample_count(width, height, 3)?;
+    let samples = if binary {
+        binary_samples(header.body(), count, maxval)?
+    } else {
+        // 每个文本样本至少占用一个字节，容量不超过剩余数据的长度
+        let rest = header.data.len() - header.pos;
+        let mut samples = Vec::with_capacity(count.min(rest));
+        for _ in 0..count {
+            samples.push(scale(header.number()?.min(maxval), maxval));
+        }
+        samples
+    };
+    bitmap(width, height, to_pixels(&samples, 3))
+}
+
+fn decode_pam(mut header: Header) -> Result<Bitmap> {
+    let (mut width, mut height, mut depth, mut maxval) = (None, None, None, None);
+    loop {
+        match header.token()? {
+            "WIDTH" => width = Some(header.number()?),
+            "HEIGHT" => height = Some(header.number()?),
+            "DEPTH" => depth = Some(header.number()?),
+            "MAXVAL" => maxval = Some(header.number()?),
+            "TUPLTYPE" => {
+                // 通道的含义由 DEPTH 确定
+                header.token()?;
+            }
+            "ENDHDR" => break,
+            _ => return Err("无效的文件头"),
+        }
+    }
+    let (width, height) = (width.ok_or("缺少宽度")?, height.ok_or("缺少高度")?);
+    let depth = match depth.ok_or("缺少通道数")? {
+        depth @ 1..=4 => depth as usize,
+        _ => return Err("不支持的通道数"),
+    };
+    let maxval = check_maxval(maxval.ok_or("缺少最大样本值")?)?;
+    let count = sample_count(width, height, depth)?;
+    let samples = binary_samples(header.body(), count, maxval)?;
+    bitmap(width, height, to_pixels(&samples, depth))
+}
+
+/// 按像素依次输出前 `depth` 个通道，通道顺序为红、绿、蓝、透明度
+fn channel_bytes(bitmap: &Bitmap, depth: usize) -> impl Iterator<Item = u8> + '_ {
+    bitmap
+        .pixels
+        .iter()
+        .flat_map(move |c| [c.red, c.green, c.blue, c.alpha].into_iter().take(depth))
+}
+
+pub(crate) fn encode_ppm(bitmap: &Bitmap) -> Vec<u8> {
+    let mut out = format!("P6\n{} {}\n255\n", bitmap.size.width, bitmap.size.height).into_bytes();
+    out.extend(channel_bytes(bitmap, 3));
+    out
+}
+
+/// 编码为 PAM 图像，含有透明像素时输出 RGB_ALPHA 类型
+pub(crate) fn encode_pam(bitmap: &Bitmap) -> Vec<u8> {
+    let alpha = bitmap.pixels.iter().any(|c| !c.is_opaque());
+    let (depth, tupltype) = if alpha { (4, "RGB_ALPHA") } else { (3, "RGB") };
+    let mut out = format!(
+        "P7\nWIDTH {}\nHEIGHT {}\nDEPTH {}\nMAXVAL 255\nTUPLTYPE {}\nENDHDR\n",
+        bitmap.size.width, bitmap.size.height, depth, tupltype
+    )
+    .into_bytes();
+    out.extend(channel_bytes(bitmap, depth));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Bitmap {
+        let pixels = vec![Color::RED, Color::GREEN, Color::BLUE, Color::new(1, 2, 3)];
+        Bitmap::from_pixels(Sizei::new(2, 2), pixels).unwrap()
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let bitmap = sample();
+        assert_eq!(decode(&encode_ppm(&bitmap)).unwrap(), bitmap);
+        assert_eq!(decode(&encode_pam(&bitmap)).unwrap(), bitmap);
+    }
+
+    #[test]
+    fn ascii_ppm_test() {
+        let text = b"P3\n# comment\n2 1\n15\n15 0 0  0 15 15\n";
+        let bitmap = decode(text).unwrap();
+        assert_eq!(bitmap.pixels(), &[Color::RED, Color::new(0, 255, 255)]);
+    }
+
+    #[test]
+    fn pam_variants_test() {
+        let mut gray =
+            b"P7\nWIDTH 2\nHEIGHT 1\nDEPTH 2\nMAXVAL 65535\nTUPLTYPE GRAYSCALE_ALPHA\nENDHDR\n"
+                .to_vec();
+        gray.extend([0xff, 0xff, 0x00, 0x00, 0x80, 0x00, 0xff, 0xff]);
+        let bitmap = decode(&gray).unwrap();
+        assert_eq!(
+            bitmap.pixels(),
+            &[Color::rgba(255, 255, 255, 0), Color::new(128, 128, 128)]
+        );
+    }
+
+    #[test]
+    fn alpha_test() {
+        let mut bitmap = sample();
+        bitmap.pixels[1] = Color::rgba(1, 2, 3, 4);
+        let pam = encode_pam(&bitmap);
+        assert!(pam.starts_with(b"P7\nWIDTH 2\nHEIGHT 2\nDEPTH 4\n"));
+        assert_eq!(decode(&pam).unwrap(), bitmap);
+        assert_eq!(
+            decode(&encode_ppm(&bitmap)).unwrap().pixels()[1],
+            Color::new(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn invalid_data_test() {
+        assert!(decode(b"P6\n2 2\n255\n\x00\x00").is_err());
+        assert!(decode(b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 5\nMAXVAL 255\nENDHDR\n").is_err());
+        assert!(decode(b"P5\n1 1\n255\n\x00").is_err());
+    }
+
+    #[test]
+    fn oversized_test() {
+        assert!(decode(b"P3 4000000000 4000000000 255 1 2 3").is_err());
+        assert!(decode(b"P6 4000000000 4000000000 65535\n\x00").is_err());
+        assert!(decode(b"P3 100000 100000 255 1 2 3").is_err());
+        let pam = b"P7\nWIDTH 4000000000\nHEIGHT 4000000000\nDEPTH 4\nMAXVAL 255\nENDHDR\n";
+        assert!(decode(pam).is_err());
+    }
+}
@@ -0,0 +1,454 @@
+// zlib（RFC 1950）与 deflate（RFC 1951）的编解码
+//
+// 解码支持全部三种块类型；编码使用基于哈希链的 LZ77 匹配与固定哈夫曼编码
+
+type Result<T> = std::result::Result<T, &'static str>;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// 动态块中码长码的排列顺序
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+pub(crate) fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+// 解码
+
+/// 按最低有效位优先的顺序读取比特
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    buf: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            buf: 0,
+            count: 0,
+        }
+    }
+
+    fn bits(&mut self, n: u32) -> Result<u32> {
+        while self.count < n {
+            let byte = *self.data.get(self.pos).ok_or("数据意外结束")?;
+            self.pos += 1;
+            self.buf |= (byte as u32) << self.count;
+            self.count += 8;
+        }
+        let value = self.buf & ((1u64 << n) - 1) as u32;
+        self.buf >>= n;
+        self.count -= n;
+        Ok(value)
+    }
+
+    /// 丢弃当前字节中剩余的比特
+    fn align(&mut self) {
+        self.buf = 0;
+        self.count = 0;
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + n)
+            .ok_or("数据意外结束")?;
+        self.pos += n;
+        Ok(bytes)
+    }
+}
+
+/// 范式哈夫曼解码表
+struct Huffman {
+    count: [u16; 16],
+    symbol: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self> {
+        let mut count = [0u16; 16];
+        for &len in lengths {
+            count[len as usize] += 1;
+        }
+        count[0] = 0;
+        let mut left = 1i32;
+        for &n in &count[1..] {
+            left = left * 2 - n as i32;
+            if left < 0 {
+                return Err("无效的哈夫曼码长");
+            }
+        }
+        let mut offset = [0u16; 16];
+        for len in 1..15 {
+            offset[len + 1] = offset[len] + count[len];
+        }
+        let mut symbol = vec![0; lengths.len()];
+        for (s, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                symbol[offset[len as usize] as usize] = s as u16;
+                offset[len as usize] += 1;
+            }
+        }
+        Ok(Self { count, symbol })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for len in 1..16 {
+            code |= reader.bits(1)? as i32;
+            let count = self.count[len] as i32;
+            if code - count < first {
+                return Ok(self.symbol[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("无效的哈夫曼编码")
+    }
+}
+
+fn fixed_tables() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    let literal = Huffman::new(&lengths).unwrap();
+    let distance = Huffman::new(&[5; 30]).unwrap();
+    (literal, distance)
+}
+
+fn dynamic_tables(reader: &mut BitReader) -> Result<(Huffman, Huffman)> {
+    let nlen = reader.bits(5)? as usize + 257;
+    let ndist = reader.bits(5)? as usize + 1;
+    let ncode = reader.bits(4)? as usize + 4;
+    if nlen > 286 || ndist > 30 {
+        return Err("无效的动态块");
+    }
+    let mut lengths = [0u8; 19];
+    for &i in &CODE_LENGTH_ORDER[..ncode] {
+        lengths[i] = reader.bits(3)? as u8;
+    }
+    let code = Huffman::new(&lengths)?;
+
+    let mut lengths = vec![0u8; nlen + ndist];
+    let mut i = 0;
+    while i < lengths.len() {
+        let symbol = code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let prev = *lengths[..i].last().ok_or("无效的动态块")?;
+                (prev, 3 + reader.bits(2)? as usize)
+            }
+            17 => (0, 3 + reader.bits(3)? as usize),
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if i + repeat > lengths.len() {
+            return Err("无效的动态块");
+        }
+        lengths[i..i + repeat].fill(value);
+        i += repeat;
+    }
+    if lengths[256] == 0 {
+        return Err("缺少块结束符");
+    }
+    Ok((
+        Huffman::new(&lengths[..nlen])?,
+        Huffman::new(&lengths[nlen..])?,
+    ))
+}
+
+fn inflate_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    literal: &Huffman,
+    distance: &Huffman,
+) -> Result<()> {
+    loop {
+        let symbol = literal.decode(reader)? as usize;
+        match symbol {
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let i = symbol - 257;
+                if i >= 29 {
+                    return Err("无效的长度码");
+                }
+                let len = LENGTH_BASE[i] as usize + reader.bits(LENGTH_EXTRA[i] as u32)? as usize;
+                let d = distance.decode(reader)? as usize;
+                if d >= 30 {
+                    return Err("无效的距离码");
+                }
+                let dist = DIST_BASE[d] as usize + reader.bits(DIST_EXTRA[d] as u32)? as usize;
+                if dist > out.len() {
+                    return Err("距离超出已解码的数据");
+                }
+                let start = out.len() - dist;
+                for k in 0..len {
+                    out.push(out[start + k]);
+                }
+            }
+        }
+    }
+}
+
+/// 解码 deflate 数据流
+pub(crate) fn inflate(data: &[u8]) -> Result<Vec<u8>> {
+    let mut reader = BitReader::new(data);
+    let mut out = Vec::new();
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align();
+                let header = reader.bytes(4)?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                let nlen = u16::from_le_bytes([header[2], header[3]]);
+                if len != !nlen {
+                    return Err("无效的存储块");
+                }
+                out.extend_from_slice(reader.bytes(len as usize)?);
+            }
+            1 => {
+                let (literal, distance) = fixed_tables();
+                inflate_block(&mut reader, &mut out, &literal, &distance)?;
+            }
+            2 => {
+                let (literal, distance) = dynamic_tables(&mut reader)?;
+                inflate_block(&mut reader, &mut out, &literal, &distance)?;
+            }
+            _ => return Err("无效的块类型"),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+/// 解码 zlib 数据流并校验其 Adler-32 校验和
+pub(crate) fn decompress(data: &[u8]) -> Result<Vec<u8>> {
+    if data.len() < 6 {
+        return Err("数据意外结束");
+    }
+    let (cmf, flg) = (data[0], data[1]);
+    if cmf & 0x0f != 8 || cmf >> 4 > 7 || !(cmf as u16 * 256 + flg as u16).is_multiple_of(31) {
+        return Err("无效的 zlib 头");
+    }
+    if flg & 0x20 != 0 {
+        return Err("不支持预设字典");
+    }
+    let out = inflate(&data[2..])?;
+    let checksum = data[data.len() - 4..].try_into().unwrap();
+    if adler32(&out) != u32::from_be_bytes(checksum) {
+        return Err("校验和不匹配");
+    }
+    Ok(out)
+}
+
+// 编码
+
+/// 按最低有效位优先的顺序写入比特
+struct BitWriter {
+    out: Vec<u8>,
+    buf: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn bits(&mut self, value: u32, n: u32) {
+        self.buf |= (value as u64) << self.count;
+        self.count += n;
+        while self.count >= 8 {
+            self.out.push(self.buf as u8);
+            self.buf >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// 写入哈夫曼码，哈夫曼码以最高有效位优先的顺序存放
+    fn code(&mut self, code: u32, n: u32) {
+        self.bits(code.reverse_bits() >> (32 - n), n);
+    }
+
+    fn literal(&mut self, symbol: u32) {
+        match symbol {
+            0..=143 => self.code(0x30 + symbol, 8),
+            144..=255 => self.code(0x190 + symbol - 144, 9),
+            256..=279 => self.code(symbol - 256, 7),
+            _ => self.code(0xc0 + symbol - 280, 8),
+        }
+    }
+
+    fn matched(&mut self, len: usize, dist: usize) {
+        let i = LENGTH_BASE
+            .iter()
+            .rposition(|&b| b as usize <= len)
+            .unwrap();
+        self.literal(257 + i as u32);
+        self.bits(
+            (len - LENGTH_BASE[i] as usize) as u32,
+            LENGTH_EXTRA[i] as u32,
+        );
+        let d = DIST_BASE.iter().rposition(|&b| b as usize <= dist).unwrap();
+        self.code(d as u32, 5);
+        self.bits((dist - DIST_BASE[d] as usize) as u32, DIST_EXTRA[d] as u32);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.out.push(self.buf as u8);
+        }
+        self.out
+    }
+}
+
+const WINDOW: usize = 1 << 15;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const MAX_CHAIN: usize = 64;
+const HASH_BITS: u32 = 15;
+
+/// 将位置 `i` 插入其哈希值对应的链
+fn insert(data: &[u8], head: &mut [usize], prev: &mut [usize], i: usize) {
+    if i + MIN_MATCH <= data.len() {
+        let h = hash(&data[i..]);
+        prev[i] = head[h];
+        head[h] = i;
+    }
+}
+
+fn hash(data: &[u8]) -> usize {
+    let v = (data[0] as u32) << 16 | (data[1] as u32) << 8 | data[2] as u32;
+    (v.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+}
+
+/// 以单个固定哈夫曼块编码 deflate 数据流
+pub(crate) fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter {
+        out: Vec::with_capacity(data.len() / 2),
+        buf: 0,
+        count: 0,
+    };
+    writer.bits(1, 1);
+    writer.bits(1, 2);
+
+    // head 记录每个哈希值最近出现的位置，prev 将同一哈希值的位置串成链
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; data.len()];
+    let mut i = 0;
+    while i < data.len() {
+        let (mut best_len, mut best_dist) = (0, 0);
+        if i + MIN_MATCH <= data.len() {
+            let max = MAX_MATCH.min(data.len() - i);
+            let mut candidate = head[hash(&data[i..])];
+            let mut chain = 0;
+            while candidate != usize::MAX && i - candidate <= WINDOW && chain < MAX_CHAIN {
+                let len = data[candidate..]
+                    .iter()
+                    .zip(&data[i..i + max])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if len > best_len {
+                    (best_len, best_dist) = (len, i - candidate);
+                    if len == max {
+                        break;
+                    }
+                }
+                candidate = prev[candidate];
+                chain += 1;
+            }
+        }
+        if best_len >= MIN_MATCH {
+            writer.matched(best_len, best_dist);
+            for k in i..i + best_len {
+                insert(data, &mut head, &mut prev, k);
+            }
+            i += best_len;
+        } else {
+            writer.literal(data[i] as u32);
+            insert(data, &mut head, &mut prev, i);
+            i += 1;
+        }
+    }
+    writer.literal(256);
+    writer.finish()
+}
+
+/// 编码为 zlib 数据流
+pub(crate) fn compress(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x9c];
+    out.extend(deflate(data));
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let mut data = b"ponsic ponsic ponsic graphics".repeat(50);
+        data.extend((0..5000u32).map(|i| (i * i % 251) as u8));
+        let packed = compress(&data);
+        assert!(packed.len() < data.len());
+        assert_eq!(decompress(&packed).unwrap(), data);
+        assert_eq!(decompress(&compress(&[])).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn stored_and_dynamic_block_test() {
+        // 存储块："abc"
+        let stored = [0x01, 0x03, 0x00, 0xfc, 0xff, b'a', b'b', b'c'];
+        assert_eq!(inflate(&stored).unwrap(), b"abc");
+        // 由 zlib 压缩得到的动态哈夫曼块
+        let dynamic = [
+            0x78, 0xda, 0x1d, 0x88, 0xc1, 0x11, 0x00, 0x30, 0x0c, 0x40, 0x66, 0x25, 0xf6, 0x9f,
+            0xa1, 0x69, 0x1e, 0xee, 0x20, 0x03, 0xf2, 0x59, 0x09, 0x26, 0xdb, 0xd6, 0xfb, 0xa9,
+            0xe1, 0xf4, 0x00, 0x39, 0x8c, 0x0f, 0x51,
+        ];
+        assert_eq!(
+            decompress(&dynamic).unwrap(),
+            b"bacaabaaabacaadaacdbdbaabbcaabadbbbdabcd"
+        );
+    }
+
+    #[test]
+    fn corrupt_data_test() {
+        let mut packed = compress(b"some data to corrupt");
+        let last = packed.len() - 1;
+        packed[last] ^= 1;
+        assert!(decompress(&packed).is_err());
+        assert!(decompress(&[0x78, 0x9c]).is_err());
+    }
+}
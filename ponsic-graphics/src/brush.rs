@@ -0,0 +1,484 @@
+use crate::Bitmap;
+use ponsic_color::{Color, Gradient};
+use ponsic_types::{Pointf, Pointi, Sizei, Transformf};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// 阴影线样式
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum HatchStyle {
+    BDiagonal,
+    Cross,
+    DiagCross,
+    FDiagonal,
+    Horizontal,
+    Vertical,
+}
+
+impl HatchStyle {
+    /// 判断阴影线图案是否覆盖指定像素
+    ///
+    /// 图案以 8 × 8 像素为周期，并与绘图目标的原点对齐
+    pub fn covers(self, x: i32, y: i32) -> bool {
+        let horizontal = y.rem_euclid(8) == 0;
+        let vertical = x.rem_euclid(8) == 0;
+        let fdiagonal = (x - y).rem_euclid(8) == 0;
+        let bdiagonal = (x + y).rem_euclid(8) == 0;
+        match self {
+            HatchStyle::BDiagonal => bdiagonal,
+            HatchStyle::Cross => horizontal || vertical,
+            HatchStyle::DiagCross => fdiagonal || bdiagonal,
+            HatchStyle::FDiagonal => fdiagonal,
+            HatchStyle::Horizontal => horizontal,
+            HatchStyle::Vertical => vertical,
+        }
+    }
+}
+
+/// 线性渐变
+///
+/// 颜色沿自 `start` 至 `end` 的方向变化，垂直于该方向的直线上颜色相同；
+/// 坐标与绘图坐标一致，整数坐标位于像素中心
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient {
+    pub start: Pointf,
+    pub end: Pointf,
+    pub gradient: Gradient,
+}
+
+impl LinearGradient {
+    pub fn new(start: Pointf, end: Pointf, gradient: Gradient) -> Self {
+        Self {
+            start,
+            end,
+            gradient,
+        }
+    }
+
+    /// 返回点 `p` 在渐变上的位置，`start` 处为 0，`end` 处为 1
+    ///
+    /// 起点与终点重合时，所有点均位于 1 处
+    pub fn offset_at(&self, p: Pointf) -> f32 {
+        let (dx, dy) = (self.end.x - self.start.x, self.end.y - self.start.y);
+        let length = dx * dx + dy * dy;
+        if length == 0.0 {
+            return 1.0;
+        }
+        ((p.x - self.start.x) * dx + (p.y - self.start.y) * dy) / length
+    }
+}
+
+/// 径向渐变
+///
+/// 颜色自 `center` 向外变化，在距中心 `radius` 处到达渐变的终点
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradient {
+    pub center: Pointf,
+    pub radius: f32,
+    pub gradient: Gradient,
+}
+
+impl RadialGradient {
+    pub fn new(center: Pointf, radius: f32, gradient: Gradient) -> Self {
+        Self {
+            center,
+            radius,
+            gradient,
+        }
+    }
+
+    /// 返回点 `p` 在渐变上的位置，中心处为 0，距中心 `radius` 处为 1
+    ///
+    /// 半径不为正数时，所有点均位于 1 处
+    pub fn offset_at(&self, p: Pointf) -> f32 {
+        if self.radius <= 0.0 {
+            return 1.0;
+        }
+        (p.x - self.center.x).hypot(p.y - self.center.y) / self.radius
+    }
+}
+
+/// 以图像平铺的图案
+///
+/// 图像像素 `(i, j)` 覆盖图案空间中的 `[i, i + 1) × [j, j + 1)`，
+/// 图案空间经 `transform` 变换后，像素 `(x, y)` 覆盖 `[x, x + 1) × [y, y + 1)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub image: Arc<Bitmap>,
+    pub transform: Transformf,
+}
+
+impl Pattern {
+    /// 创建以原点为起点、不经变换平铺图像的图案
+    pub fn new(image: impl Into<Arc<Bitmap>>) -> Self {
+        Self {
+            image: image.into(),
+            transform: Transformf::IDENTITY,
+        }
+    }
+
+    /// 设置图案的变换
+    pub fn set_transform(mut self, transform: Transformf) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// 返回覆盖像素 `(x, y)` 中心的图像像素的颜色
+    ///
+    /// 图像为空或变换不可逆时返回 `None`
+    pub fn color_at(&self, x: i32, y: i32) -> Option<Color> {
+        self.sample(&self.transform.inverse()?, x, y)
+    }
+
+    /// 以预先求得的逆变换取色，参见 \[`color_at()`\]
+    fn sample(&self, inverse: &Transformf, x: i32, y: i32) -> Option<Color> {
+        let size = self.image.size();
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+        let p = inverse.transform_point(Pointf::new(x as f32 + 0.5, y as f32 + 0.5));
+        let i = (p.x.floor() as i64).rem_euclid(size.width as i64);
+        let j = (p.y.floor() as i64).rem_euclid(size.height as i64);
+        self.image.pixel(Pointi::new(i as i32, j as i32))
+    }
+}
+
+/// 画刷描述
+///
+/// 画刷的比较与哈希按浮点数的位模式进行，因此 `0.0` 与 `-0.0` 不相等，`NaN` 与自身相等
+#[derive(Debug, Clone)]
+pub enum GenBrush {
+    Solid(Color),
+    Hatch(Color, HatchStyle),
+    LinearGradient(LinearGradient),
+    RadialGradient(RadialGradient),
+    Pattern(Pattern),
+}
+
+impl Default for GenBrush {
+    fn default() -> Self {
+        GenBrush::Solid(Color::BLACK)
+    }
+}
+
+impl GenBrush {
+    /// 由画刷描述创建绘图后端的画刷，如 GDI 画刷
+    pub fn create<B: From<Self>>(self) -> B {
+        B::from(self)
+    }
+
+    /// 返回画刷在指定像素处的颜色
+    ///
+    /// # Note
+    /// 若该像素未被画刷覆盖（如阴影线的间隙），则返回 `None`
+    pub fn color_at(&self, x: i32, y: i32) -> Option<Color> {
+        match self {
+            GenBrush::Solid(color) => Some(*color),
+            GenBrush::Hatch(color, style) => style.covers(x, y).then_some(*color),
+            GenBrush::Pattern(pattern) => pattern.color_at(x, y),
+            _ => self
+                .gradient_at(Pointf::new(x as f32, y as f32))
+                .map(|(gradient, t)| gradient.color_at(t)),
+        }
+    }
+
+    /// 返回逐像素取色的函数，结果与 \[`color_at()`\] 相同
+    ///
+    /// 图案的逆变换等与像素无关的数据只计算一次，适用于大量像素的取色
+    pub fn sampler(&self) -> impl Fn(i32, i32) -> Option<Color> + '_ {
+        let inverse = match self {
+            GenBrush::Pattern(pattern) => pattern.transform.inverse(),
+            _ => None,
+        };
+        move |x, y| match self {
+            GenBrush::Pattern(pattern) => pattern.sample(inverse.as_ref()?, x, y),
+            _ => self.color_at(x, y),
+        }
+    }
+
+    /// 返回画刷的主要颜色
+    ///
+    /// 渐变画刷返回其第一个色标的颜色，图案画刷返回图像左上角像素的颜色
+    pub fn color(&self) -> Color {
+        match self {
+            GenBrush::Solid(color) | GenBrush::Hatch(color, _) => *color,
+            GenBrush::Pattern(pattern) => pattern
+                .image
+                .pixel(Pointi::new(0, 0))
+                .unwrap_or(Color::TRANSPARENT),
+            GenBrush::LinearGradient(LinearGradient { gradient, .. })
+            | GenBrush::RadialGradient(RadialGradient { gradient, .. }) => gradient
+                .stops()
+                .first()
+                .map_or(Color::TRANSPARENT, |stop| stop.color),
+        }
+    }
+
+    /// 渐变画刷返回其渐变及点 `p` 在渐变上的位置
+    fn gradient_at(&self, p: Pointf) -> Option<(&Gradient, f32)> {
+        match self {
+            GenBrush::LinearGradient(linear) => Some((&linear.gradient, linear.offset_at(p))),
+            GenBrush::RadialGradient(radial) => Some((&radial.gradient, radial.offset_at(p))),
+            _ => None,
+        }
+    }
+
+    /// 将画刷绘制为指定尺寸的位图，位图的原点与绘图坐标的原点重合
+    ///
+    /// 渐变画刷按查找表取色，可供不支持渐变的绘图目标生成图案画刷；
+    /// 未被画刷覆盖的像素是透明的
+    pub fn render(&self, size: Sizei) -> Bitmap {
+        let table = match self {
+            GenBrush::LinearGradient(LinearGradient { gradient, .. })
+            | GenBrush::RadialGradient(RadialGradient { gradient, .. }) => {
+                gradient.sample(GRADIENT_TABLE_SIZE)
+            }
+            _ => Vec::new(),
+        };
+        let last = (GRADIENT_TABLE_SIZE - 1) as f32;
+        let width = size.width.max(1) as usize;
+        let sampler = self.sampler();
+        let mut bitmap = Bitmap::new(size, Color::TRANSPARENT);
+        for (i, pixel) in bitmap.pixels_mut().iter_mut().enumerate() {
+            let (x, y) = ((i % width) as i32, (i / width) as i32);
+            *pixel = match self.gradient_at(Pointf::new(x as f32, y as f32)) {
+                Some((gradient, t)) => table[(gradient.spread().apply(t) * last).round() as usize],
+                None => sampler(x, y).unwrap_or(Color::TRANSPARENT),
+            };
+        }
+        bitmap
+    }
+}
+
+impl GenBrush {
+    /// 用于比较与哈希的键，浮点数被编码为与 [`f32::total_cmp()`] 次序相同的整数
+    fn key(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut head = Vec::new();
+        let mut image: &[Color] = &[];
+        match self {
+            GenBrush::Solid(color) => head.extend([0, color.to_argb()]),
+            GenBrush::Hatch(color, style) => head.extend([1, color.to_argb(), *style as u32]),
+            GenBrush::LinearGradient(linear) => {
+                let LinearGradient { start, end, .. } = *linear;
+                head.push(2);
+                head.extend([start.x, start.y, end.x, end.y].map(float_key));
+                gradient_key(&linear.gradient, &mut head);
+            }
+            GenBrush::RadialGradient(radial) => {
+                let RadialGradient { center, radius, .. } = *radial;
+                head.push(3);
+                head.extend([center.x, center.y, radius].map(float_key));
+                gradient_key(&radial.gradient, &mut head);
+            }
+            GenBrush::Pattern(pattern) => {
+                let t = pattern.transform;
+                let size = pattern.image.size();
+                head.push(4);
+                head.extend([t.m11, t.m12, t.m21, t.m22, t.dx, t.dy].map(float_key));
+                head.extend([size.width, size.height]);
+                image = pattern.image.pixels();
+            }
+        }
+        head.into_iter().chain(image.iter().map(|c| c.to_argb()))
+    }
+}
+
+/// 将浮点数编码为整数，整数的大小次序与 [`f32::total_cmp()`] 相同
+fn float_key(value: f32) -> u32 {
+    let bits = value.to_bits();
+    if bits >> 31 == 1 {
+        !bits
+    } else {
+        bits | 1 << 31
+    }
+}
+
+/// 将渐变的插值空间、扩展方式与各色标追加至键
+fn gradient_key(gradient: &Gradient, key: &mut Vec<u32>) {
+    key.extend([gradient.space() as u32, gradient.spread() as u32]);
+    key.push(gradient.stops().len() as u32);
+    for stop in gradient.stops() {
+        key.extend([float_key(stop.offset), stop.color.to_argb()]);
+    }
+}
+
+impl PartialEq for GenBrush {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for GenBrush {}
+
+impl PartialOrd for GenBrush {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GenBrush {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(other.key())
+    }
+}
+
+impl Hash for GenBrush {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().for_each(|k| k.hash(state));
+    }
+}
+
+/// 渐变查找表的长度
+const GRADIENT_TABLE_SIZE: usize = 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hatch_test() {
+        let brush = GenBrush::Hatch(Color::RED, HatchStyle::Cross);
+        assert_eq!(brush.color_at(0, 3), Some(Color::RED));
+        assert_eq!(brush.color_at(3, 16), Some(Color::RED));
+        assert_eq!(brush.color_at(3, 3), None);
+        assert!(HatchStyle::FDiagonal.covers(5, 5));
+        assert!(HatchStyle::BDiagonal.covers(5, 3));
+        assert!(!HatchStyle::BDiagonal.covers(5, 5));
+    }
+
+    #[test]
+    fn gradient_test() {
+        let gradient = Gradient::from_colors(&[Color::BLACK, Color::WHITE]);
+        let linear = GenBrush::LinearGradient(LinearGradient::new(
+            Pointf::new(0.0, 0.0),
+            Pointf::new(10.0, 0.0),
+            gradient.clone(),
+        ));
+        assert_eq!(linear.color_at(5, 100), Some(Color::new(128, 128, 128)));
+        assert_eq!(linear.color_at(-5, 0), Some(Color::BLACK));
+        assert_eq!(linear.color(), Color::BLACK);
+
+        let radial =
+            GenBrush::RadialGradient(RadialGradient::new(Pointf::new(5.0, 5.0), 4.0, gradient));
+        assert_eq!(radial.color_at(5, 5), Some(Color::BLACK));
+        assert_eq!(radial.color_at(5, 9), Some(Color::WHITE));
+        assert_eq!(radial.color_at(7, 5), Some(Color::new(128, 128, 128)));
+    }
+
+    #[test]
+    fn render_test() {
+        let brush = GenBrush::LinearGradient(LinearGradient::new(
+            Pointf::new(0.0, 0.0),
+            Pointf::new(0.0, 3.0),
+            Gradient::from_colors(&[Color::RED, Color::BLUE]),
+        ));
+        let bitmap = brush.render(Sizei::new(2, 4));
+        for y in 0..4 {
+            assert_eq!(
+                bitmap.pixel(Pointi::new(1, y)),
+                brush.color_at(1, y),
+                "{}",
+                y
+            );
+        }
+        let hatch = GenBrush::Hatch(Color::RED, HatchStyle::Horizontal).render(Sizei::new(2, 2));
+        assert_eq!(
+            hatch.pixels(),
+            &[
+                Color::RED,
+                Color::RED,
+                Color::TRANSPARENT,
+                Color::TRANSPARENT
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_test() {
+        use std::collections::HashSet;
+
+        let gradient = Gradient::from_colors(&[Color::RED, Color::BLUE]);
+        let linear = |x: f32| {
+            GenBrush::LinearGradient(LinearGradient::new(
+                Pointf::new(0.0, 0.0),
+                Pointf::new(x, 0.0),
+                gradient.clone(),
+            ))
+        };
+        assert_eq!(linear(1.0), linear(1.0));
+        assert_ne!(linear(0.0), linear(-0.0));
+        assert_eq!(linear(f32::NAN), linear(f32::NAN));
+        assert!(linear(-1.0) < linear(1.0) && linear(1.0) < linear(2.0));
+        assert!(GenBrush::Solid(Color::WHITE) < GenBrush::Hatch(Color::BLACK, HatchStyle::Cross));
+
+        let image = Bitmap::new(Sizei::new(2, 2), Color::RED);
+        let pattern = GenBrush::Pattern(Pattern::new(image.clone()));
+        let brushes: HashSet<_> = [
+            linear(1.0),
+            linear(1.0),
+            pattern.clone(),
+            GenBrush::Pattern(Pattern::new(image)),
+            GenBrush::Pattern(Pattern::new(Bitmap::new(Sizei::new(2, 2), Color::BLUE))),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(brushes.len(), 3);
+    }
+
+    #[test]
+    fn pattern_test() {
+        let image = Bitmap::from_pixels(
+            Sizei::new(2, 1),
+            vec![Color::RED, Color::rgba(0, 0, 255, 128)],
+        )
+        .unwrap();
+        let pattern = Pattern::new(image);
+        assert_eq!(pattern.color_at(0, 5), Some(Color::RED));
+        assert_eq!(pattern.color_at(3, -1), Some(Color::rgba(0, 0, 255, 128)));
+        assert_eq!(pattern.color_at(-2, 0), Some(Color::RED));
+
+        let shifted = pattern
+            .clone()
+            .set_transform(Transformf::translation(1.0, 0.0));
+        assert_eq!(shifted.color_at(0, 0), Some(Color::rgba(0, 0, 255, 128)));
+        // 放大一倍后每个图像像素覆盖 2 × 2 个像素
+        let scaled = pattern.clone().set_transform(Transformf::scaling(2.0, 2.0));
+        assert_eq!(scaled.color_at(1, 1), Some(Color::RED));
+        assert_eq!(scaled.color_at(2, 0), Some(Color::rgba(0, 0, 255, 128)));
+        // 旋转 90° 后图像的行变为列
+        let rotated = pattern
+            .clone()
+            .set_transform(Transformf::new(0.0, 1.0, -1.0, 0.0, 0.0, 0.0));
+        assert_eq!(rotated.color_at(0, 0), Some(Color::RED));
+        assert_eq!(rotated.color_at(0, 1), Some(Color::rgba(0, 0, 255, 128)));
+        assert_eq!(
+            pattern
+                .clone()
+                .set_transform(Transformf::scaling(0.0, 1.0))
+                .color_at(0, 0),
+            None
+        );
+
+        let brush = GenBrush::Pattern(scaled);
+        let sampler = brush.sampler();
+        for (x, y) in [(0, 0), (2, 1), (-3, 7)] {
+            assert_eq!(sampler(x, y), brush.color_at(x, y));
+        }
+        let brush = GenBrush::Pattern(pattern.set_transform(Transformf::scaling(0.0, 1.0)));
+        assert_eq!(brush.sampler()(0, 0), None);
+
+        let brush = GenBrush::Pattern(Pattern::new(
+            Bitmap::from_pixels(
+                Sizei::new(2, 1),
+                vec![Color::RED, Color::rgba(0, 0, 255, 128)],
+            )
+            .unwrap(),
+        ));
+        assert_eq!(brush.color(), Color::RED);
+        assert_eq!(
+            brush.render(Sizei::new(3, 1)).pixels(),
+            &[Color::RED, Color::rgba(0, 0, 255, 128), Color::RED]
+        );
+    }
+}
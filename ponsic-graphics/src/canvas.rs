@@ -0,0 +1,302 @@
+use crate::{
+    CapStyle, CharSet, ClipPrecision, FontFamily, FontWeight, GenBrush, GenFont, GenPen,
+    HatchStyle, JoinStyle, LineStyle, LinearGradient, OutPrecision, PenStyle, Pitch, Quality,
+    RadialGradient,
+};
+use ponsic_color::{Color, Gradient};
+use ponsic_types::{Pointf, Pointi as Point, Recti as Rect};
+
+/// 与平台无关的 2D 绘图目标
+///
+/// 其方法与窗口 2D 绘图上下文中的 `DrawOpen`、`DrawClose`、`DrawPath`、
+/// `DrawPolygon`、`DrawPixel` 一一对应，并具有相同的语义；
+/// 基于此特征编写的绘图代码可以同时用于窗口、软件光栅化表面或其它绘图目标
+///
+/// # Note
+/// 闭合图形以画刷填充内部，以画笔描绘轮廓；开放图形只以画笔描绘
+pub trait Canvas {
+    /// 返回当前的画笔
+    fn pen(&self) -> GenPen<'_>;
+
+    /// 设置此后绘图所使用的画笔
+    fn set_pen(&mut self, pen: &GenPen);
+
+    /// 返回当前的画刷
+    fn brush(&self) -> &GenBrush;
+
+    /// 设置此后绘图所使用的画刷
+    fn set_brush(&mut self, brush: &GenBrush);
+
+    /// 若绘图目标可以绘制文本，则返回其文本绘图接口
+    fn as_text_canvas(&mut self) -> Option<&mut dyn TextCanvas> {
+        None
+    }
+
+    /// 设置画笔宽度
+    fn set_line_width(&mut self, width: usize) {
+        let pen = self.pen().to_buf();
+        self.set_pen(&pen.as_pen().set_width(width));
+    }
+
+    /// 设置画笔颜色
+    fn set_line_color(&mut self, color: Color) {
+        let pen = self.pen().to_buf();
+        self.set_pen(&pen.as_pen().set_color(color));
+    }
+
+    /// 设置画笔线型
+    fn set_line_style(&mut self, style: LineStyle) {
+        let pen = self.pen().to_buf();
+        self.set_pen(&pen.as_pen().set_line_style(style));
+    }
+
+    /// 设置画笔端点样式
+    fn set_cap_style(&mut self, style: CapStyle) {
+        let pen = self.pen().to_buf();
+        self.set_pen(&pen.as_pen().set_cap_style(style));
+    }
+
+    /// 设置画笔连接样式
+    fn set_join_style(&mut self, style: JoinStyle) {
+        let pen = self.pen().to_buf();
+        self.set_pen(&pen.as_pen().set_join_style(style));
+    }
+
+    /// 设置画笔样式
+    fn set_pen_style(&mut self, style: PenStyle) {
+        let pen = self.pen().to_buf();
+        self.set_pen(&pen.as_pen().set_pen_style(style));
+    }
+
+    /// 设置画刷的颜色，渐变画刷被替换为纯色画刷
+    fn set_brush_color(&mut self, color: Color) {
+        let brush = match *self.brush() {
+            GenBrush::Hatch(_, style) => GenBrush::Hatch(color, style),
+            _ => GenBrush::Solid(color),
+        };
+        self.set_brush(&brush);
+    }
+
+    /// 设置画刷的阴影线样式，渐变画刷以其第一个色标的颜色绘制阴影线
+    fn set_brush_hatch(&mut self, style: HatchStyle) {
+        let brush = GenBrush::Hatch(self.brush().color(), style);
+        self.set_brush(&brush);
+    }
+
+    /// 设置自 `start` 至 `end` 的线性渐变画刷
+    fn set_brush_linear_gradient(&mut self, start: Point, end: Point, gradient: Gradient) {
+        let to_f = |p: Point| Pointf::new(p.x as f32, p.y as f32);
+        let brush = LinearGradient::new(to_f(start), to_f(end), gradient);
+        self.set_brush(&GenBrush::LinearGradient(brush));
+    }
+
+    /// 设置以 `center` 为中心、`radius` 为半径的径向渐变画刷
+    fn set_brush_radial_gradient(&mut self, center: Point, radius: u32, gradient: Gradient) {
+        let center = Pointf::new(center.x as f32, center.y as f32);
+        let brush = RadialGradient::new(center, radius as f32, gradient);
+        self.set_brush(&GenBrush::RadialGradient(brush));
+    }
+
+    /// 绘制自 `p1` 至 `p2` 的线段，并将当前位置移动至 `p2`
+    fn line(&mut self, p1: Point, p2: Point) {
+        self.move_to(p1);
+        self.line_to(p2);
+    }
+
+    /// 绘制折线，不使用也不改变当前位置
+    fn polyline(&mut self, points: &[Point]);
+
+    /// 绘制椭圆弧
+    ///
+    /// 椭圆由 `border` 确定，弧自椭圆中心指向 `p1` 的射线处开始，
+    /// 逆时针延伸至椭圆中心指向 `p2` 的射线处
+    fn arc(&mut self, border: Rect, p1: Point, p2: Point);
+
+    /// 绘制自当前位置至圆弧起点的线段及圆弧，并将当前位置移动至圆弧终点
+    ///
+    /// 角度以度为单位，`end` 为圆弧扫过的角度，正值表示逆时针方向
+    fn angle_arc(&mut self, orgin: Point, radius: u32, start: f32, end: f32);
+
+    /// 绘制三次贝塞尔曲线，不使用也不改变当前位置
+    ///
+    /// `points` 由起点及随后每三个一组的控制点与终点组成
+    fn poly_bezier(&mut self, points: &[Point]);
+
+    /// 绘制矩形
+    fn rectangle(&mut self, rect: Rect);
+
+    /// 绘制内切于 `rect` 的椭圆
+    fn ellipse(&mut self, rect: Rect);
+
+    /// 绘制多边形，多边形将自动闭合
+    fn polygon(&mut self, points: &[Point]);
+
+    /// 绘制扇形，其弧的确定方式与 \[`arc()`\] 相同
+    fn pie(&mut self, rect: Rect, p1: Point, p2: Point);
+
+    /// 绘制弓形，其弧的确定方式与 \[`arc()`\] 相同
+    fn chord(&mut self, rect: Rect, p1: Point, p2: Point);
+
+    /// 绘制圆角矩形，`dx`、`dy` 为圆角所在椭圆的宽度与高度
+    fn round_rect(&mut self, rect: Rect, dx: u32, dy: u32);
+
+    /// 开始记录路径，此前记录的路径将被丢弃
+    ///
+    /// 记录期间绘制的图形不会立即绘制，而是加入路径中
+    fn begin_path(&mut self);
+
+    /// 结束记录路径
+    fn end_path(&mut self);
+
+    /// 将当前位置移动至 `p`
+    fn move_to(&mut self, p: Point);
+
+    /// 绘制自当前位置至 `p` 的线段，并将当前位置移动至 `p`
+    fn line_to(&mut self, p: Point);
+
+    /// 绘制自当前位置至弧起点的线段及椭圆弧，并将当前位置移动至弧终点
+    ///
+    /// 弧的确定方式与 \[`arc()`\] 相同
+    fn arc_to(&mut self, rect: Rect, p1: Point, p2: Point);
+
+    /// 自当前位置起绘制三次贝塞尔曲线，并将当前位置移动至曲线终点
+    fn poly_bezier_to(&mut self, points: &[Point]);
+
+    /// 自当前位置起绘制折线，并将当前位置移动至折线终点
+    fn polyline_to(&mut self, points: &[Point]);
+
+    /// 闭合路径中的当前图形
+    fn close_figure(&mut self);
+
+    /// 以画笔描绘路径，并丢弃该路径
+    fn stroke_path(&mut self);
+
+    /// 以画刷填充路径，并丢弃该路径
+    fn fill_path(&mut self);
+
+    /// 以画刷填充路径并以画笔描绘路径，然后丢弃该路径
+    fn stroke_and_fill_path(&mut self);
+
+    /// 绘制一组多边形，`count` 依次指定每个多边形的顶点数
+    fn poly_polygon(&mut self, points: &[Point], count: &[u32]);
+
+    /// 绘制一组折线，`count` 依次指定每条折线的顶点数
+    fn poly_polyline(&mut self, points: &[Point], count: &[u32]) {
+        let mut start = 0;
+        for &n in count {
+            let end = (start + n as usize).min(points.len());
+            self.polyline(&points[start..end]);
+            start = end;
+        }
+    }
+
+    /// 将指定位置的像素设置为指定颜色
+    fn draw_pixel(&mut self, p: Point, color: Color);
+}
+
+/// 可以绘制文本的绘图目标
+///
+/// 其方法与窗口 2D 绘图上下文中的 `DrawText::out_text` 及字体设置方法一一对应
+pub trait TextCanvas: Canvas {
+    /// 返回当前的字体
+    fn font(&self) -> &GenFont;
+
+    /// 设置此后绘制文本所使用的字体
+    fn set_font(&mut self, font: &GenFont);
+
+    /// 设置此后绘制文本所使用的颜色
+    fn set_text_color(&mut self, color: Color);
+
+    /// 以 `p` 为左上角绘制单行文本
+    fn out_text(&mut self, text: &str, p: Point);
+
+    /// 设置字体高度
+    fn set_font_height(&mut self, height: i32) {
+        let font = self.font().clone().set_height(height);
+        self.set_font(&font);
+    }
+
+    /// 设置字体平均字符宽度
+    fn set_font_width(&mut self, width: i32) {
+        let font = self.font().clone().set_width(width);
+        self.set_font(&font);
+    }
+
+    /// 设置文本行的倾斜角度，以十分之一度为单位
+    fn set_font_escapement(&mut self, escapement: i32) {
+        let font = self.font().clone().set_escapement(escapement);
+        self.set_font(&font);
+    }
+
+    /// 设置字符的倾斜角度，以十分之一度为单位
+    fn set_font_orientation(&mut self, orientation: i32) {
+        let font = self.font().clone().set_orientation(orientation);
+        self.set_font(&font);
+    }
+
+    /// 设置字体粗细
+    fn set_font_weight(&mut self, weight: FontWeight) {
+        let font = self.font().clone().set_weight(weight);
+        self.set_font(&font);
+    }
+
+    /// 设置是否为斜体
+    fn set_font_italic(&mut self, italic: bool) {
+        let font = self.font().clone().set_italic(italic);
+        self.set_font(&font);
+    }
+
+    /// 设置是否带下划线
+    fn set_font_underline(&mut self, underline: bool) {
+        let font = self.font().clone().set_underline(underline);
+        self.set_font(&font);
+    }
+
+    /// 设置是否带删除线
+    fn set_font_strikeout(&mut self, strikeout: bool) {
+        let font = self.font().clone().set_strikeout(strikeout);
+        self.set_font(&font);
+    }
+
+    /// 设置字符集
+    fn set_font_charset(&mut self, charset: CharSet) {
+        let font = self.font().clone().set_charset(charset);
+        self.set_font(&font);
+    }
+
+    /// 设置输出精度
+    fn set_font_outprecision(&mut self, outprecision: OutPrecision) {
+        let font = self.font().clone().set_outprecision(outprecision);
+        self.set_font(&font);
+    }
+
+    /// 设置裁剪精度
+    fn set_font_clipprecision(&mut self, clipprecision: ClipPrecision) {
+        let font = self.font().clone().set_clipprecision(clipprecision);
+        self.set_font(&font);
+    }
+
+    /// 设置输出质量
+    fn set_font_quality(&mut self, quality: Quality) {
+        let font = self.font().clone().set_quality(quality);
+        self.set_font(&font);
+    }
+
+    /// 设置字符间距类型
+    fn set_font_pitch(&mut self, pitch: Pitch) {
+        let font = self.font().clone().set_pitch(pitch);
+        self.set_font(&font);
+    }
+
+    /// 设置字体族
+    fn set_font_family(&mut self, family: FontFamily) {
+        let font = self.font().clone().set_family(family);
+        self.set_font(&font);
+    }
+
+    /// 设置字体名称
+    fn set_font_name(&mut self, name: &str) {
+        let font = self.font().clone().set_name(String::from(name));
+        self.set_font(&font);
+    }
+}
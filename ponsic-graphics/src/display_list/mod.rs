@@ -0,0 +1,438 @@
+mod text;
+
+pub use text::ParseDisplayListError;
+
+use crate::{Canvas, GenBrush, GenFont, GenPen, GenPenBuf, TextCanvas};
+use ponsic_color::Color;
+use ponsic_types::{Pointi as Point, Recti as Rect};
+
+/// 绘图命令
+///
+/// 每个命令对应 \[`Canvas`\] 或 \[`TextCanvas`\] 中的一次调用
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    SetPen(GenPenBuf),
+    SetBrush(GenBrush),
+    SetFont(GenFont),
+    SetTextColor(Color),
+    Line(Point, Point),
+    Polyline(Vec<Point>),
+    Arc(Rect, Point, Point),
+    AngleArc(Point, u32, f32, f32),
+    PolyBezier(Vec<Point>),
+    Rectangle(Rect),
+    Ellipse(Rect),
+    Polygon(Vec<Point>),
+    Pie(Rect, Point, Point),
+    Chord(Rect, Point, Point),
+    RoundRect(Rect, u32, u32),
+    BeginPath,
+    EndPath,
+    MoveTo(Point),
+    LineTo(Point),
+    ArcTo(Rect, Point, Point),
+    PolyBezierTo(Vec<Point>),
+    PolylineTo(Vec<Point>),
+    CloseFigure,
+    StrokePath,
+    FillPath,
+    StrokeAndFillPath,
+    PolyPolygon(Vec<Point>, Vec<u32>),
+    PolyPolyline(Vec<Point>, Vec<u32>),
+    DrawPixel(Point, Color),
+    OutText(String, Point),
+}
+
+impl Command {
+    /// 在指定绘图目标上执行此命令
+    ///
+    /// # Note
+    /// 若绘图目标不能绘制文本，则文本相关的命令将被忽略
+    pub fn apply(&self, canvas: &mut dyn Canvas) {
+        match self {
+            Command::SetPen(pen) => canvas.set_pen(&pen.as_pen()),
+            Command::SetBrush(brush) => canvas.set_brush(brush),
+            Command::SetFont(font) => {
+                if let Some(canvas) = canvas.as_text_canvas() {
+                    canvas.set_font(font);
+                }
+            }
+            Command::SetTextColor(color) => {
+                if let Some(canvas) = canvas.as_text_canvas() {
+                    canvas.set_text_color(*color);
+                }
+            }
+            Command::Line(p1, p2) => canvas.line(*p1, *p2),
+            Command::Polyline(points) => canvas.polyline(points),
+            Command::Arc(rect, p1, p2) => canvas.arc(*rect, *p1, *p2),
+            Command::AngleArc(orgin, radius, start, end) => {
+                canvas.angle_arc(*orgin, *radius, *start, *end)
+            }
+            Command::PolyBezier(points) => canvas.poly_bezier(points),
+            Command::Rectangle(rect) => canvas.rectangle(*rect),
+            Command::Ellipse(rect) => canvas.ellipse(*rect),
+            Command::Polygon(points) => canvas.polygon(points),
+            Command::Pie(rect, p1, p2) => canvas.pie(*rect, *p1, *p2),
+            Command::Chord(rect, p1, p2) => canvas.chord(*rect, *p1, *p2),
+            Command::RoundRect(rect, dx, dy) => canvas.round_rect(*rect, *dx, *dy),
+            Command::BeginPath => canvas.begin_path(),
+            Command::EndPath => canvas.end_path(),
+            Command::MoveTo(p) => canvas.move_to(*p),
+            Command::LineTo(p) => canvas.line_to(*p),
+            Command::ArcTo(rect, p1, p2) => canvas.arc_to(*rect, *p1, *p2),
+            Command::PolyBezierTo(points) => canvas.poly_bezier_to(points),
+            Command::PolylineTo(points) => canvas.polyline_to(points),
+            Command::CloseFigure => canvas.close_figure(),
+            Command::StrokePath => canvas.stroke_path(),
+            Command::FillPath => canvas.fill_path(),
+            Command::StrokeAndFillPath => canvas.stroke_and_fill_path(),
+            Command::PolyPolygon(points, count) => canvas.poly_polygon(points, count),
+            Command::PolyPolyline(points, count) => canvas.poly_polyline(points, count),
+            Command::DrawPixel(p, color) => canvas.draw_pixel(*p, *color),
+            Command::OutText(text, p) => {
+                if let Some(canvas) = canvas.as_text_canvas() {
+                    canvas.out_text(text, *p);
+                }
+            }
+        }
+    }
+}
+
+/// 绘图命令列表
+///
+/// 作为绘图目标时，记录所有绘图调用而不进行任何绘制；
+/// 记录的命令可以逐条检查、与文本相互转换，并可重放至任意绘图目标
+///
+/// # Note
+/// 初始的画笔、画刷与字体与窗口 2D 绘图上下文的初始状态相同
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayList {
+    commands: Vec<Command>,
+    pen: GenPenBuf,
+    brush: GenBrush,
+    font: GenFont,
+}
+
+impl Default for DisplayList {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            pen: GenPenBuf::default(),
+            brush: GenBrush::Solid(Color::WHITE),
+            font: GenFont::default(),
+        }
+    }
+}
+
+impl DisplayList {
+    /// 创建空的命令列表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 返回已记录的命令
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// 追加一条命令
+    pub fn push(&mut self, command: Command) {
+        match &command {
+            Command::SetPen(pen) => self.pen = pen.clone(),
+            Command::SetBrush(brush) => self.brush = brush.clone(),
+            Command::SetFont(font) => self.font = font.clone(),
+            _ => {}
+        }
+        self.commands.push(command);
+    }
+
+    /// 清空已记录的命令，并恢复初始的绘图状态
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 在指定绘图目标上依次执行所有命令
+    pub fn replay(&self, canvas: &mut dyn Canvas) {
+        for command in &self.commands {
+            command.apply(canvas);
+        }
+    }
+}
+
+impl Canvas for DisplayList {
+    fn pen(&self) -> GenPen<'_> {
+        self.pen.as_pen()
+    }
+
+    fn set_pen(&mut self, pen: &GenPen) {
+        self.push(Command::SetPen(pen.to_buf()));
+    }
+
+    fn brush(&self) -> &GenBrush {
+        &self.brush
+    }
+
+    fn set_brush(&mut self, brush: &GenBrush) {
+        self.push(Command::SetBrush(brush.clone()));
+    }
+
+    fn as_text_canvas(&mut self) -> Option<&mut dyn TextCanvas> {
+        Some(self)
+    }
+
+    fn line(&mut self, p1: Point, p2: Point) {
+        self.push(Command::Line(p1, p2));
+    }
+
+    fn polyline(&mut self, points: &[Point]) {
+        self.push(Command::Polyline(points.to_vec()));
+    }
+
+    fn arc(&mut self, border: Rect, p1: Point, p2: Point) {
+        self.push(Command::Arc(border, p1, p2));
+    }
+
+    fn angle_arc(&mut self, orgin: Point, radius: u32, start: f32, end: f32) {
+        self.push(Command::AngleArc(orgin, radius, start, end));
+    }
+
+    fn poly_bezier(&mut self, points: &[Point]) {
+        self.push(Command::PolyBezier(points.to_vec()));
+    }
+
+    fn rectangle(&mut self, rect: Rect) {
+        self.push(Command::Rectangle(rect));
+    }
+
+    fn ellipse(&mut self, rect: Rect) {
+        self.push(Command::Ellipse(rect));
+    }
+
+    fn polygon(&mut self, points: &[Point]) {
+        self.push(Command::Polygon(points.to_vec()));
+    }
+
+    fn pie(&mut self, rect: Rect, p1: Point, p2: Point) {
+        self.push(Command::Pie(rect, p1, p2));
+    }
+
+    fn chord(&mut self, rect: Rect, p1: Point, p2: Point) {
+        self.push(Command::Chord(rect, p1, p2));
+    }
+
+    fn round_rect(&mut self, rect: Rect, dx: u32, dy: u32) {
+        self.push(Command::RoundRect(rect, dx, dy));
+    }
+
+    fn begin_path(&mut self) {
+        self.push(Command::BeginPath);
+    }
+
+    fn end_path(&mut self) {
+        self.push(Command::EndPath);
+    }
+
+    fn move_to(&mut self, p: Point) {
+        self.push(Command::MoveTo(p));
+    }
+
+    fn line_to(&mut self, p: Point) {
+        self.push(Command::LineTo(p));
+    }
+
+    fn arc_to(&mut self, rect: Rect, p1: Point, p2: Point) {
+        self.push(Command::ArcTo(rect, p1, p2));
+    }
+
+    fn poly_bezier_to(&mut self, points: &[Point]) {
+        self.push(Command::PolyBezierTo(points.to_vec()));
+    }
+
+    fn polyline_to(&mut self, points: &[Point]) {
+        self.push(Command::PolylineTo(points.to_vec()));
+    }
+
+    fn close_figure(&mut self) {
+        self.push(Command::CloseFigure);
+    }
+
+    fn stroke_path(&mut self) {
+        self.push(Command::StrokePath);
+    }
+
+    fn fill_path(&mut self) {
+        self.push(Command::FillPath);
+    }
+
+    fn stroke_and_fill_path(&mut self) {
+        self.push(Command::StrokeAndFillPath);
+    }
+
+    fn poly_polygon(&mut self, points: &[Point], count: &[u32]) {
+        self.push(Command::PolyPolygon(points.to_vec(), count.to_vec()));
+    }
+
+    fn poly_polyline(&mut self, points: &[Point], count: &[u32]) {
+        self.push(Command::PolyPolyline(points.to_vec(), count.to_vec()));
+    }
+
+    fn draw_pixel(&mut self, p: Point, color: Color) {
+        self.push(Command::DrawPixel(p, color));
+    }
+}
+
+impl TextCanvas for DisplayList {
+    fn font(&self) -> &GenFont {
+        &self.font
+    }
+
+    fn set_font(&mut self, font: &GenFont) {
+        self.push(Command::SetFont(font.clone()));
+    }
+
+    fn set_text_color(&mut self, color: Color) {
+        self.push(Command::SetTextColor(color));
+    }
+
+    fn out_text(&mut self, text: &str, p: Point) {
+        self.push(Command::OutText(String::from(text), p));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bitmap, HatchStyle, LineStyle, Pattern, RadialGradient, Surface};
+    use ponsic_color::{Gradient, InterpolationSpace, SpreadMode};
+    use ponsic_types::Sizei;
+
+    fn paint(canvas: &mut dyn Canvas) {
+        canvas.set_line_width(3);
+        canvas.set_line_color(Color::RED);
+        canvas.set_brush_color(Color::BLUE);
+        canvas.rectangle(Rect::new(2, 2, 20, 12));
+        canvas.set_brush_hatch(HatchStyle::Cross);
+        canvas.ellipse(Rect::new(10, 10, 30, 30));
+        canvas.begin_path();
+        canvas.move_to(Point::new(1, 30));
+        canvas.poly_bezier_to(&[Point::new(5, 20), Point::new(15, 40), Point::new(20, 30)]);
+        canvas.close_figure();
+        canvas.end_path();
+        canvas.stroke_and_fill_path();
+        canvas.draw_pixel(Point::new(31, 0), Color::GREEN);
+    }
+
+    #[test]
+    fn record_test() {
+        let mut list = DisplayList::new();
+        list.set_line_width(2);
+        list.line(Point::new(0, 0), Point::new(4, 4));
+        list.set_font_height(20);
+        list.out_text("hi", Point::new(1, 2));
+
+        let pen = GenPen::default().set_width(2).to_buf();
+        assert_eq!(
+            list.commands(),
+            &[
+                Command::SetPen(pen),
+                Command::Line(Point::new(0, 0), Point::new(4, 4)),
+                Command::SetFont(GenFont::default().set_height(20)),
+                Command::OutText(String::from("hi"), Point::new(1, 2)),
+            ]
+        );
+        assert_eq!(list.pen().width, 2);
+        assert_eq!(list.font().height, 20);
+
+        list.clear();
+        assert!(list.commands().is_empty());
+        assert_eq!(list.pen().width, 0);
+    }
+
+    #[test]
+    fn replay_test() {
+        let mut direct = Surface::new(Sizei::new(32, 32));
+        paint(&mut direct);
+
+        let mut list = DisplayList::new();
+        paint(&mut list);
+        let mut replayed = Surface::new(Sizei::new(32, 32));
+        list.replay(&mut replayed);
+
+        assert_eq!(direct.pixels(), replayed.pixels());
+    }
+
+    #[test]
+    fn replay_text_test() {
+        let mut list = DisplayList::new();
+        list.set_text_color(Color::RED);
+        list.out_text("text", Point::new(0, 0));
+
+        let mut copy = DisplayList::new();
+        list.replay(&mut copy);
+        assert_eq!(copy, list);
+
+        // 不能绘制文本的目标忽略文本命令
+        let mut surface = Surface::new(Sizei::new(4, 4));
+        list.replay(&mut surface);
+        assert!(surface.pixels().iter().all(|&c| c == Color::WHITE));
+    }
+
+    #[test]
+    fn text_round_trip_test() {
+        let mut list = DisplayList::new();
+        paint(&mut list);
+        let dash = [4, 2];
+        list.set_line_style(LineStyle::UserDef(&dash));
+        list.angle_arc(Point::new(5, 5), 3, 0.5, -90.0);
+        list.poly_polygon(
+            &[Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)],
+            &[3],
+        );
+        list.set_font_name("Microsoft YaHei");
+        list.out_text("say \"hi\"\n", Point::new(-1, 2));
+        list.draw_pixel(Point::new(1, 1), Color::rgba(1, 2, 3, 128));
+        let gradient = Gradient::new()
+            .add_stop(0.0, Color::RED)
+            .add_stop(0.3, Color::rgba(0, 0, 255, 128))
+            .add_stop(0.3, Color::LIME)
+            .set_space(InterpolationSpace::OkLab)
+            .set_spread(SpreadMode::Reflect);
+        list.set_brush_linear_gradient(Point::new(0, 0), Point::new(3, -4), gradient.clone());
+        list.set_brush(&GenBrush::RadialGradient(RadialGradient::new(
+            ponsic_types::Pointf::new(0.5, -1.25),
+            2.75,
+            gradient,
+        )));
+        let image = Bitmap::from_pixels(Sizei::new(2, 1), vec![Color::RED, Color::TRANSPARENT]);
+        list.set_brush(&GenBrush::Pattern(
+            Pattern::new(image.unwrap())
+                .set_transform(ponsic_types::Transformf::new(0.0, 2.0, -0.5, 0.0, 1.5, 0.0)),
+        ));
+
+        let text = list.to_string();
+        let parsed: DisplayList = text.parse().unwrap();
+        assert_eq!(parsed, list);
+        assert_eq!(parsed.to_string(), text);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = "begin_path\nline 1,2\n".parse::<DisplayList>().unwrap_err();
+        assert_eq!(err.line(), 2);
+        let err = "fill_path\n\nfoo".parse::<DisplayList>().unwrap_err();
+        assert_eq!(err.line(), 3);
+        assert!("rectangle 0,0,1,1 2".parse::<Command>().is_err());
+        assert!("draw_pixel 0,0 #12345g".parse::<Command>().is_err());
+        assert!("draw_pixel 0,0 #1234567".parse::<Command>().is_err());
+        assert!(
+            "set_brush Pattern 0,0 1,0,0 Zg=="
+                .parse::<Command>()
+                .is_err()
+        );
+        assert!(
+            "set_brush Pattern 0,0 1,0,0,1 Zg=="
+                .parse::<Command>()
+                .is_err()
+        );
+    }
+}
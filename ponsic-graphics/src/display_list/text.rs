@@ -0,0 +1,650 @@
+//! 绘图命令的文本格式
+//!
+//! 每行一条命令，由命令名与以空格分隔的参数组成：
+//! 点写作 `x,y`，矩形写作 `l,t,r,b`，点列写作 `[x,y;x,y]`，计数列写作 `[a,b]`，
+//! 颜色写作 `#rrggbb`，半透明颜色写作 `#rrggbbaa`，字符串以双引号包围并转义，
+//! 枚举值写作其变体名；渐变画刷的色标列写作 `[offset:#rrggbb;...]`，
+//! 变换写作 `m11,m12,m21,m22,dx,dy`，图案画刷的图像写作 Base64 编码的 PNG 数据
+
+use super::{Command, DisplayList};
+use crate::{
+    Bitmap, CapStyle, CharSet, ClipPrecision, FontFamily, FontWeight, GenBrush, GenFont, GenPenBuf,
+    HatchStyle, ImageFormat, JoinStyle, LineStyleBuf, LinearGradient, OutPrecision, Pattern,
+    PenStyle, Pitch, Quality, RadialGradient, base64,
+};
+use ponsic_color::{Color, Gradient, InterpolationSpace, SpreadMode};
+use ponsic_types::{Pointf, Pointi as Point, Recti as Rect, Transformf};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// 解析绘图命令文本时发生的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDisplayListError {
+    line: usize,
+    message: &'static str,
+}
+
+impl ParseDisplayListError {
+    /// 返回出错的行号，从 1 开始
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 返回错误描述
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+impl Display for ParseDisplayListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "第 {} 行解析失败: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseDisplayListError {}
+
+// 格式化
+
+struct PointText(Point);
+
+impl Display for PointText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0.x, self.0.y)
+    }
+}
+
+struct PointfText(Pointf);
+
+impl Display for PointfText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0.x, self.0.y)
+    }
+}
+
+struct TransformText(Transformf);
+
+impl Display for TransformText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let t = self.0;
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            t.m11, t.m12, t.m21, t.m22, t.dx, t.dy
+        )
+    }
+}
+
+/// 渐变写作插值空间、扩展方式与色标列 `[offset:#rrggbb;...]`
+struct GradientText<'a>(&'a Gradient);
+
+impl Display for GradientText<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let gradient = self.0;
+        write!(f, "{:?} {:?} [", gradient.space(), gradient.spread())?;
+        for (i, stop) in gradient.stops().iter().enumerate() {
+            if i > 0 {
+                write!(f, ";")?;
+            }
+            write!(f, "{}:{}", stop.offset, stop.color)?;
+        }
+        write!(f, "]")
+    }
+}
+
+struct RectText(Rect);
+
+impl Display for RectText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let r = &self.0;
+        write!(f, "{},{},{},{}", r.left(), r.top(), r.right(), r.bottom())
+    }
+}
+
+struct PointsText<'a>(&'a [Point]);
+
+impl Display for PointsText<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, &p) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ";")?;
+            }
+            write!(f, "{}", PointText(p))?;
+        }
+        write!(f, "]")
+    }
+}
+
+struct CountsText<'a>(&'a [u32]);
+
+impl Display for CountsText<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, n) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", n)?;
+        }
+        write!(f, "]")
+    }
+}
+
+struct LineStyleText<'a>(&'a LineStyleBuf);
+
+impl Display for LineStyleText<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            LineStyleBuf::UserDef(array) => write!(f, "{}", CountsText(array)),
+            style => write!(f, "{:?}", style),
+        }
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::SetPen(pen) => write!(
+                f,
+                "set_pen {} {:?} {:?} {:?} {} {}",
+                LineStyleText(&pen.line_style),
+                pen.join_style,
+                pen.cap_style,
+                pen.pen_style,
+                pen.width,
+                pen.color
+            ),
+            Command::SetBrush(GenBrush::Solid(color)) => {
+                write!(f, "set_brush Solid {}", color)
+            }
+            Command::SetBrush(GenBrush::Hatch(color, style)) => {
+                write!(f, "set_brush Hatch {} {:?}", color, style)
+            }
+            Command::SetBrush(GenBrush::LinearGradient(linear)) => write!(
+                f,
+                "set_brush LinearGradient {} {} {}",
+                PointfText(linear.start),
+                PointfText(linear.end),
+                GradientText(&linear.gradient)
+            ),
+            Command::SetBrush(GenBrush::RadialGradient(radial)) => write!(
+                f,
+                "set_brush RadialGradient {} {} {}",
+                PointfText(radial.center),
+                radial.radius,
+                GradientText(&radial.gradient)
+            ),
+            Command::SetBrush(GenBrush::Pattern(pattern)) => write!(
+                f,
+                "set_brush Pattern {} {}",
+                TransformText(pattern.transform),
+                base64::encode(&pattern.image.encode(ImageFormat::Png))
+            ),
+            Command::SetFont(font) => write!(
+                f,
+                "set_font {:?} {} {} {} {} {:?} {} {} {} {:?} {:?} {:?} {:?} {:?} {:?}",
+                font.name,
+                font.height,
+                font.widht,
+                font.escapement,
+                font.orientation,
+                font.weight,
+                font.italic,
+                font.underline,
+                font.strikeout,
+                font.charset,
+                font.outprecision,
+                font.clipprecision,
+                font.quality,
+                font.pitch,
+                font.family
+            ),
+            Command::SetTextColor(color) => write!(f, "set_text_color {}", color),
+            Command::Line(p1, p2) => write!(f, "line {} {}", PointText(*p1), PointText(*p2)),
+            Command::Polyline(points) => write!(f, "polyline {}", PointsText(points)),
+            Command::Arc(rect, p1, p2) => write!(
+                f,
+                "arc {} {} {}",
+                RectText(*rect),
+                PointText(*p1),
+                PointText(*p2)
+            ),
+            Command::AngleArc(orgin, radius, start, end) => write!(
+                f,
+                "angle_arc {} {} {} {}",
+                PointText(*orgin),
+                radius,
+                start,
+                end
+            ),
+            Command::PolyBezier(points) => write!(f, "poly_bezier {}", PointsText(points)),
+            Command::Rectangle(rect) => write!(f, "rectangle {}", RectText(*rect)),
+            Command::Ellipse(rect) => write!(f, "ellipse {}", RectText(*rect)),
+            Command::Polygon(points) => write!(f, "polygon {}", PointsText(points)),
+            Command::Pie(rect, p1, p2) => write!(
+                f,
+                "pie {} {} {}",
+                RectText(*rect),
+                PointText(*p1),
+                PointText(*p2)
+            ),
+            Command::Chord(rect, p1, p2) => write!(
+                f,
+                "chord {} {} {}",
+                RectText(*rect),
+                PointText(*p1),
+                PointText(*p2)
+            ),
+            Command::RoundRect(rect, dx, dy) => {
+                write!(f, "round_rect {} {} {}", RectText(*rect), dx, dy)
+            }
+            Command::BeginPath => write!(f, "begin_path"),
+            Command::EndPath => write!(f, "end_path"),
+            Command::MoveTo(p) => write!(f, "move_to {}", PointText(*p)),
+            Command::LineTo(p) => write!(f, "line_to {}", PointText(*p)),
+            Command::ArcTo(rect, p1, p2) => write!(
+                f,
+                "arc_to {} {} {}",
+                RectText(*rect),
+                PointText(*p1),
+                PointText(*p2)
+            ),
+            Command::PolyBezierTo(points) => write!(f, "poly_bezier_to {}", PointsText(points)),
+            Command::PolylineTo(points) => write!(f, "polyline_to {}", PointsText(points)),
+            Command::CloseFigure => write!(f, "close_figure"),
+            Command::StrokePath => write!(f, "stroke_path"),
+            Command::FillPath => write!(f, "fill_path"),
+            Command::StrokeAndFillPath => write!(f, "stroke_and_fill_path"),
+            Command::PolyPolygon(points, count) => write!(
+                f,
+                "poly_polygon {} {}",
+                PointsText(points),
+                CountsText(count)
+            ),
+            Command::PolyPolyline(points, count) => write!(
+                f,
+                "poly_polyline {} {}",
+                PointsText(points),
+                CountsText(count)
+            ),
+            Command::DrawPixel(p, color) => {
+                write!(f, "draw_pixel {} {}", PointText(*p), color)
+            }
+            Command::OutText(text, p) => write!(f, "out_text {:?} {}", text, PointText(*p)),
+        }
+    }
+}
+
+impl Display for DisplayList {
+    /// 以文本格式输出所有命令，每行一条
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for command in &self.commands {
+            writeln!(f, "{}", command)?;
+        }
+        Ok(())
+    }
+}
+
+// 解析
+
+type Result<T> = std::result::Result<T, &'static str>;
+
+/// 按变体名解析枚举值
+macro_rules! parse_enum {
+    ($token:expr => $ty:ident { $($variant:ident),* $(,)? }) => {
+        match $token {
+            $(stringify!($variant) => Ok($ty::$variant),)*
+            _ => Err("无效的枚举值"),
+        }
+    };
+}
+
+fn parse_number<T: FromStr>(token: &str) -> Result<T> {
+    token.parse().map_err(|_| "无效的数值")
+}
+
+fn parse_bool(token: &str) -> Result<bool> {
+    token.parse().map_err(|_| "无效的布尔值")
+}
+
+fn parse_point(token: &str) -> Result<Point> {
+    let (x, y) = token.split_once(',').ok_or("无效的坐标")?;
+    Ok(Point::new(parse_number(x)?, parse_number(y)?))
+}
+
+fn parse_pointf(token: &str) -> Result<Pointf> {
+    let (x, y) = token.split_once(',').ok_or("无效的坐标")?;
+    Ok(Pointf::new(parse_number(x)?, parse_number(y)?))
+}
+
+fn parse_rect(token: &str) -> Result<Rect> {
+    let mut values = token.split(',').map(parse_number::<i32>);
+    let mut next = || values.next().ok_or("无效的矩形")?;
+    let rect = Rect::new(next()?, next()?, next()?, next()?);
+    match values.next() {
+        Some(_) => Err("无效的矩形"),
+        None => Ok(rect),
+    }
+}
+
+fn parse_transform(token: &str) -> Result<Transformf> {
+    let mut values = token.split(',').map(parse_number::<f32>);
+    let mut next = || values.next().ok_or("无效的变换")?;
+    let transform = Transformf::new(next()?, next()?, next()?, next()?, next()?, next()?);
+    match values.next() {
+        Some(_) => Err("无效的变换"),
+        None => Ok(transform),
+    }
+}
+
+fn list_items(token: &str, separator: char) -> Result<Vec<&str>> {
+    let inner = token
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or("无效的列表")?;
+    if inner.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(inner.split(separator).collect())
+}
+
+fn parse_points(token: &str) -> Result<Vec<Point>> {
+    list_items(token, ';')?
+        .into_iter()
+        .map(parse_point)
+        .collect()
+}
+
+fn parse_counts(token: &str) -> Result<Vec<u32>> {
+    list_items(token, ',')?
+        .into_iter()
+        .map(parse_number)
+        .collect()
+}
+
+fn parse_color(token: &str) -> Result<Color> {
+    if !token.starts_with('#') {
+        return Err("无效的颜色");
+    }
+    token.parse().map_err(|_| "无效的颜色")
+}
+
+fn parse_line_style(token: &str) -> Result<LineStyleBuf> {
+    if token.starts_with('[') {
+        return Ok(LineStyleBuf::UserDef(parse_counts(token)?));
+    }
+    parse_enum!(token => LineStyleBuf { Solid, Dash, Dot, DashDot, DashDotDot, Null })
+}
+
+/// 还原以 `{:?}` 格式输出的字符串，`token` 包含两侧的引号
+fn parse_string(token: &str) -> Result<String> {
+    const ERR: &str = "无效的字符串";
+    let inner = token
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or(ERR)?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next().ok_or(ERR)? {
+            'n' => text.push('\n'),
+            'r' => text.push('\r'),
+            't' => text.push('\t'),
+            '0' => text.push('\0'),
+            '\\' => text.push('\\'),
+            '"' => text.push('"'),
+            '\'' => text.push('\''),
+            'u' => {
+                let rest = chars.as_str().strip_prefix('{').ok_or(ERR)?;
+                let (hex, rest) = rest.split_once('}').ok_or(ERR)?;
+                let code = u32::from_str_radix(hex, 16).map_err(|_| ERR)?;
+                text.push(char::from_u32(code).ok_or(ERR)?);
+                chars = rest.chars();
+            }
+            _ => return Err(ERR),
+        }
+    }
+    Ok(text)
+}
+
+/// 命令参数的迭代器
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(line: &'a str) -> Self {
+        Self { rest: line }
+    }
+
+    /// 返回下一个参数，以引号开始的参数延续至与之配对的引号
+    fn next(&mut self) -> Result<&'a str> {
+        let rest = self.rest.trim_start();
+        if rest.is_empty() {
+            return Err("缺少参数");
+        }
+        let end = if rest.starts_with('"') {
+            let mut escaped = false;
+            rest.char_indices()
+                .skip(1)
+                .find(|&(_, c)| {
+                    let end = c == '"' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    end
+                })
+                .map(|(i, _)| i + 1)
+                .ok_or("无效的字符串")?
+        } else {
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        };
+        let (token, rest) = rest.split_at(end);
+        self.rest = rest;
+        Ok(token)
+    }
+
+    fn finish(self) -> Result<()> {
+        match self.rest.trim().is_empty() {
+            true => Ok(()),
+            false => Err("多余的参数"),
+        }
+    }
+
+    fn point(&mut self) -> Result<Point> {
+        parse_point(self.next()?)
+    }
+
+    fn rect(&mut self) -> Result<Rect> {
+        parse_rect(self.next()?)
+    }
+
+    fn points(&mut self) -> Result<Vec<Point>> {
+        parse_points(self.next()?)
+    }
+
+    fn counts(&mut self) -> Result<Vec<u32>> {
+        parse_counts(self.next()?)
+    }
+
+    fn color(&mut self) -> Result<Color> {
+        parse_color(self.next()?)
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T> {
+        parse_number(self.next()?)
+    }
+
+    fn boolean(&mut self) -> Result<bool> {
+        parse_bool(self.next()?)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        parse_string(self.next()?)
+    }
+}
+
+fn parse_pen(t: &mut Tokens) -> Result<GenPenBuf> {
+    Ok(GenPenBuf {
+        line_style: parse_line_style(t.next()?)?,
+        join_style: parse_enum!(t.next()? => JoinStyle { Bevel, Miter, Round })?,
+        cap_style: parse_enum!(t.next()? => CapStyle { Round, Square, Flat })?,
+        pen_style: parse_enum!(t.next()? => PenStyle { Null, Solid, Hollow })?,
+        width: t.number()?,
+        color: t.color()?,
+    })
+}
+
+fn parse_brush(t: &mut Tokens) -> Result<GenBrush> {
+    match t.next()? {
+        "Solid" => Ok(GenBrush::Solid(t.color()?)),
+        "Hatch" => {
+            let color = t.color()?;
+            let style = parse_enum!(t.next()? => HatchStyle {
+                BDiagonal, Cross, DiagCross, FDiagonal, Horizontal, Vertical,
+            })?;
+            Ok(GenBrush::Hatch(color, style))
+        }
+        "LinearGradient" => {
+            let start = parse_pointf(t.next()?)?;
+            let end = parse_pointf(t.next()?)?;
+            let gradient = parse_gradient(t)?;
+            Ok(GenBrush::LinearGradient(LinearGradient::new(
+                start, end, gradient,
+            )))
+        }
+        "RadialGradient" => {
+            let center = parse_pointf(t.next()?)?;
+            let radius = t.number()?;
+            let gradient = parse_gradient(t)?;
+            Ok(GenBrush::RadialGradient(RadialGradient::new(
+                center, radius, gradient,
+            )))
+        }
+        "Pattern" => {
+            let transform = parse_transform(t.next()?)?;
+            let data = base64::decode(t.next()?).ok_or("无效的图像数据")?;
+            let image = Bitmap::decode(&data).map_err(|e| e.message())?;
+            Ok(GenBrush::Pattern(
+                Pattern::new(image).set_transform(transform),
+            ))
+        }
+        _ => Err("无效的画刷"),
+    }
+}
+
+fn parse_gradient(t: &mut Tokens) -> Result<Gradient> {
+    let space = parse_enum!(t.next()? => InterpolationSpace { Srgb, LinearRgb, Hsl, OkLab })?;
+    let spread = parse_enum!(t.next()? => SpreadMode { Pad, Repeat, Reflect })?;
+    let mut gradient = Gradient::new().set_space(space).set_spread(spread);
+    for stop in list_items(t.next()?, ';')? {
+        let (offset, color) = stop.split_once(':').ok_or("无效的色标")?;
+        gradient = gradient.add_stop(parse_number(offset)?, parse_color(color)?);
+    }
+    Ok(gradient)
+}
+
+fn parse_font(t: &mut Tokens) -> Result<GenFont> {
+    Ok(GenFont {
+        name: t.string()?,
+        height: t.number()?,
+        widht: t.number()?,
+        escapement: t.number()?,
+        orientation: t.number()?,
+        weight: parse_enum!(t.next()? => FontWeight {
+            Dontcare, Thin, ExtraLight, Light, Normal, Medium, SemiBold, Bold, ExtraBold, Heavy,
+        })?,
+        italic: t.boolean()?,
+        underline: t.boolean()?,
+        strikeout: t.boolean()?,
+        charset: parse_enum!(t.next()? => CharSet {
+            Ansi, Baltic, ChineseBig5, Default, Easteurope, Gb2312, Greek, Hangul, Mac, Oem,
+            Russian, Shiftjis, Symbol, Turkish, Vietnamese, Johab, Arabic, Hebrew, Thai,
+        })?,
+        outprecision: parse_enum!(t.next()? => OutPrecision {
+            Character, Default, Device, Outline, PostScriptOnly, Raster, String, Stroke,
+            TrueTypeOnly, TrueType,
+        })?,
+        clipprecision: parse_enum!(t.next()? => ClipPrecision {
+            Character, Default, DfaDisable, Embedded, LhAngles, Mask, Stroke, TtAlways,
+        })?,
+        quality: parse_enum!(t.next()? => Quality {
+            Antialiased, Cleartype, Default, Draft, NonAntialiased, Proof,
+        })?,
+        pitch: parse_enum!(t.next()? => Pitch { Default, Fixed, Variable })?,
+        family: parse_enum!(t.next()? => FontFamily {
+            Decorative, Dontcare, Modern, Roman, Script, Swiss,
+        })?,
+    })
+}
+
+fn parse_command(line: &str) -> Result<Command> {
+    let mut t = Tokens::new(line);
+    let command = match t.next()? {
+        "set_pen" => Command::SetPen(parse_pen(&mut t)?),
+        "set_brush" => Command::SetBrush(parse_brush(&mut t)?),
+        "set_font" => Command::SetFont(parse_font(&mut t)?),
+        "set_text_color" => Command::SetTextColor(t.color()?),
+        "line" => Command::Line(t.point()?, t.point()?),
+        "polyline" => Command::Polyline(t.points()?),
+        "arc" => Command::Arc(t.rect()?, t.point()?, t.point()?),
+        "angle_arc" => Command::AngleArc(t.point()?, t.number()?, t.number()?, t.number()?),
+        "poly_bezier" => Command::PolyBezier(t.points()?),
+        "rectangle" => Command::Rectangle(t.rect()?),
+        "ellipse" => Command::Ellipse(t.rect()?),
+        "polygon" => Command::Polygon(t.points()?),
+        "pie" => Command::Pie(t.rect()?, t.point()?, t.point()?),
+        "chord" => Command::Chord(t.rect()?, t.point()?, t.point()?),
+        "round_rect" => Command::RoundRect(t.rect()?, t.number()?, t.number()?),
+        "begin_path" => Command::BeginPath,
+        "end_path" => Command::EndPath,
+        "move_to" => Command::MoveTo(t.point()?),
+        "line_to" => Command::LineTo(t.point()?),
+        "arc_to" => Command::ArcTo(t.rect()?, t.point()?, t.point()?),
+        "poly_bezier_to" => Command::PolyBezierTo(t.points()?),
+        "polyline_to" => Command::PolylineTo(t.points()?),
+        "close_figure" => Command::CloseFigure,
+        "stroke_path" => Command::StrokePath,
+        "fill_path" => Command::FillPath,
+        "stroke_and_fill_path" => Command::StrokeAndFillPath,
+        "poly_polygon" => Command::PolyPolygon(t.points()?, t.counts()?),
+        "poly_polyline" => Command::PolyPolyline(t.points()?, t.counts()?),
+        "draw_pixel" => Command::DrawPixel(t.point()?, t.color()?),
+        "out_text" => Command::OutText(t.string()?, t.point()?),
+        _ => return Err("未知的命令"),
+    };
+    t.finish()?;
+    Ok(command)
+}
+
+impl FromStr for Command {
+    type Err = ParseDisplayListError;
+
+    /// 解析单条命令
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_command(s).map_err(|message| ParseDisplayListError { line: 1, message })
+    }
+}
+
+impl FromStr for DisplayList {
+    type Err = ParseDisplayListError;
+
+    /// 逐行解析命令，空行将被忽略
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut list = DisplayList::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let command = parse_command(line).map_err(|message| ParseDisplayListError {
+                line: i + 1,
+                message,
+            })?;
+            list.push(command);
+        }
+        Ok(list)
+    }
+}
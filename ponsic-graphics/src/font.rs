@@ -0,0 +1,211 @@
+// 各枚举的取值与 GDI 中对应常量的取值相同
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FontWeight {
+    Dontcare = 0,
+    Thin = 100,
+    ExtraLight = 200,
+    Light = 300,
+    #[default]
+    Normal = 400,
+    Medium = 500,
+    SemiBold = 600,
+    Bold = 700,
+    ExtraBold = 800,
+    Heavy = 900,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CharSet {
+    Ansi = 0,
+    Baltic = 186,
+    ChineseBig5 = 136,
+    #[default]
+    Default = 1,
+    Easteurope = 238,
+    Gb2312 = 134,
+    Greek = 161,
+    Hangul = 129,
+    Mac = 77,
+    Oem = 255,
+    Russian = 204,
+    Shiftjis = 128,
+    Symbol = 2,
+    Turkish = 162,
+    Vietnamese = 163,
+    Johab = 130,
+    Arabic = 178,
+    Hebrew = 177,
+    Thai = 222,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OutPrecision {
+    Character = 2,
+    #[default]
+    Default = 0,
+    Device = 5,
+    Outline = 8,
+    PostScriptOnly = 10,
+    Raster = 6,
+    String = 1,
+    Stroke = 3,
+    TrueTypeOnly = 7,
+    TrueType = 4,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ClipPrecision {
+    Character = 1,
+    #[default]
+    Default = 0,
+    DfaDisable = 4 << 4,
+    Embedded = 8 << 4,
+    LhAngles = 1 << 4,
+    Mask = 0xf,
+    Stroke = 2,
+    TtAlways = 2 << 4,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Quality {
+    Antialiased = 4,
+    Cleartype = 5,
+    #[default]
+    Default = 0,
+    Draft = 1,
+    NonAntialiased = 3,
+    Proof = 2,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Pitch {
+    #[default]
+    Default = 0,
+    Fixed = 1,
+    Variable = 2,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FontFamily {
+    Decorative = 5 << 4,
+    #[default]
+    Dontcare = 0,
+    Modern = 3 << 4,
+    Roman = 1 << 4,
+    Script = 4 << 4,
+    Swiss = 2 << 4,
+}
+
+/// 字体描述
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct GenFont {
+    pub height: i32,
+    pub widht: i32,
+    pub escapement: i32,
+    pub orientation: i32,
+    pub weight: FontWeight,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikeout: bool,
+    pub charset: CharSet,
+    pub outprecision: OutPrecision,
+    pub clipprecision: ClipPrecision,
+    pub quality: Quality,
+    pub pitch: Pitch,
+    pub family: FontFamily,
+    pub name: String,
+}
+
+impl GenFont {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 由字体描述创建绘图后端的字体，如 GDI 字体
+    pub fn create<F: From<Self>>(self) -> F {
+        F::from(self)
+    }
+
+    pub fn set_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn set_width(mut self, width: i32) -> Self {
+        self.widht = width;
+        self
+    }
+
+    pub fn set_escapement(mut self, escapement: i32) -> Self {
+        self.escapement = escapement;
+        self
+    }
+
+    pub fn set_orientation(mut self, orientation: i32) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn set_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn set_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    pub fn set_underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    pub fn set_strikeout(mut self, strikeout: bool) -> Self {
+        self.strikeout = strikeout;
+        self
+    }
+
+    pub fn set_charset(mut self, charset: CharSet) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    pub fn set_outprecision(mut self, outprecision: OutPrecision) -> Self {
+        self.outprecision = outprecision;
+        self
+    }
+
+    pub fn set_clipprecision(mut self, clipprecision: ClipPrecision) -> Self {
+        self.clipprecision = clipprecision;
+        self
+    }
+
+    pub fn set_quality(mut self, quality: Quality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    pub fn set_pitch(mut self, pitch: Pitch) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    pub fn set_family(mut self, family: FontFamily) -> Self {
+        self.family = family;
+        self
+    }
+
+    pub fn set_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+}
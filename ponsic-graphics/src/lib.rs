@@ -0,0 +1,22 @@
+mod base64;
+mod bitmap;
+mod brush;
+mod canvas;
+mod display_list;
+mod font;
+mod path;
+mod pen;
+mod snapshot;
+mod surface;
+mod svg;
+
+pub use bitmap::{Bitmap, ImageError, ImageFormat};
+pub use brush::*;
+pub use canvas::{Canvas, TextCanvas};
+pub use display_list::*;
+pub use font::*;
+pub use path::{FillRule, Mask, Path2D, PathSegment, Stroker};
+pub use pen::*;
+pub use snapshot::{Snapshot, SnapshotError, UPDATE_SNAPSHOTS_VAR};
+pub use surface::Surface;
+pub use svg::SvgDocument;
@@ -0,0 +1,423 @@
+mod raster;
+mod stroke;
+
+pub(crate) use raster::FLATTEN_TOLERANCE;
+pub use raster::Mask;
+pub use stroke::Stroker;
+
+use ponsic_types::{CornerRadii, Pointf, Rectf, Sizef, Transformf};
+use std::f32::consts::FRAC_PI_2;
+
+/// 填充规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FillRule {
+    /// 环绕数不为零的区域位于路径内部
+    #[default]
+    NonZero,
+    /// 环绕数为奇数的区域位于路径内部
+    EvenOdd,
+}
+
+impl FillRule {
+    /// 判断具有指定环绕数的区域是否位于路径内部
+    pub fn contains(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// 路径段
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathSegment {
+    /// 开始新的图形
+    MoveTo(Pointf),
+    /// 自当前位置至指定点的线段
+    LineTo(Pointf),
+    /// 自当前位置起的三次贝塞尔曲线，依次为两个控制点与终点
+    CubicTo(Pointf, Pointf, Pointf),
+    /// 闭合当前图形
+    Close,
+}
+
+/// 折线化后的图形
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Contour {
+    pub points: Vec<Pointf>,
+    pub closed: bool,
+}
+
+/// 以浮点坐标描述的路径
+///
+/// 坐标以像素为单位，像素 `(x, y)` 覆盖区域 `[x, x + 1) × [y, y + 1)`；
+/// 椭圆弧在记录时即被转换为三次贝塞尔曲线
+///
+/// # Examples
+/// ```
+/// use ponsic_graphics::{FillRule, Path2D};
+/// use ponsic_types::{Pointf, Pointi, Sizei};
+///
+/// let mut path = Path2D::new();
+/// path.move_to(Pointf::new(1.0, 1.0));
+/// path.line_to(Pointf::new(9.0, 1.0));
+/// path.line_to(Pointf::new(5.0, 9.0));
+/// path.close_figure();
+/// let mask = path.rasterize(Sizei::new(10, 10), FillRule::NonZero);
+/// assert_eq!(mask.coverage(Pointi::new(5, 4)), 255);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Path2D {
+    segments: Vec<PathSegment>,
+    start: Option<Pointf>,
+    current: Option<Pointf>,
+}
+
+impl Path2D {
+    /// 创建空路径
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 返回路径中的所有路径段
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// 判断路径是否为空
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// 返回当前位置，若尚未开始任何图形，则返回 `None`
+    pub fn current(&self) -> Option<Pointf> {
+        self.current
+    }
+
+    /// 清空路径
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 以仿射变换变换路径中的所有点
+    ///
+    /// 仿射变换下三次贝塞尔曲线的形状保持不变，因此变换不会损失精度
+    pub fn transform(&mut self, transform: &Transformf) {
+        let f = |p: Pointf| transform.transform_point(p);
+        for segment in &mut self.segments {
+            *segment = match *segment {
+                PathSegment::MoveTo(p) => PathSegment::MoveTo(f(p)),
+                PathSegment::LineTo(p) => PathSegment::LineTo(f(p)),
+                PathSegment::CubicTo(c1, c2, p) => PathSegment::CubicTo(f(c1), f(c2), f(p)),
+                PathSegment::Close => PathSegment::Close,
+            };
+        }
+        self.start = self.start.map(f);
+        self.current = self.current.map(f);
+    }
+
+    /// 开始新的图形，并将当前位置移动至 `p`
+    pub fn move_to(&mut self, p: Pointf) {
+        self.segments.push(PathSegment::MoveTo(p));
+        self.start = Some(p);
+        self.current = Some(p);
+    }
+
+    /// 添加自当前位置至 `p` 的线段；若尚未开始任何图形，则等同于 \[`move_to()`\]
+    pub fn line_to(&mut self, p: Pointf) {
+        if self.current.is_none() {
+            return self.move_to(p);
+        }
+        self.segments.push(PathSegment::LineTo(p));
+        self.current = Some(p);
+    }
+
+    /// 依次添加至 `points` 中各点的线段
+    pub fn polyline_to(&mut self, points: &[Pointf]) {
+        for &p in points {
+            self.line_to(p);
+        }
+    }
+
+    /// 添加自当前位置起的三次贝塞尔曲线
+    ///
+    /// `points` 由每三个一组的两个控制点与终点组成，多余的点将被忽略；
+    /// 若尚未开始任何图形，则以第一个控制点为起点
+    pub fn poly_bezier_to(&mut self, points: &[Pointf]) {
+        for group in points.chunks_exact(3) {
+            if self.current.is_none() {
+                self.move_to(group[0]);
+            }
+            self.segments
+                .push(PathSegment::CubicTo(group[0], group[1], group[2]));
+            self.current = Some(group[2]);
+        }
+    }
+
+    /// 添加自当前位置至弧起点的线段及椭圆弧，并将当前位置移动至弧终点
+    ///
+    /// 椭圆以 `center` 为中心、`radii` 为两半轴长；角度以度为单位，
+    /// `sweep` 为弧扫过的角度，正值表示逆时针方向
+    pub fn arc_to(&mut self, center: Pointf, radii: Sizef, start: f32, sweep: f32) {
+        let (start, sweep) = (start.to_radians(), sweep.to_radians());
+        let at = |t: f32| {
+            Pointf::new(
+                center.x + radii.width * t.cos(),
+                center.y - radii.height * t.sin(),
+            )
+        };
+        let tangent = |t: f32| Pointf::new(-radii.width * t.sin(), -radii.height * t.cos());
+
+        self.line_to(at(start));
+        let n = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / n as f32;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        for i in 0..n {
+            let t0 = start + step * i as f32;
+            let t1 = t0 + step;
+            let (p0, p3) = (at(t0), at(t1));
+            let (d0, d1) = (tangent(t0), tangent(t1));
+            self.poly_bezier_to(&[p0 + d0 * k, p3 - d1 * k, p3]);
+        }
+    }
+
+    /// 添加圆角矩形的闭合图形，各角的半径可以不同
+    ///
+    /// 图形自上边起按顺时针方向绘制；半径先经 \[`CornerRadii::fit()`\] 调整，
+    /// 半径为 0 的角为直角；宽度或高度不为正的矩形将被忽略
+    pub fn add_round_rect(&mut self, rect: Rectf, radii: CornerRadii<f32>) {
+        if rect.width() <= 0.0 || rect.height() <= 0.0 {
+            return;
+        }
+        let r = radii.fit(rect.size());
+        let (l, t, rt, b) = (rect.left(), rect.top(), rect.right(), rect.bottom());
+        let corners = [
+            (
+                r.top_right,
+                Pointf::new(rt - r.top_right, t + r.top_right),
+                90.0,
+                Pointf::new(rt, t),
+            ),
+            (
+                r.bottom_right,
+                Pointf::new(rt - r.bottom_right, b - r.bottom_right),
+                0.0,
+                Pointf::new(rt, b),
+            ),
+            (
+                r.bottom_left,
+                Pointf::new(l + r.bottom_left, b - r.bottom_left),
+                270.0,
+                Pointf::new(l, b),
+            ),
+            (
+                r.top_left,
+                Pointf::new(l + r.top_left, t + r.top_left),
+                180.0,
+                Pointf::new(l, t),
+            ),
+        ];
+        self.move_to(Pointf::new(l + r.top_left, t));
+        for (radius, center, start, corner) in corners {
+            if radius > 0.0 {
+                self.arc_to(center, Sizef::new(radius, radius), start, -90.0);
+            } else {
+                self.line_to(corner);
+            }
+        }
+        self.close_figure();
+    }
+
+    /// 闭合当前图形，当前位置回到图形的起点
+    pub fn close_figure(&mut self) {
+        if self.current.is_some() {
+            self.segments.push(PathSegment::Close);
+            self.current = self.start;
+        }
+    }
+
+    /// 以折线添加一个图形，空折线将被忽略
+    pub(crate) fn add_contour(&mut self, points: &[Pointf], closed: bool) {
+        if let Some((&first, rest)) = points.split_first() {
+            self.move_to(first);
+            self.polyline_to(rest);
+            if closed {
+                self.close_figure();
+            }
+        }
+    }
+
+    /// 将路径折线化，曲线与折线的偏差不超过 `tolerance`
+    pub(crate) fn flatten(&self, tolerance: f32) -> Vec<Contour> {
+        let mut contours: Vec<Contour> = Vec::new();
+        let mut current = Pointf::default();
+        let mut open = false;
+        for &segment in &self.segments {
+            match segment {
+                PathSegment::MoveTo(p) => {
+                    contours.push(Contour {
+                        points: vec![p],
+                        closed: false,
+                    });
+                    open = true;
+                    current = p;
+                }
+                PathSegment::Close => {
+                    if let Some(contour) = contours.last_mut().filter(|_| open) {
+                        contour.closed = true;
+                        current = contour.points[0];
+                    }
+                    open = false;
+                }
+                segment => {
+                    if !open {
+                        // 闭合后继续绘制时，以闭合点为起点开始新的图形
+                        contours.push(Contour {
+                            points: vec![current],
+                            closed: false,
+                        });
+                        open = true;
+                    }
+                    let points = &mut contours.last_mut().unwrap().points;
+                    match segment {
+                        PathSegment::LineTo(p) => points.push(p),
+                        PathSegment::CubicTo(p1, p2, p3) => {
+                            flatten_cubic(current, p1, p2, p3, tolerance, points)
+                        }
+                        _ => unreachable!(),
+                    }
+                    current = *points.last().unwrap();
+                }
+            }
+        }
+        contours
+    }
+}
+
+/// 将三次贝塞尔曲线均匀折线化，结果不包含起点
+fn flatten_cubic(
+    p0: Pointf,
+    p1: Pointf,
+    p2: Pointf,
+    p3: Pointf,
+    tolerance: f32,
+    out: &mut Vec<Pointf>,
+) {
+    // 均匀划分为 n 段时的偏差不超过 max|B''| / (8n²)
+    let dd = |a: Pointf, b: Pointf, c: Pointf| (a.x - 2.0 * b.x + c.x).hypot(a.y - 2.0 * b.y + c.y);
+    let m = 6.0 * dd(p0, p1, p2).max(dd(p1, p2, p3));
+    let n = ((m / (8.0 * tolerance.max(1e-3))).sqrt().ceil() as usize).clamp(1, 1024);
+    for i in 1..n {
+        let t = i as f32 / n as f32;
+        let u = 1.0 - t;
+        let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+        out.push(Pointf::new(
+            a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+            a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+        ));
+    }
+    out.push(p3);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ponsic_types::{Pointi, Sizei};
+
+    #[test]
+    fn fill_rule_test() {
+        assert!(FillRule::NonZero.contains(2));
+        assert!(!FillRule::EvenOdd.contains(2));
+        assert!(FillRule::EvenOdd.contains(-1));
+        assert!(!FillRule::NonZero.contains(0));
+    }
+
+    #[test]
+    fn flatten_test() {
+        let mut path = Path2D::new();
+        path.line_to(Pointf::new(0.0, 0.0));
+        path.line_to(Pointf::new(4.0, 0.0));
+        path.close_figure();
+        assert_eq!(path.current(), Some(Pointf::new(0.0, 0.0)));
+        path.line_to(Pointf::new(0.0, 4.0));
+        let contours = path.flatten(0.1);
+        assert_eq!(contours.len(), 2);
+        assert!(contours[0].closed && !contours[1].closed);
+        assert_eq!(
+            contours[1].points,
+            [Pointf::new(0.0, 0.0), Pointf::new(0.0, 4.0)]
+        );
+    }
+
+    #[test]
+    fn arc_to_test() {
+        let mut path = Path2D::new();
+        path.move_to(Pointf::new(0.0, 0.0));
+        path.arc_to(Pointf::new(10.0, 10.0), Sizef::new(5.0, 5.0), 0.0, 180.0);
+        let end = path.current().unwrap();
+        assert!((end.x - 5.0).abs() < 1e-4 && (end.y - 10.0).abs() < 1e-4);
+        let contours = path.flatten(0.01);
+        for p in &contours[0].points[1..] {
+            let r = (p.x - 10.0).hypot(p.y - 10.0);
+            assert!((r - 5.0).abs() < 0.02);
+            // y 轴向下，逆时针的上半圆位于 y <= 10 一侧
+            assert!(p.y <= 10.0 + 1e-4);
+        }
+    }
+
+    #[test]
+    fn transform_test() {
+        let mut path = Path2D::new();
+        path.move_to(Pointf::new(1.0, 0.0));
+        path.poly_bezier_to(&[
+            Pointf::new(1.0, 1.0),
+            Pointf::new(2.0, 1.0),
+            Pointf::new(2.0, 0.0),
+        ]);
+        path.close_figure();
+        path.transform(&Transformf::scaling(2.0, 3.0).then_translate(1.0, 1.0));
+        assert_eq!(
+            path.segments()[..2],
+            [
+                PathSegment::MoveTo(Pointf::new(3.0, 1.0)),
+                PathSegment::CubicTo(
+                    Pointf::new(3.0, 4.0),
+                    Pointf::new(5.0, 4.0),
+                    Pointf::new(5.0, 1.0)
+                ),
+            ]
+        );
+        assert_eq!(path.current(), Some(Pointf::new(3.0, 1.0)));
+    }
+
+    #[test]
+    fn add_round_rect_test() {
+        let mut path = Path2D::new();
+        let rect = Rectf::from((Pointf::new(0.0, 0.0), Sizef::new(20.0, 20.0)));
+        path.add_round_rect(rect, CornerRadii::new(10.0, 0.0, 4.0, 0.0));
+        assert_eq!(
+            path.segments()[0],
+            PathSegment::MoveTo(Pointf::new(10.0, 0.0))
+        );
+        assert_eq!(path.segments().last(), Some(&PathSegment::Close));
+
+        let mask = path.rasterize(Sizei::new(20, 20), FillRule::NonZero);
+        let coverage = |x, y| mask.coverage(Pointi::new(x, y));
+        assert_eq!(coverage(0, 0), 0);
+        assert_eq!(coverage(19, 19), 0);
+        for (x, y) in [(19, 0), (0, 19), (10, 10), (2, 10), (16, 17)] {
+            assert_eq!(coverage(x, y), 255, "({x}, {y})");
+        }
+
+        // 半径之和超过边长时按比例缩小，不会产生自交
+        let mut path = Path2D::new();
+        path.add_round_rect(rect, CornerRadii::uniform(30.0));
+        let mask = path.rasterize(Sizei::new(20, 20), FillRule::NonZero);
+        assert_eq!(mask.coverage(Pointi::new(10, 10)), 255);
+        assert_eq!(mask.coverage(Pointi::new(0, 0)), 0);
+
+        let mut path = Path2D::new();
+        path.add_round_rect(Rectf::default(), CornerRadii::uniform(1.0));
+        assert!(path.is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+// 抗锯齿的扫描转换
+// 每个像素行被划分为若干子扫描线，每条子扫描线上按填充规则求出内部区间，
+// 再将区间在水平方向上的精确覆盖长度累加至像素
+
+use super::{FillRule, Path2D};
+use ponsic_types::{Pointf, Pointi as Point, Sizei};
+
+/// 每个像素行的子扫描线数
+const SUBSAMPLES: usize = 16;
+
+/// 曲线折线化所允许的最大误差（像素）
+pub(crate) const FLATTEN_TOLERANCE: f32 = 0.01;
+
+/// 覆盖率蒙版
+///
+/// 每个像素的值表示其被路径覆盖的比例，0 表示完全未覆盖，255 表示完全覆盖
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mask {
+    size: Sizei,
+    data: Vec<u8>,
+}
+
+impl Mask {
+    /// 创建完全未覆盖的蒙版
+    pub fn new(size: Sizei) -> Self {
+        Self {
+            size,
+            data: vec![0; size.width as usize * size.height as usize],
+        }
+    }
+
+    /// 返回蒙版的尺寸
+    pub fn size(&self) -> Sizei {
+        self.size
+    }
+
+    /// 返回按行优先排列的覆盖率数据
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// 返回指定像素的覆盖率，超出蒙版的像素视为未覆盖
+    pub fn coverage(&self, p: Point) -> u8 {
+        if p.x < 0 || p.y < 0 || p.x as u32 >= self.size.width || p.y as u32 >= self.size.height {
+            0
+        } else {
+            self.data[p.y as usize * self.size.width as usize + p.x as usize]
+        }
+    }
+}
+
+/// 有向边，`winding` 为边自上而下时的环绕数增量
+struct Edge {
+    top: Pointf,
+    bottom: Pointf,
+    winding: i32,
+}
+
+impl Edge {
+    fn new(a: Pointf, b: Pointf) -> Option<Self> {
+        if a.y == b.y || !(a.x.is_finite() && a.y.is_finite() && b.x.is_finite() && b.y.is_finite())
+        {
+            return None;
+        }
+        Some(if a.y < b.y {
+            Edge {
+                top: a,
+                bottom: b,
+                winding: 1,
+            }
+        } else {
+            Edge {
+                top: b,
+                bottom: a,
+                winding: -1,
+            }
+        })
+    }
+
+    fn x_at(&self, y: f32) -> f32 {
+        let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+        self.top.x + (self.bottom.x - self.top.x) * t
+    }
+}
+
+/// 将区间 `[x0, x1)` 以权重 `weight` 累加至覆盖率
+///
+/// `partial` 记录区间两端像素的部分覆盖，`full` 以差分形式记录完全覆盖的像素
+fn accumulate(partial: &mut [f32], full: &mut [f32], x0: f32, x1: f32, weight: f32) {
+    let width = partial.len() as f32;
+    let (x0, x1) = (x0.clamp(0.0, width), x1.clamp(0.0, width));
+    if x0 >= x1 {
+        return;
+    }
+    let (i0, i1) = (x0.floor() as usize, x1.floor() as usize);
+    if i0 == i1 {
+        partial[i0] += (x1 - x0) * weight;
+        return;
+    }
+    partial[i0] += (i0 as f32 + 1.0 - x0) * weight;
+    full[i0 + 1] += weight;
+    full[i1] -= weight;
+    if i1 < partial.len() {
+        partial[i1] += (x1 - i1 as f32) * weight;
+    }
+}
+
+impl Path2D {
+    /// 以指定的填充规则将路径光栅化为抗锯齿的覆盖率蒙版
+    ///
+    /// 未闭合的图形在填充时视为自动闭合
+    pub fn rasterize(&self, size: Sizei, rule: FillRule) -> Mask {
+        let mut mask = Mask::new(size);
+        let width = size.width as usize;
+        let mut edges = Vec::new();
+        for contour in self.flatten(FLATTEN_TOLERANCE) {
+            let n = contour.points.len();
+            for i in 0..n {
+                edges.extend(Edge::new(contour.points[i], contour.points[(i + 1) % n]));
+            }
+        }
+        if edges.is_empty() || width == 0 {
+            return mask;
+        }
+        edges.sort_by(|a, b| a.top.y.total_cmp(&b.top.y));
+
+        let min_y = edges[0].top.y;
+        let max_y = edges.iter().map(|e| e.bottom.y).fold(f32::MIN, f32::max);
+        let y0 = (min_y.floor().max(0.0)) as usize;
+        let y1 = (max_y.ceil().min(size.height as f32)).max(0.0) as usize;
+
+        let weight = 1.0 / SUBSAMPLES as f32;
+        let mut partial = vec![0.0f32; width];
+        let mut full = vec![0.0f32; width + 1];
+        let mut active: Vec<&Edge> = Vec::new();
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        let mut next = 0;
+        for y in y0..y1 {
+            let (row_top, row_bottom) = (y as f32, y as f32 + 1.0);
+            while next < edges.len() && edges[next].top.y < row_bottom {
+                active.push(&edges[next]);
+                next += 1;
+            }
+            active.retain(|e| e.bottom.y > row_top);
+            if active.is_empty() {
+                continue;
+            }
+
+            partial.fill(0.0);
+            full.fill(0.0);
+            for s in 0..SUBSAMPLES {
+                let sy = row_top + (s as f32 + 0.5) * weight;
+                crossings.clear();
+                crossings.extend(
+                    active
+                        .iter()
+                        .filter(|e| e.top.y <= sy && sy < e.bottom.y)
+                        .map(|e| (e.x_at(sy), e.winding)),
+                );
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                let mut start = 0.0;
+                for &(x, w) in &crossings {
+                    let inside = rule.contains(winding);
+                    winding += w;
+                    match (inside, rule.contains(winding)) {
+                        (false, true) => start = x,
+                        (true, false) => accumulate(&mut partial, &mut full, start, x, weight),
+                        _ => {}
+                    }
+                }
+            }
+
+            let row = &mut mask.data[y * width..(y + 1) * width];
+            let mut run = 0.0;
+            for x in 0..width {
+                run += full[x];
+                row[x] = ((run + partial[x]).clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        }
+        mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ponsic_types::Sizef;
+
+    fn rect(path: &mut Path2D, x0: f32, y0: f32, x1: f32, y1: f32) {
+        path.move_to(Pointf::new(x0, y0));
+        path.line_to(Pointf::new(x1, y0));
+        path.line_to(Pointf::new(x1, y1));
+        path.line_to(Pointf::new(x0, y1));
+        path.close_figure();
+    }
+
+    #[test]
+    fn aligned_rect_test() {
+        let mut path = Path2D::new();
+        rect(&mut path, 1.0, 1.0, 3.0, 3.0);
+        let mask = path.rasterize(Sizei::new(4, 4), FillRule::NonZero);
+        #[rustfmt::skip]
+        assert_eq!(mask.data(), &[
+            0, 0, 0, 0,
+            0, 255, 255, 0,
+            0, 255, 255, 0,
+            0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn partial_coverage_test() {
+        let mut path = Path2D::new();
+        rect(&mut path, 0.5, 0.0, 2.25, 1.0);
+        let mask = path.rasterize(Sizei::new(3, 1), FillRule::NonZero);
+        assert_eq!(mask.data(), &[128, 255, 64]);
+
+        // 对角线将像素平分
+        let mut path = Path2D::new();
+        path.move_to(Pointf::new(0.0, 0.0));
+        path.line_to(Pointf::new(1.0, 0.0));
+        path.line_to(Pointf::new(0.0, 1.0));
+        let mask = path.rasterize(Sizei::new(1, 1), FillRule::NonZero);
+        assert_eq!(mask.data(), &[128]);
+    }
+
+    #[test]
+    fn fill_rule_test() {
+        // 同向嵌套的两个矩形
+        let mut path = Path2D::new();
+        rect(&mut path, 0.0, 0.0, 6.0, 6.0);
+        rect(&mut path, 2.0, 2.0, 4.0, 4.0);
+        let center = Point::new(3, 3);
+        let non_zero = path.rasterize(Sizei::new(6, 6), FillRule::NonZero);
+        let even_odd = path.rasterize(Sizei::new(6, 6), FillRule::EvenOdd);
+        assert_eq!(non_zero.coverage(center), 255);
+        assert_eq!(even_odd.coverage(center), 0);
+        assert_eq!(even_odd.coverage(Point::new(1, 1)), 255);
+    }
+
+    #[test]
+    fn circle_area_test() {
+        let mut path = Path2D::new();
+        let c = Pointf::new(10.0, 10.0);
+        path.move_to(Pointf::new(18.0, 10.0));
+        path.arc_to(c, Sizef::new(8.0, 8.0), 0.0, 360.0);
+        path.close_figure();
+        let mask = path.rasterize(Sizei::new(20, 20), FillRule::NonZero);
+        let area: f32 = mask.data().iter().map(|&v| v as f32 / 255.0).sum();
+        assert!((area - std::f32::consts::PI * 64.0).abs() < 0.5);
+        assert_eq!(mask.coverage(Point::new(10, 10)), 255);
+        assert_eq!(mask.coverage(Point::new(0, 0)), 0);
+    }
+
+    #[test]
+    fn clip_test() {
+        let mut path = Path2D::new();
+        rect(&mut path, -5.0, -5.0, 20.0, 1.5);
+        let mask = path.rasterize(Sizei::new(2, 3), FillRule::EvenOdd);
+        assert_eq!(mask.data(), &[255, 255, 128, 128, 0, 0]);
+    }
+}
@@ -0,0 +1,545 @@
+// 描边
+// 开放图形的轮廓由左侧正向偏移、终点端点、右侧反向偏移与起点端点依次组成；
+// 闭合图形的轮廓由左右两侧的两个方向相反的环组成。
+// 内侧连接经过顶点本身，由此产生的自相交以非零规则填充时不会产生空洞
+
+use super::raster::FLATTEN_TOLERANCE;
+use super::{Contour, Path2D};
+use crate::{CapStyle, GenPen, JoinStyle};
+use ponsic_types::Pointf;
+use std::f32::consts::PI;
+
+/// 视为重合的两点间的最大距离
+const EPSILON: f32 = 1e-4;
+
+fn dot(a: Pointf, b: Pointf) -> f32 {
+    a.x * b.x + a.y * b.y
+}
+
+fn cross(a: Pointf, b: Pointf) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+fn length(v: Pointf) -> f32 {
+    v.x.hypot(v.y)
+}
+
+/// 将向量逆时针（按数学约定）旋转 90°
+fn normal(d: Pointf) -> Pointf {
+    Pointf::new(-d.y, d.x)
+}
+
+/// 描边器
+///
+/// 将路径按画笔的宽度、线型、连接样式与端点样式展开为轮廓，
+/// 轮廓应以非零规则（\[`FillRule::NonZero`\]）填充
+///
+/// # Examples
+/// ```
+/// use ponsic_graphics::{CapStyle, FillRule, Stroker};
+/// use ponsic_types::{Pointf, Sizei};
+///
+/// let stroker = Stroker::new(4.0).set_cap_style(CapStyle::Square);
+/// let outline = stroker.stroke_polyline(&[Pointf::new(4.0, 8.0), Pointf::new(12.0, 8.0)], false);
+/// let mask = outline.rasterize(Sizei::new(16, 16), FillRule::NonZero);
+/// assert_eq!(mask.data().iter().filter(|&&c| c == 255).count(), 12 * 4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroker {
+    width: f32,
+    join_style: JoinStyle,
+    cap_style: CapStyle,
+    miter_limit: f32,
+    dashes: Vec<f32>,
+    dash_offset: f32,
+}
+
+impl Stroker {
+    /// 创建指定宽度的实线描边器
+    ///
+    /// 默认以尖角连接、平端点，尖角限制为 10
+    pub fn new(width: f32) -> Self {
+        Self {
+            width,
+            join_style: JoinStyle::Miter,
+            cap_style: CapStyle::Flat,
+            miter_limit: 10.0,
+            dashes: Vec::new(),
+            dash_offset: 0.0,
+        }
+    }
+
+    /// 创建与画笔描述相符的描边器
+    ///
+    /// # Note
+    /// 若画笔不绘制任何内容，则返回 `None`；宽度为 0 的画笔按宽度 1 处理
+    pub fn from_pen(pen: &GenPen) -> Option<Self> {
+        if pen.is_null() {
+            return None;
+        }
+        let width = pen.width.max(1);
+        let dashes = pen
+            .line_style
+            .dash_array(width)
+            .map(|array| array.into_iter().map(|n| n as f32).collect())
+            .unwrap_or_default();
+        Some(
+            Self::new(width as f32)
+                .set_join_style(pen.join_style)
+                .set_cap_style(pen.cap_style)
+                .set_dashes(dashes),
+        )
+    }
+
+    pub fn set_width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn set_join_style(mut self, join_style: JoinStyle) -> Self {
+        self.join_style = join_style;
+        self
+    }
+
+    pub fn set_cap_style(mut self, cap_style: CapStyle) -> Self {
+        self.cap_style = cap_style;
+        self
+    }
+
+    /// 设置尖角限制，即尖角长度与线宽之比的上限，超出限制的尖角连接以斜角代替
+    pub fn set_miter_limit(mut self, miter_limit: f32) -> Self {
+        self.miter_limit = miter_limit.max(1.0);
+        self
+    }
+
+    /// 设置虚线数组，数组依次为实线段与间隔的长度，空数组表示实线
+    ///
+    /// # Note
+    /// 与 SVG 一致，奇数长度的数组将被重复一次；
+    /// 若数组含有负数或总长度为 0，则按实线处理
+    pub fn set_dashes(mut self, dashes: Vec<f32>) -> Self {
+        self.dashes = dashes;
+        self
+    }
+
+    /// 设置虚线的起始偏移
+    pub fn set_dash_offset(mut self, dash_offset: f32) -> Self {
+        self.dash_offset = dash_offset;
+        self
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn join_style(&self) -> JoinStyle {
+        self.join_style
+    }
+
+    pub fn cap_style(&self) -> CapStyle {
+        self.cap_style
+    }
+
+    pub fn miter_limit(&self) -> f32 {
+        self.miter_limit
+    }
+
+    pub fn dashes(&self) -> &[f32] {
+        &self.dashes
+    }
+
+    pub fn dash_offset(&self) -> f32 {
+        self.dash_offset
+    }
+
+    /// 返回有效的虚线数组，实线返回 `None`
+    fn pattern(&self) -> Option<Vec<f32>> {
+        let total: f32 = self.dashes.iter().sum();
+        if self.dashes.iter().any(|d| *d < 0.0 || !d.is_finite()) || total <= 0.0 {
+            return None;
+        }
+        let mut pattern = self.dashes.clone();
+        if pattern.len() % 2 == 1 {
+            pattern.extend_from_slice(&self.dashes);
+        }
+        Some(pattern)
+    }
+
+    /// 将图形按虚线数组切分为若干开放图形
+    fn dash_contour(&self, pattern: &[f32], contour: &Contour) -> Vec<Contour> {
+        let total: f32 = pattern.iter().sum();
+        let mut phase = self.dash_offset.rem_euclid(total);
+        let mut index = 0;
+        while phase >= pattern[index] {
+            phase -= pattern[index];
+            index = (index + 1) % pattern.len();
+        }
+        let mut remaining = pattern[index] - phase;
+        let starts_on = index % 2 == 0;
+
+        let mut points = contour.points.clone();
+        if contour.closed {
+            points.push(points[0]);
+        }
+        let mut dashes = Vec::new();
+        let mut current = starts_on.then(|| vec![points[0]]);
+        for pair in points.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let len = length(b - a);
+            let mut t = 0.0;
+            while len - t > remaining {
+                t += remaining;
+                let p = a + (b - a) * (t / len);
+                match current.take() {
+                    Some(mut dash) => {
+                        dash.push(p);
+                        dashes.push(dash);
+                    }
+                    None => current = Some(vec![p]),
+                }
+                index = (index + 1) % pattern.len();
+                remaining = pattern[index];
+            }
+            remaining -= len - t;
+            if let Some(dash) = &mut current {
+                dash.push(b);
+            }
+        }
+        if let Some(dash) = current {
+            // 闭合图形首尾相接的两段合并为一段
+            if contour.closed && starts_on && !dashes.is_empty() {
+                let mut merged = dash;
+                merged.extend_from_slice(&dashes[0][1..]);
+                dashes[0] = merged;
+            } else {
+                dashes.push(dash);
+            }
+        }
+        dashes
+            .into_iter()
+            .map(|points| Contour {
+                points,
+                closed: false,
+            })
+            .collect()
+    }
+
+    fn dashed_contours(&self, path: &Path2D) -> Vec<Contour> {
+        let contours = path.flatten(FLATTEN_TOLERANCE);
+        match self.pattern() {
+            Some(pattern) => contours
+                .iter()
+                .flat_map(|c| self.dash_contour(&pattern, c))
+                .collect(),
+            None => contours,
+        }
+    }
+
+    /// 将路径按虚线数组切分为若干开放图形，实线描边器返回折线化的原路径
+    pub fn dash(&self, path: &Path2D) -> Path2D {
+        let mut out = Path2D::new();
+        for contour in self.dashed_contours(path) {
+            out.add_contour(&contour.points, contour.closed);
+        }
+        out
+    }
+
+    /// 将路径展开为描边轮廓
+    pub fn stroke(&self, path: &Path2D) -> Path2D {
+        let mut out = Path2D::new();
+        if self.width <= 0.0 {
+            return out;
+        }
+        for contour in self.dashed_contours(path) {
+            self.stroke_contour(&contour.points, contour.closed, &mut out);
+        }
+        out
+    }
+
+    /// 将折线展开为描边轮廓
+    pub fn stroke_polyline(&self, points: &[Pointf], closed: bool) -> Path2D {
+        let mut path = Path2D::new();
+        path.add_contour(points, closed);
+        self.stroke(&path)
+    }
+
+    fn stroke_contour(&self, points: &[Pointf], closed: bool, out: &mut Path2D) {
+        let mut points = points.to_vec();
+        points.dedup_by(|b, a| length(*b - *a) <= EPSILON);
+        if closed && points.len() > 1 && length(points[0] - points[points.len() - 1]) <= EPSILON {
+            points.pop();
+        }
+        let h = self.width / 2.0;
+        match points.len() {
+            0 => {}
+            1 => {
+                // 零长度的图形只绘制端点
+                let p = points[0];
+                let d = Pointf::new(1.0, 0.0);
+                let mut outline = Vec::new();
+                if self.cap_style != CapStyle::Flat {
+                    outline.push(p + normal(d) * h);
+                    self.cap(p, d, h, &mut outline);
+                    outline.push(p - normal(d) * h);
+                    self.cap(p, -d, h, &mut outline);
+                }
+                out.add_contour(&outline, true);
+            }
+            2 if closed => self.stroke_contour(&points, false, out),
+            n if closed => {
+                let dirs: Vec<Pointf> = (0..n)
+                    .map(|i| {
+                        let d = points[(i + 1) % n] - points[i];
+                        d / length(d)
+                    })
+                    .collect();
+                for side in [1.0, -1.0] {
+                    let mut outline = Vec::new();
+                    for i in 0..n {
+                        let prev = dirs[(i + n - 1) % n];
+                        self.join(points[i], prev, dirs[i], side * h, &mut outline);
+                    }
+                    if side < 0.0 {
+                        outline.reverse();
+                    }
+                    out.add_contour(&outline, true);
+                }
+            }
+            n => {
+                let dirs: Vec<Pointf> = points
+                    .windows(2)
+                    .map(|w| (w[1] - w[0]) / length(w[1] - w[0]))
+                    .collect();
+                let mut outline = vec![points[0] + normal(dirs[0]) * h];
+                for i in 1..n - 1 {
+                    self.join(points[i], dirs[i - 1], dirs[i], h, &mut outline);
+                }
+                let last = dirs[n - 2];
+                outline.push(points[n - 1] + normal(last) * h);
+                self.cap(points[n - 1], last, h, &mut outline);
+                outline.push(points[n - 1] - normal(last) * h);
+                for i in (1..n - 1).rev() {
+                    self.join(points[i], -dirs[i], -dirs[i - 1], h, &mut outline);
+                }
+                outline.push(points[0] - normal(dirs[0]) * h);
+                self.cap(points[0], -dirs[0], h, &mut outline);
+                out.add_contour(&outline, true);
+            }
+        }
+    }
+
+    /// 在顶点 `p` 处连接方向为 `d0` 与 `d1` 的两段，`h` 为带符号的偏移距离
+    fn join(&self, p: Pointf, d0: Pointf, d1: Pointf, h: f32, out: &mut Vec<Pointf>) {
+        let (n0, n1) = (normal(d0), normal(d1));
+        let (a, b) = (p + n0 * h, p + n1 * h);
+        if dot(d0, d1) >= 1.0 - 1e-6 {
+            out.push(a);
+            return;
+        }
+        // 向偏移一侧转弯时，该侧为内侧
+        if cross(d0, d1) * h > 0.0 {
+            out.extend([a, p, b]);
+            return;
+        }
+        match self.join_style {
+            JoinStyle::Bevel => out.extend([a, b]),
+            JoinStyle::Miter => {
+                let cos = dot(n0, n1);
+                let ratio = (2.0 / (1.0 + cos)).sqrt();
+                if 1.0 + cos > 1e-6 && ratio <= self.miter_limit {
+                    out.push(p + (n0 + n1) * (h / (1.0 + cos)));
+                } else {
+                    out.extend([a, b]);
+                }
+            }
+            JoinStyle::Round => {
+                let start = (a.y - p.y).atan2(a.x - p.x);
+                let mut sweep = (b.y - p.y).atan2(b.x - p.x) - start;
+                if sweep > PI {
+                    sweep -= 2.0 * PI;
+                } else if sweep < -PI {
+                    sweep += 2.0 * PI;
+                }
+                arc(p, h.abs(), start, sweep, out);
+            }
+        }
+    }
+
+    /// 在端点 `p` 处添加朝向 `d` 的端点，自左侧偏移点绕至右侧偏移点，两偏移点本身不包含在内
+    fn cap(&self, p: Pointf, d: Pointf, h: f32, out: &mut Vec<Pointf>) {
+        let n = normal(d);
+        match self.cap_style {
+            CapStyle::Flat => {}
+            CapStyle::Square => out.extend([p + (n + d) * h, p + (d - n) * h]),
+            CapStyle::Round => {
+                let mut points = Vec::new();
+                arc(p, h, n.y.atan2(n.x), -PI, &mut points);
+                out.extend_from_slice(&points[1..points.len() - 1]);
+            }
+        }
+    }
+}
+
+/// 添加以 `center` 为圆心的圆弧上的点，包含起点与终点
+fn arc(center: Pointf, radius: f32, start: f32, sweep: f32, out: &mut Vec<Pointf>) {
+    let step = if radius > FLATTEN_TOLERANCE {
+        2.0 * (1.0 - FLATTEN_TOLERANCE / radius).acos()
+    } else {
+        PI / 2.0
+    };
+    let n = ((sweep.abs() / step).ceil() as usize).max(2);
+    for i in 0..=n {
+        let t = start + sweep * i as f32 / n as f32;
+        out.push(center + Pointf::new(t.cos(), t.sin()) * radius);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FillRule, LineStyle, Mask};
+    use ponsic_types::{Pointi as Point, Sizei};
+
+    fn filled(path: &Path2D) -> usize {
+        let mask = path.rasterize(Sizei::new(32, 32), FillRule::NonZero);
+        mask.data().iter().filter(|&&c| c >= 128).count()
+    }
+
+    fn line() -> [Pointf; 2] {
+        [Pointf::new(8.0, 16.0), Pointf::new(24.0, 16.0)]
+    }
+
+    #[test]
+    fn cap_test() {
+        let stroker = Stroker::new(4.0);
+        assert_eq!(filled(&stroker.stroke_polyline(&line(), false)), 16 * 4);
+        let square = stroker.clone().set_cap_style(CapStyle::Square);
+        assert_eq!(filled(&square.stroke_polyline(&line(), false)), 20 * 4);
+        let round = stroker.set_cap_style(CapStyle::Round);
+        let area = filled(&round.stroke_polyline(&line(), false)) as f32;
+        assert!((area - (64.0 + 4.0 * PI)).abs() <= 3.0);
+    }
+
+    #[test]
+    fn join_test() {
+        let points = [
+            Pointf::new(4.0, 20.0),
+            Pointf::new(16.0, 8.0),
+            Pointf::new(28.0, 20.0),
+        ];
+        let stroke = |join| {
+            Stroker::new(4.0)
+                .set_join_style(join)
+                .stroke_polyline(&points, false)
+                .rasterize(Sizei::new(32, 32), FillRule::NonZero)
+        };
+        let area = |mask: &Mask| mask.data().iter().map(|&c| c as f32 / 255.0).sum::<f32>();
+        let (miter, round, bevel) = (
+            stroke(JoinStyle::Miter),
+            stroke(JoinStyle::Round),
+            stroke(JoinStyle::Bevel),
+        );
+        // 直角连接处尖角比斜角多出 h²/2，圆角多出 h²(π/4 - 1/2)
+        assert!((area(&miter) - area(&bevel) - 2.0).abs() < 0.1);
+        assert!((area(&round) - area(&bevel) - 4.0 * (PI / 4.0 - 0.5)).abs() < 0.1);
+        // 尖角的顶点位于 (16, 8 - 2√2) 处
+        assert!(miter.coverage(Point::new(16, 5)) > 0);
+        assert_eq!(bevel.coverage(Point::new(16, 5)), 0);
+        // 内侧不产生空洞
+        assert_eq!(miter.coverage(Point::new(16, 9)), 255);
+    }
+
+    #[test]
+    fn miter_limit_test() {
+        let points = [
+            Pointf::new(4.0, 24.0),
+            Pointf::new(16.0, 4.0),
+            Pointf::new(17.0, 24.0),
+        ];
+        let stroker = Stroker::new(2.0);
+        let limited = stroker.clone().set_miter_limit(2.0);
+        assert!(
+            filled(&stroker.stroke_polyline(&points, false))
+                > filled(&limited.stroke_polyline(&points, false))
+        );
+    }
+
+    #[test]
+    fn closed_test() {
+        let square = [
+            Pointf::new(8.0, 8.0),
+            Pointf::new(24.0, 8.0),
+            Pointf::new(24.0, 24.0),
+            Pointf::new(8.0, 24.0),
+        ];
+        let outline = Stroker::new(2.0).stroke_polyline(&square, true);
+        assert_eq!(filled(&outline), 18 * 18 - 14 * 14);
+        let mask = outline.rasterize(Sizei::new(32, 32), FillRule::NonZero);
+        assert_eq!(mask.coverage(Point::new(7, 7)), 255);
+        assert_eq!(mask.coverage(Point::new(16, 16)), 0);
+    }
+
+    #[test]
+    fn dash_test() {
+        let mut path = Path2D::new();
+        path.add_contour(&line(), false);
+        let stroker = Stroker::new(2.0).set_dashes(vec![4.0, 2.0]);
+        let contours = stroker.dash(&path).flatten(FLATTEN_TOLERANCE);
+        assert_eq!(contours.len(), 3);
+        assert_eq!(
+            contours[2].points,
+            [Pointf::new(20.0, 16.0), Pointf::new(24.0, 16.0)]
+        );
+        assert_eq!(filled(&stroker.stroke(&path)), 12 * 2);
+
+        let contours = stroker
+            .set_dash_offset(5.0)
+            .dash(&path)
+            .flatten(FLATTEN_TOLERANCE);
+        assert_eq!(contours[0].points[0], Pointf::new(9.0, 16.0));
+    }
+
+    #[test]
+    fn closed_dash_test() {
+        let square = [
+            Pointf::new(0.0, 0.0),
+            Pointf::new(10.0, 0.0),
+            Pointf::new(10.0, 10.0),
+            Pointf::new(0.0, 10.0),
+        ];
+        let mut path = Path2D::new();
+        path.add_contour(&square, true);
+        let stroker = Stroker::new(1.0).set_dashes(vec![6.0, 2.0]);
+        assert_eq!(stroker.dash(&path).flatten(FLATTEN_TOLERANCE).len(), 5);
+        // 周长恰为 5 个周期，偏移后末段与首段相接
+        let contours = stroker
+            .set_dash_offset(3.0)
+            .dash(&path)
+            .flatten(FLATTEN_TOLERANCE);
+        assert_eq!(contours.len(), 5);
+        let start = contours[0].points[0];
+        assert!(start.x.abs() < 1e-4 && (start.y - 3.0).abs() < 1e-4);
+        assert_eq!(*contours[0].points.last().unwrap(), Pointf::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn from_pen_test() {
+        let pen = GenPen::default()
+            .set_width(2)
+            .set_line_style(LineStyle::Dot)
+            .set_cap_style(CapStyle::Round);
+        let stroker = Stroker::from_pen(&pen).unwrap();
+        assert_eq!(stroker.width(), 2.0);
+        assert_eq!(stroker.dashes(), &[6.0, 6.0]);
+        assert_eq!(stroker.cap_style(), CapStyle::Round);
+        assert!(Stroker::from_pen(&GenPen::default().set_line_style(LineStyle::Null)).is_none());
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let point = [Pointf::new(16.0, 16.0)];
+        assert!(Stroker::new(4.0).stroke_polyline(&point, false).is_empty());
+        let dot = Stroker::new(4.0).set_cap_style(CapStyle::Square);
+        assert_eq!(filled(&dot.stroke_polyline(&point, false)), 16);
+    }
+}
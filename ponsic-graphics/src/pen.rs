@@ -0,0 +1,257 @@
+use ponsic_color::Color;
+
+/// 线型
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum LineStyle<'a> {
+    #[default]
+    Solid,
+    Dash,
+    Dot,
+    DashDot,
+    DashDotDot,
+    Null,
+    /// 用户自定义的虚线，数组依次为实线段与间隔的长度
+    UserDef(&'a [u32]),
+}
+
+impl LineStyle<'_> {
+    /// 返回以像素为单位的虚线数组，实线与空画笔返回 `None`
+    ///
+    /// 预定义线型的数组与 GDI 相近并随画笔宽度缩放，用户自定义的数组直接以像素为单位；
+    /// 缩放后超出 `u32` 范围的长度取其最大值
+    pub fn dash_array(&self, width: usize) -> Option<Vec<u32>> {
+        let (array, scale): (&[u32], usize) = match *self {
+            LineStyle::Dash => (&[18, 6], width),
+            LineStyle::Dot => (&[3, 3], width),
+            LineStyle::DashDot => (&[9, 6, 3, 6], width),
+            LineStyle::DashDotDot => (&[9, 3, 3, 3, 3, 3], width),
+            LineStyle::UserDef(array) => (array, 1),
+            LineStyle::Solid | LineStyle::Null => return None,
+        };
+        let scale = u32::try_from(scale.max(1)).unwrap_or(u32::MAX);
+        Some(array.iter().map(|n| n.saturating_mul(scale)).collect())
+    }
+}
+
+/// 线段连接样式
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum JoinStyle {
+    Bevel,
+    #[default]
+    Miter,
+    Round,
+}
+
+/// 线段端点样式
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum CapStyle {
+    Round,
+    Square,
+    #[default]
+    Flat,
+}
+
+/// 画笔的填充样式
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum PenStyle {
+    Null,
+    #[default]
+    Solid,
+    Hollow,
+}
+
+/// 画笔描述
+#[derive(Debug, Clone)]
+pub struct GenPen<'a> {
+    pub line_style: LineStyle<'a>,
+    pub join_style: JoinStyle,
+    pub cap_style: CapStyle,
+    pub pen_style: PenStyle,
+    pub width: usize,
+    pub color: Color,
+}
+
+impl Default for GenPen<'static> {
+    fn default() -> Self {
+        GenPen {
+            line_style: Default::default(),
+            join_style: Default::default(),
+            cap_style: Default::default(),
+            pen_style: Default::default(),
+            width: Default::default(),
+            color: Color::BLACK,
+        }
+    }
+}
+
+impl<'a> GenPen<'a> {
+    pub fn set_line_style(mut self, line_style: LineStyle<'a>) -> Self {
+        self.line_style = line_style;
+        self
+    }
+}
+
+impl GenPen<'_> {
+    pub fn set_cap_style(mut self, cap_style: CapStyle) -> Self {
+        self.cap_style = cap_style;
+        self
+    }
+    pub fn set_join_style(mut self, join_style: JoinStyle) -> Self {
+        self.join_style = join_style;
+        self
+    }
+    pub fn set_pen_style(mut self, pen_style: PenStyle) -> Self {
+        self.pen_style = pen_style;
+        self
+    }
+    pub fn set_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+    pub fn set_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// 判断画笔是否不绘制任何内容
+    pub fn is_null(&self) -> bool {
+        self.line_style == LineStyle::Null || self.pen_style != PenStyle::Solid
+    }
+
+    /// 由画笔描述创建绘图后端的画笔，如 GDI 画笔
+    pub fn create<P: From<Self>>(self) -> P {
+        P::from(self)
+    }
+
+    /// 创建拥有所有权的画笔描述
+    pub fn to_buf(&self) -> GenPenBuf {
+        GenPenBuf {
+            line_style: self.line_style.into(),
+            join_style: self.join_style,
+            cap_style: self.cap_style,
+            pen_style: self.pen_style,
+            width: self.width,
+            color: self.color,
+        }
+    }
+}
+
+/// 拥有所有权的线型
+///
+/// 与 \[`LineStyle`\] 相同，但自行保存用户自定义的虚线数组
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum LineStyleBuf {
+    #[default]
+    Solid,
+    Dash,
+    Dot,
+    DashDot,
+    DashDotDot,
+    Null,
+    UserDef(Vec<u32>),
+}
+
+impl LineStyleBuf {
+    /// 以借用的形式返回线型
+    pub fn as_style(&self) -> LineStyle<'_> {
+        match self {
+            LineStyleBuf::Solid => LineStyle::Solid,
+            LineStyleBuf::Dash => LineStyle::Dash,
+            LineStyleBuf::Dot => LineStyle::Dot,
+            LineStyleBuf::DashDot => LineStyle::DashDot,
+            LineStyleBuf::DashDotDot => LineStyle::DashDotDot,
+            LineStyleBuf::Null => LineStyle::Null,
+            LineStyleBuf::UserDef(array) => LineStyle::UserDef(array),
+        }
+    }
+}
+
+impl From<LineStyle<'_>> for LineStyleBuf {
+    fn from(style: LineStyle<'_>) -> Self {
+        match style {
+            LineStyle::Solid => LineStyleBuf::Solid,
+            LineStyle::Dash => LineStyleBuf::Dash,
+            LineStyle::Dot => LineStyleBuf::Dot,
+            LineStyle::DashDot => LineStyleBuf::DashDot,
+            LineStyle::DashDotDot => LineStyleBuf::DashDotDot,
+            LineStyle::Null => LineStyleBuf::Null,
+            LineStyle::UserDef(array) => LineStyleBuf::UserDef(array.to_vec()),
+        }
+    }
+}
+
+/// 拥有所有权的画笔描述
+///
+/// 适用于需要长期保存画笔状态的绘图目标
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GenPenBuf {
+    pub line_style: LineStyleBuf,
+    pub join_style: JoinStyle,
+    pub cap_style: CapStyle,
+    pub pen_style: PenStyle,
+    pub width: usize,
+    pub color: Color,
+}
+
+impl Default for GenPenBuf {
+    fn default() -> Self {
+        GenPen::default().to_buf()
+    }
+}
+
+impl GenPenBuf {
+    /// 以借用的形式返回画笔描述
+    pub fn as_pen(&self) -> GenPen<'_> {
+        GenPen {
+            line_style: self.line_style.as_style(),
+            join_style: self.join_style,
+            cap_style: self.cap_style,
+            pen_style: self.pen_style,
+            width: self.width,
+            color: self.color,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pen_buf_round_trip_test() {
+        let dash = [4, 2];
+        let pen = GenPen::default()
+            .set_line_style(LineStyle::UserDef(&dash))
+            .set_width(3)
+            .set_color(Color::RED);
+        let buf = pen.to_buf();
+        assert_eq!(buf.line_style, LineStyleBuf::UserDef(vec![4, 2]));
+        let back = buf.as_pen();
+        assert_eq!(back.line_style, LineStyle::UserDef(&[4, 2]));
+        assert_eq!(back.width, 3);
+        assert_eq!(back.color, Color::RED);
+    }
+
+    #[test]
+    fn dash_array_test() {
+        assert_eq!(LineStyle::Solid.dash_array(2), None);
+        assert_eq!(LineStyle::Dash.dash_array(2), Some(vec![36, 12]));
+        assert_eq!(LineStyle::Dot.dash_array(0), Some(vec![3, 3]));
+        assert_eq!(LineStyle::UserDef(&[4, 1]).dash_array(3), Some(vec![4, 1]));
+        assert_eq!(
+            LineStyle::Dot.dash_array(usize::MAX),
+            Some(vec![u32::MAX, u32::MAX])
+        );
+        assert_eq!(
+            LineStyle::Dash.dash_array(300_000_000),
+            Some(vec![u32::MAX, 1_800_000_000])
+        );
+    }
+
+    #[test]
+    fn is_null_test() {
+        assert!(!GenPen::default().is_null());
+        assert!(GenPen::default().set_line_style(LineStyle::Null).is_null());
+        assert!(GenPen::default().set_pen_style(PenStyle::Null).is_null());
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{Bitmap, Canvas, Surface};
+use ponsic_color::Color;
+use ponsic_types::Sizei;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 若设置了此环境变量，则快照比较时以实际结果更新参考图像
+pub const UPDATE_SNAPSHOTS_VAR: &str = "PONSIC_UPDATE_SNAPSHOTS";
+
+/// 快照比较失败的原因
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// 读写图像文件失败
+    Io(io::Error),
+    /// 参考图像不存在，实际结果已写入 `actual`
+    Missing { actual: PathBuf },
+    /// 实际结果与参考图像的尺寸不同，实际结果已写入 `actual`
+    SizeMismatch {
+        expected: Sizei,
+        found: Sizei,
+        actual: PathBuf,
+    },
+    /// 存在超出容差的像素，实际结果与差异图像已分别写入 `actual` 与 `diff`
+    Mismatch {
+        pixels: usize,
+        actual: PathBuf,
+        diff: PathBuf,
+    },
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(e) => write!(f, "读写快照失败: {}", e),
+            SnapshotError::Missing { actual } => {
+                write!(f, "参考图像不存在，实际结果已写入 {}", actual.display())
+            }
+            SnapshotError::SizeMismatch {
+                expected,
+                found,
+                actual,
+            } => write!(
+                f,
+                "尺寸不符: 期望 {}×{}，实际 {}×{}，实际结果已写入 {}",
+                expected.width,
+                expected.height,
+                found.width,
+                found.height,
+                actual.display()
+            ),
+            SnapshotError::Mismatch {
+                pixels,
+                actual,
+                diff,
+            } => write!(
+                f,
+                "{} 个像素超出容差，实际结果已写入 {}，差异图像已写入 {}",
+                pixels,
+                actual.display(),
+                diff.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(e: io::Error) -> Self {
+        SnapshotError::Io(e)
+    }
+}
+
+/// 判断两个颜色包括透明度在内的每个通道之差是否都不超过容差
+fn within(a: Color, b: Color, tolerance: u8) -> bool {
+    a.red.abs_diff(b.red) <= tolerance
+        && a.green.abs_diff(b.green) <= tolerance
+        && a.blue.abs_diff(b.blue) <= tolerance
+        && a.alpha.abs_diff(b.alpha) <= tolerance
+}
+
+// 比较
+impl Bitmap {
+    /// 逐像素与尺寸相同的参考位图比较，返回超出容差的像素数与差异图像
+    ///
+    /// 差异图像中，超出容差的像素以红色标出，其余像素为参考图像的淡化灰度
+    ///
+    /// # Panics
+    /// 若两幅位图的尺寸不同，则会 panic
+    pub fn diff(&self, expected: &Bitmap, tolerance: u8) -> (usize, Bitmap) {
+        assert_eq!(self.size(), expected.size(), "位图尺寸不同");
+        let mut mismatched = 0;
+        let pixels = self
+            .pixels()
+            .iter()
+            .zip(expected.pixels())
+            .map(|(&a, &e)| {
+                if within(a, e, tolerance) {
+                    let luma =
+                        (e.red as u32 * 299 + e.green as u32 * 587 + e.blue as u32 * 114) / 1000;
+                    let v = (192 + luma / 4) as u8;
+                    Color::new(v, v, v)
+                } else {
+                    mismatched += 1;
+                    Color::RED
+                }
+            })
+            .collect();
+        let diff = Bitmap::from_pixels(expected.size(), pixels).unwrap();
+        (mismatched, diff)
+    }
+}
+
+/// 在路径的文件名与扩展名之间插入后缀，如 `a.png` 变为 `a.diff.png`
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("png");
+    path.with_extension(format!("{}.{}", suffix, ext))
+}
+
+/// 图像快照测试
+///
+/// 在指定尺寸的软件光栅化绘图表面上执行绘图代码，并将结果与参考图像比较
+///
+/// # Examples
+/// ```no_run
+/// use ponsic_color::Color;
+/// use ponsic_graphics::{Canvas, Snapshot};
+/// use ponsic_types::{Recti, Sizei};
+///
+/// Snapshot::new(Sizei::new(64, 64))
+///     .set_tolerance(2)
+///     .check("tests/snapshots/rect.png", |canvas| {
+///         canvas.set_brush_color(Color::RED);
+///         canvas.rectangle(Recti::new(8, 8, 56, 56));
+///     })
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    size: Sizei,
+    background: Color,
+    tolerance: u8,
+    update: bool,
+}
+
+impl Snapshot {
+    /// 创建指定尺寸的快照测试
+    ///
+    /// 默认背景为白色、容差为 0；是否更新参考图像由环境变量 [`UPDATE_SNAPSHOTS_VAR`] 决定
+    pub fn new(size: Sizei) -> Self {
+        Self {
+            size,
+            background: Color::WHITE,
+            tolerance: 0,
+            update: std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some(),
+        }
+    }
+
+    /// 设置绘图前的背景颜色
+    pub fn set_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// 设置每个颜色通道（包括透明度）所允许的最大差值
+    pub fn set_tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// 设置是否以实际结果覆盖参考图像，而不进行比较
+    pub fn set_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// 执行绘图代码并返回绘制结果
+    pub fn render(&self, paint: impl FnOnce(&mut dyn Canvas)) -> Bitmap {
+        let mut surface = Surface::from_bitmap(Bitmap::new(self.size, self.background));
+        paint(&mut surface);
+        surface.into_bitmap()
+    }
+
+    /// 执行绘图代码，并将结果与 `reference` 处的参考图像比较
+    ///
+    /// # Note
+    /// 比较失败时，实际结果与差异图像将写入参考图像所在的目录，
+    /// 文件名分别带有 `.actual` 与 `.diff` 后缀；比较成功时这些文件将被删除
+    pub fn check(
+        &self,
+        reference: impl AsRef<Path>,
+        paint: impl FnOnce(&mut dyn Canvas),
+    ) -> Result<(), SnapshotError> {
+        let reference = reference.as_ref();
+        let actual = self.render(paint);
+        let actual_path = sibling(reference, "actual");
+        let diff_path = sibling(reference, "diff");
+
+        if let Some(dir) = reference.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        if self.update {
+            actual.save(reference)?;
+            return Ok(());
+        }
+        let expected = match Bitmap::load(reference) {
+            Ok(expected) => expected,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                actual.save(&actual_path)?;
+                return Err(SnapshotError::Missing {
+                    actual: actual_path,
+                });
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if expected.size() != actual.size() {
+            actual.save(&actual_path)?;
+            return Err(SnapshotError::SizeMismatch {
+                expected: expected.size(),
+                found: actual.size(),
+                actual: actual_path,
+            });
+        }
+        let (pixels, diff) = actual.diff(&expected, self.tolerance);
+        if pixels > 0 {
+            actual.save(&actual_path)?;
+            diff.save(&diff_path)?;
+            return Err(SnapshotError::Mismatch {
+                pixels,
+                actual: actual_path,
+                diff: diff_path,
+            });
+        }
+        let _ = std::fs::remove_file(actual_path);
+        let _ = std::fs::remove_file(diff_path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ponsic_types::{Pointi as Point, Recti as Rect};
+
+    /// 返回测试专用的临时目录，以进程号区分同时运行的多个测试进程
+    fn temp_dir(test: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ponsic_snapshot_{}_{}", test, std::process::id()))
+    }
+
+    fn paint(canvas: &mut dyn Canvas) {
+        canvas.set_brush_color(Color::BLUE);
+        canvas.ellipse(Rect::new(2, 2, 14, 10));
+    }
+
+    #[test]
+    fn diff_test() {
+        let expected = Bitmap::new(Sizei::new(2, 1), Color::new(100, 100, 100));
+        let mut actual = expected.clone();
+        actual.set_pixel(Point::new(1, 0), Color::new(103, 100, 98));
+        assert_eq!(actual.diff(&expected, 3).0, 0);
+        let (pixels, image) = actual.diff(&expected, 2);
+        assert_eq!(pixels, 1);
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(Color::RED));
+        assert_ne!(image.pixel(Point::new(0, 0)), Some(Color::RED));
+
+        // 透明度同样参与比较
+        actual.set_pixel(Point::new(1, 0), Color::rgba(100, 100, 100, 250));
+        assert_eq!(actual.diff(&expected, 5).0, 0);
+        assert_eq!(actual.diff(&expected, 4).0, 1);
+    }
+
+    #[test]
+    fn check_test() {
+        let dir = temp_dir("check");
+        let reference = dir.join("ellipse.png");
+        let _ = std::fs::remove_dir_all(&dir);
+        let snapshot = Snapshot::new(Sizei::new(16, 12)).set_update(false);
+
+        let err = snapshot.check(&reference, paint).unwrap_err();
+        assert!(matches!(err, SnapshotError::Missing { .. }));
+        assert!(dir.join("ellipse.actual.png").exists());
+
+        snapshot
+            .clone()
+            .set_update(true)
+            .check(&reference, paint)
+            .unwrap();
+        snapshot.check(&reference, paint).unwrap();
+        assert!(!dir.join("ellipse.actual.png").exists());
+
+        let err = snapshot
+            .check(&reference, |canvas| {
+                paint(canvas);
+                canvas.draw_pixel(Point::new(0, 0), Color::BLACK);
+            })
+            .unwrap_err();
+        assert!(matches!(err, SnapshotError::Mismatch { pixels: 1, .. }));
+        let diff = Bitmap::load(dir.join("ellipse.diff.png")).unwrap();
+        assert_eq!(diff.pixel(Point::new(0, 0)), Some(Color::RED));
+
+        let err = Snapshot::new(Sizei::new(8, 8))
+            .set_update(false)
+            .check(&reference, paint)
+            .unwrap_err();
+        assert!(matches!(err, SnapshotError::SizeMismatch { .. }));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,786 @@
+pub(crate) mod scan;
+pub(crate) mod shape;
+
+use crate::path::FLATTEN_TOLERANCE;
+use crate::{Bitmap, Canvas, FillRule, GenBrush, GenPen, GenPenBuf, Mask, Path2D, Stroker};
+use ponsic_color::Color;
+use ponsic_types::{Pointf, Pointi as Point, Recti as Rect, Sizei};
+use scan::Vertex;
+use shape::{arc_angles, ellipse_params, vertex};
+
+/// 路径中的一个图形
+#[derive(Debug, Clone)]
+struct Figure {
+    points: Vec<Vertex>,
+    closed: bool,
+}
+
+/// 路径的记录状态
+#[derive(Debug, Clone, Default)]
+enum PathState {
+    #[default]
+    None,
+    Recording(Vec<Figure>),
+    Ended(Vec<Figure>),
+}
+
+/// 软件光栅化绘图表面
+///
+/// 以内存中的像素缓冲区作为绘图目标，提供与窗口 2D 绘图上下文相同的绘图操作，
+/// 不依赖任何平台接口，绘制结果逐像素确定
+///
+/// # Note
+/// 闭合图形先以画刷填充内部，再以画笔描绘轮廓；与 GDI 一致，
+/// 矩形类图形不包含其右边界与下边界，开放线段不包含其终点像素；
+/// 阴影线画刷只绘制阴影线本身，其间隙保持透明；
+/// 所有绘图操作均以 Porter-Duff 的 \[`CompositeOp::SrcOver`\] 运算合成至已有内容之上
+///
+/// 宽度大于 1 的画笔由 \[`Stroker`\] 展开为轮廓后填充，遵循画笔的线型、连接样式与端点样式；
+/// 宽度不大于 1 的画笔以 Bresenham 算法绘制，只遵循线型
+#[derive(Debug, Clone)]
+pub struct Surface {
+    bitmap: Bitmap,
+    pen: GenPenBuf,
+    brush: GenBrush,
+    current: Point,
+    path: PathState,
+}
+
+impl Surface {
+    /// 创建一个以白色填充的绘图表面
+    pub fn new(size: Sizei) -> Self {
+        Self::from_bitmap(Bitmap::new(size, Color::WHITE))
+    }
+
+    /// 以已有的位图作为绘图表面的初始内容
+    pub fn from_bitmap(bitmap: Bitmap) -> Self {
+        Self {
+            bitmap,
+            pen: GenPenBuf::default(),
+            brush: GenBrush::Solid(Color::WHITE),
+            current: Point::new(0, 0),
+            path: PathState::None,
+        }
+    }
+
+    /// 返回绘图表面的尺寸
+    pub fn size(&self) -> Sizei {
+        self.bitmap.size()
+    }
+
+    /// 返回按行优先排列的像素数据
+    pub fn pixels(&self) -> &[Color] {
+        self.bitmap.pixels()
+    }
+
+    /// 返回指定位置的像素颜色
+    ///
+    /// # Note
+    /// 若位置超出绘图表面，则返回 `None`
+    pub fn pixel(&self, p: Point) -> Option<Color> {
+        self.bitmap.pixel(p)
+    }
+
+    /// 以指定颜色填充整个绘图表面
+    pub fn clear(&mut self, color: Color) {
+        self.bitmap.fill(color);
+    }
+
+    /// 返回绘图表面的内容
+    pub fn bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    /// 取出绘图表面的内容
+    pub fn into_bitmap(self) -> Bitmap {
+        self.bitmap
+    }
+
+    fn put(&mut self, x: i32, y: i32, color: Color) {
+        let p = Point::new(x, y);
+        if let Some(dst) = self.bitmap.pixel(p) {
+            self.bitmap.set_pixel(p, color.over(dst));
+        }
+    }
+}
+
+// 光栅化
+impl Surface {
+    fn fill_figures(&mut self, figures: &[Vec<Vertex>], rule: FillRule, brush: &GenBrush) {
+        let size = self.size();
+        let (width, height) = (size.width as i32, size.height as i32);
+        let pixels = self.bitmap.pixels_mut();
+        let sampler = brush.sampler();
+        scan::fill_spans(figures, rule, width, height, |y, x0, x1| {
+            let row = y as usize * size.width as usize;
+            for x in x0..x1 {
+                if let Some(color) = sampler(x, y) {
+                    let pixel = &mut pixels[row + x as usize];
+                    *pixel = color.over(*pixel);
+                }
+            }
+        });
+    }
+
+    fn stroke_figure(&mut self, points: &[Vertex], closed: bool) {
+        let pen = self.pen.as_pen();
+        let Some(stroker) = Stroker::from_pen(&pen) else {
+            return;
+        };
+        if points.is_empty() {
+            return;
+        }
+        let color = pen.color;
+        let mut path = Path2D::new();
+        let points: Vec<Pointf> = points.iter().map(|&(x, y)| Pointf::new(x, y)).collect();
+        path.add_contour(&points, closed);
+
+        if pen.width <= 1 {
+            // 细线条以 Bresenham 算法绘制，虚线先被切分为若干段；
+            // 线段先被裁剪至比表面大一圈的范围内，被裁剪的端点总是落在表面之外
+            let size = self.size();
+            let (min, max) = ((-1.0, -1.0), (size.width as f32, size.height as f32));
+            let round = |(x, y): Vertex| (x.round() as i32, y.round() as i32);
+            let bitmap = &mut self.bitmap;
+            for contour in stroker.dash(&path).flatten(FLATTEN_TOLERANCE) {
+                let n = contour.points.len();
+                let count = if contour.closed { n } else { n - 1 };
+                for i in 0..count {
+                    let (a, b) = (contour.points[i], contour.points[(i + 1) % n]);
+                    let Some((a, b)) = scan::clip_line((a.x, a.y), (b.x, b.y), min, max) else {
+                        continue;
+                    };
+                    scan::line(round(a), round(b), false, |x, y| {
+                        let p = Point::new(x, y);
+                        if let Some(dst) = bitmap.pixel(p) {
+                            bitmap.set_pixel(p, color.over(dst));
+                        }
+                    });
+                }
+            }
+            return;
+        }
+
+        let figures: Vec<Vec<Vertex>> = stroker
+            .stroke(&path)
+            .flatten(FLATTEN_TOLERANCE)
+            .into_iter()
+            .map(|c| c.points.into_iter().map(|p| (p.x, p.y)).collect())
+            .collect();
+        self.fill_figures(&figures, FillRule::NonZero, &GenBrush::Solid(color));
+    }
+
+    fn draw_shape(&mut self, outline: Vec<Figure>, fill: Option<Vec<Vec<Vertex>>>) {
+        match &mut self.path {
+            PathState::Recording(figures) => figures.extend(outline),
+            _ => {
+                if let Some(fill) = fill {
+                    let brush = self.brush.clone();
+                    self.fill_figures(&fill, FillRule::EvenOdd, &brush);
+                }
+                for figure in outline {
+                    self.stroke_figure(&figure.points, figure.closed);
+                }
+            }
+        }
+    }
+
+    fn draw_open(&mut self, points: Vec<Vertex>) {
+        self.draw_shape(
+            vec![Figure {
+                points,
+                closed: false,
+            }],
+            None,
+        );
+    }
+
+    fn draw_closed(&mut self, outline: Vec<Vertex>, fill: Vec<Vertex>) {
+        self.draw_shape(
+            vec![Figure {
+                points: outline,
+                closed: true,
+            }],
+            Some(vec![fill]),
+        );
+    }
+}
+
+/// 按覆盖率 `coverage` 将 `src` 覆盖于 `dst` 之上
+fn blend(dst: Color, src: Color, coverage: u8) -> Color {
+    let alpha = (src.alpha as u32 * coverage as u32 + 127) / 255;
+    src.with_alpha(alpha as u8).over(dst)
+}
+
+// 抗锯齿
+impl Surface {
+    /// 以当前画刷按覆盖率混合绘制蒙版
+    ///
+    /// 蒙版与绘图表面的左上角对齐
+    pub fn fill_mask(&mut self, mask: &Mask) {
+        let size = self.size();
+        let width = size.width.min(mask.size().width) as usize;
+        let height = size.height.min(mask.size().height) as usize;
+        let pixels = self.bitmap.pixels_mut();
+        let sampler = self.brush.sampler();
+        for y in 0..height {
+            for x in 0..width {
+                let coverage = mask.data()[y * mask.size().width as usize + x];
+                if coverage == 0 {
+                    continue;
+                }
+                if let Some(color) = sampler(x as i32, y as i32) {
+                    let pixel = &mut pixels[y * size.width as usize + x];
+                    *pixel = blend(*pixel, color, coverage);
+                }
+            }
+        }
+    }
+
+    /// 以当前画刷及指定的填充规则抗锯齿地填充路径
+    ///
+    /// # Note
+    /// 与 \[`Canvas`\] 的绘图操作不同，`path` 采用浮点坐标，
+    /// 像素 `(x, y)` 覆盖区域 `[x, x + 1) × [y, y + 1)`
+    pub fn fill_antialiased(&mut self, path: &Path2D, rule: FillRule) {
+        let mask = path.rasterize(self.size(), rule);
+        self.fill_mask(&mask);
+    }
+}
+
+fn bezier_chain(mut from: Vertex, points: &[Point], out: &mut Vec<Vertex>) {
+    for group in points.chunks_exact(3) {
+        let to = vertex(group[2]);
+        shape::bezier(from, vertex(group[0]), vertex(group[1]), to, out);
+        from = to;
+    }
+}
+
+// 路径
+impl Surface {
+    fn fill_path_figures(&mut self, figures: &[Figure]) {
+        let fill: Vec<Vec<Vertex>> = figures.iter().map(|f| f.points.clone()).collect();
+        let brush = self.brush.clone();
+        self.fill_figures(&fill, FillRule::EvenOdd, &brush);
+    }
+
+    fn take_path(&mut self) -> Vec<Figure> {
+        match std::mem::take(&mut self.path) {
+            PathState::None => Vec::new(),
+            PathState::Recording(figures) | PathState::Ended(figures) => figures,
+        }
+    }
+
+    fn update_current(&mut self, points: &[Vertex]) {
+        if let Some(&(x, y)) = points.last() {
+            self.current = Point::new(x.round() as i32, y.round() as i32);
+        }
+    }
+
+    /// 追加一段以当前位置为起点的开放折线
+    ///
+    /// 记录路径时，若最后一个图形未闭合，则折线将接续该图形
+    fn append_open(&mut self, points: Vec<Vertex>) {
+        if let PathState::Recording(figures) = &mut self.path {
+            match figures.last_mut() {
+                Some(figure) if !figure.closed => figure.points.extend_from_slice(&points[1..]),
+                _ => figures.push(Figure {
+                    points,
+                    closed: false,
+                }),
+            }
+        } else if points.len() >= 2 {
+            self.draw_open(points);
+        }
+    }
+}
+
+fn split_counts<'a>(points: &'a [Point], count: &'a [u32]) -> impl Iterator<Item = &'a [Point]> {
+    count.iter().scan(0usize, move |start, &n| {
+        let begin = (*start).min(points.len());
+        let end = (begin + n as usize).min(points.len());
+        *start = end;
+        Some(&points[begin..end])
+    })
+}
+
+impl Canvas for Surface {
+    fn pen(&self) -> GenPen<'_> {
+        self.pen.as_pen()
+    }
+
+    fn set_pen(&mut self, pen: &GenPen) {
+        self.pen = pen.to_buf();
+    }
+
+    fn brush(&self) -> &GenBrush {
+        &self.brush
+    }
+
+    fn set_brush(&mut self, brush: &GenBrush) {
+        self.brush = brush.clone();
+    }
+
+    fn polyline(&mut self, points: &[Point]) {
+        if points.len() >= 2 {
+            self.draw_open(points.iter().copied().map(vertex).collect());
+        }
+    }
+
+    fn arc(&mut self, border: Rect, p1: Point, p2: Point) {
+        let (center, (rx, ry), _) = ellipse_params(border);
+        let (start, sweep) = arc_angles(border, p1, p2);
+        self.draw_open(shape::arc(center, rx, ry, start, sweep));
+    }
+
+    fn angle_arc(&mut self, orgin: Point, radius: u32, start: f32, end: f32) {
+        let r = radius as f32;
+        let mut points = vec![vertex(self.current)];
+        points.extend(shape::arc(
+            vertex(orgin),
+            r,
+            r,
+            start.to_radians(),
+            end.to_radians(),
+        ));
+        self.update_current(&points);
+        self.append_open(points);
+    }
+
+    fn poly_bezier(&mut self, points: &[Point]) {
+        if let Some((&first, rest)) = points.split_first() {
+            let mut out = vec![vertex(first)];
+            bezier_chain(vertex(first), rest, &mut out);
+            if out.len() >= 2 {
+                self.draw_open(out);
+            }
+        }
+    }
+
+    fn rectangle(&mut self, rect: Rect) {
+        let (l, t) = (rect.left() as f32, rect.top() as f32);
+        let (r, b) = (rect.right() as f32, rect.bottom() as f32);
+        self.draw_closed(
+            vec![(l, t), (r - 1.0, t), (r - 1.0, b - 1.0), (l, b - 1.0)],
+            vec![
+                (l - 0.5, t - 0.5),
+                (r - 0.5, t - 0.5),
+                (r - 0.5, b - 0.5),
+                (l - 0.5, b - 0.5),
+            ],
+        );
+    }
+
+    fn ellipse(&mut self, rect: Rect) {
+        let (center, (sx, sy), (fx, fy)) = ellipse_params(rect);
+        let tau = std::f32::consts::TAU;
+        let mut outline = shape::arc(center, sx, sy, 0.0, tau);
+        let mut fill = shape::arc(center, fx, fy, 0.0, tau);
+        outline.pop();
+        fill.pop();
+        self.draw_closed(outline, fill);
+    }
+
+    fn polygon(&mut self, points: &[Point]) {
+        let points: Vec<Vertex> = points.iter().copied().map(vertex).collect();
+        self.draw_closed(points.clone(), points);
+    }
+
+    fn pie(&mut self, rect: Rect, p1: Point, p2: Point) {
+        let (center, (sx, sy), (fx, fy)) = ellipse_params(rect);
+        let (start, sweep) = arc_angles(rect, p1, p2);
+        let mut outline = vec![center];
+        outline.extend(shape::arc(center, sx, sy, start, sweep));
+        let mut fill = vec![center];
+        fill.extend(shape::arc(center, fx, fy, start, sweep));
+        self.draw_closed(outline, fill);
+    }
+
+    fn chord(&mut self, rect: Rect, p1: Point, p2: Point) {
+        let (center, (sx, sy), (fx, fy)) = ellipse_params(rect);
+        let (start, sweep) = arc_angles(rect, p1, p2);
+        self.draw_closed(
+            shape::arc(center, sx, sy, start, sweep),
+            shape::arc(center, fx, fy, start, sweep),
+        );
+    }
+
+    fn round_rect(&mut self, rect: Rect, dx: u32, dy: u32) {
+        let (l, t) = (rect.left() as f32, rect.top() as f32);
+        let (r, b) = (rect.right() as f32, rect.bottom() as f32);
+        let (rx, ry) = (dx as f32 / 2.0, dy as f32 / 2.0);
+        self.draw_closed(
+            shape::round_rect(l, t, r - 1.0, b - 1.0, rx, ry),
+            shape::round_rect(l - 0.5, t - 0.5, r - 0.5, b - 0.5, rx, ry),
+        );
+    }
+
+    fn begin_path(&mut self) {
+        self.path = PathState::Recording(Vec::new());
+    }
+
+    fn end_path(&mut self) {
+        if let PathState::Recording(figures) = std::mem::take(&mut self.path) {
+            self.path = PathState::Ended(figures);
+        }
+    }
+
+    fn move_to(&mut self, p: Point) {
+        self.current = p;
+        if let PathState::Recording(figures) = &mut self.path {
+            figures.push(Figure {
+                points: vec![vertex(p)],
+                closed: false,
+            });
+        }
+    }
+
+    fn line_to(&mut self, p: Point) {
+        self.polyline_to(&[p]);
+    }
+
+    fn arc_to(&mut self, rect: Rect, p1: Point, p2: Point) {
+        let (center, (rx, ry), _) = ellipse_params(rect);
+        let (start, sweep) = arc_angles(rect, p1, p2);
+        let mut points = vec![vertex(self.current)];
+        points.extend(shape::arc(center, rx, ry, start, sweep));
+        self.update_current(&points);
+        self.append_open(points);
+    }
+
+    fn poly_bezier_to(&mut self, points: &[Point]) {
+        let mut out = vec![vertex(self.current)];
+        bezier_chain(vertex(self.current), points, &mut out);
+        if let Some(last) = points.chunks_exact(3).last() {
+            self.current = last[2];
+        }
+        self.append_open(out);
+    }
+
+    fn polyline_to(&mut self, points: &[Point]) {
+        let mut out = vec![vertex(self.current)];
+        out.extend(points.iter().copied().map(vertex));
+        if let Some(&last) = points.last() {
+            self.current = last;
+        }
+        self.append_open(out);
+    }
+
+    fn close_figure(&mut self) {
+        if let PathState::Recording(figures) = &mut self.path
+            && let Some(figure) = figures.last_mut()
+        {
+            figure.closed = true;
+        }
+    }
+
+    fn stroke_path(&mut self) {
+        for figure in self.take_path() {
+            self.stroke_figure(&figure.points, figure.closed);
+        }
+    }
+
+    fn fill_path(&mut self) {
+        let figures = self.take_path();
+        self.fill_path_figures(&figures);
+    }
+
+    fn stroke_and_fill_path(&mut self) {
+        let figures = self.take_path();
+        self.fill_path_figures(&figures);
+        for figure in figures {
+            self.stroke_figure(&figure.points, figure.closed);
+        }
+    }
+
+    fn poly_polygon(&mut self, points: &[Point], count: &[u32]) {
+        let figures: Vec<Vec<Vertex>> = split_counts(points, count)
+            .map(|group| group.iter().copied().map(vertex).collect())
+            .collect();
+        let outline = figures
+            .iter()
+            .map(|points| Figure {
+                points: points.clone(),
+                closed: true,
+            })
+            .collect();
+        self.draw_shape(outline, Some(figures));
+    }
+
+    fn draw_pixel(&mut self, p: Point, color: Color) {
+        self.put(p.x, p.y, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CapStyle, HatchStyle, LineStyle, Pattern};
+    use ponsic_color::Gradient;
+    use ponsic_types::Transformf;
+
+    fn surface() -> Surface {
+        Surface::new(Sizei::new(20, 20))
+    }
+
+    fn count(surface: &Surface, color: Color) -> usize {
+        surface.pixels().iter().filter(|&&c| c == color).count()
+    }
+
+    #[test]
+    fn line_excludes_end_point_test() {
+        let mut s = surface();
+        s.line(Point::new(2, 5), Point::new(8, 5));
+        assert_eq!(count(&s, Color::BLACK), 6);
+        assert_eq!(s.pixel(Point::new(2, 5)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(8, 5)), Some(Color::WHITE));
+    }
+
+    #[test]
+    fn wide_line_test() {
+        let mut s = surface();
+        s.set_line_width(3);
+        s.line(Point::new(2, 5), Point::new(8, 5));
+        assert_eq!(count(&s, Color::BLACK), 18);
+        assert_eq!(s.pixel(Point::new(2, 4)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(2, 6)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(2, 7)), Some(Color::WHITE));
+    }
+
+    #[test]
+    fn pen_style_test() {
+        let mut s = surface();
+        s.set_line_width(3);
+        s.set_cap_style(CapStyle::Square);
+        s.line(Point::new(2, 5), Point::new(8, 5));
+        assert_eq!(count(&s, Color::BLACK), 9 * 3);
+
+        let mut s = surface();
+        s.set_line_style(LineStyle::Dash);
+        s.line(Point::new(0, 5), Point::new(20, 5));
+        assert_eq!(count(&s, Color::BLACK), 18);
+        assert_eq!(s.pixel(Point::new(18, 5)), Some(Color::WHITE));
+    }
+
+    #[test]
+    fn huge_coordinates_test() {
+        let mut s = surface();
+        s.polyline(&[Point::new(-100_000_000, 0), Point::new(100_000_000, 10)]);
+        assert_eq!(count(&s, Color::BLACK), 20);
+        assert_eq!(s.pixel(Point::new(0, 5)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(19, 5)), Some(Color::BLACK));
+
+        let mut s = surface();
+        s.set_brush_color(Color::RED);
+        s.rectangle(Rect::new(
+            -100_000_000,
+            -100_000_000,
+            100_000_000,
+            100_000_000,
+        ));
+        assert_eq!(count(&s, Color::RED), 400);
+
+        let mut s = surface();
+        s.set_brush_color(Color::RED);
+        s.ellipse(Rect::new(
+            -100_000_000,
+            -100_000_000,
+            100_000_000,
+            100_000_000,
+        ));
+        assert_eq!(count(&s, Color::RED), 400);
+
+        let mut s = surface();
+        s.angle_arc(Point::new(10, 10), 3, 0.0, 1e30);
+        assert!(count(&s, Color::BLACK) > 0);
+    }
+
+    #[test]
+    fn rectangle_test() {
+        let mut s = surface();
+        s.set_brush_color(Color::RED);
+        s.rectangle(Rect::new(2, 2, 8, 6));
+        // 轮廓占据 6 × 4 区域的边界，内部为 4 × 2
+        assert_eq!(count(&s, Color::BLACK), 16);
+        assert_eq!(count(&s, Color::RED), 8);
+        assert_eq!(s.pixel(Point::new(7, 5)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(8, 6)), Some(Color::WHITE));
+    }
+
+    #[test]
+    fn ellipse_test() {
+        let mut s = surface();
+        s.set_brush_color(Color::RED);
+        s.ellipse(Rect::new(2, 4, 18, 16));
+        for y in 0..20 {
+            for x in 0..20 {
+                let inside = (2..18).contains(&x) && (4..16).contains(&y);
+                if !inside {
+                    assert_eq!(s.pixel(Point::new(x, y)), Some(Color::WHITE), "({x}, {y})");
+                }
+            }
+        }
+        assert_eq!(s.pixel(Point::new(10, 10)), Some(Color::RED));
+        assert_eq!(s.pixel(Point::new(2, 4)), Some(Color::WHITE));
+        assert_eq!(s.pixel(Point::new(2, 10)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(17, 10)), Some(Color::BLACK));
+    }
+
+    #[test]
+    fn polygon_fill_test() {
+        let mut s = surface();
+        s.set_line_color(Color::RED);
+        s.set_brush_color(Color::RED);
+        s.polygon(&[
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(10, 10),
+            Point::new(0, 10),
+        ]);
+        assert_eq!(count(&s, Color::RED), 121);
+    }
+
+    #[test]
+    fn path_test() {
+        let mut s = surface();
+        s.set_brush_color(Color::BLUE);
+        s.begin_path();
+        s.move_to(Point::new(0, 0));
+        s.polyline_to(&[Point::new(10, 0), Point::new(10, 10), Point::new(0, 10)]);
+        s.close_figure();
+        s.end_path();
+        // 记录路径期间不会绘制任何内容
+        assert_eq!(count(&s, Color::WHITE), 400);
+        s.fill_path();
+        assert_eq!(count(&s, Color::BLUE), 100);
+        // 路径在使用后被丢弃
+        s.clear(Color::WHITE);
+        s.stroke_path();
+        assert_eq!(count(&s, Color::WHITE), 400);
+    }
+
+    #[test]
+    fn poly_polygon_hole_test() {
+        let mut s = surface();
+        s.set_line_color(Color::RED);
+        s.set_brush_color(Color::RED);
+        let points = [
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(10, 10),
+            Point::new(0, 10),
+            Point::new(3, 3),
+            Point::new(7, 3),
+            Point::new(7, 7),
+            Point::new(3, 7),
+        ];
+        s.poly_polygon(&points, &[4, 4]);
+        assert_eq!(s.pixel(Point::new(5, 5)), Some(Color::WHITE));
+        assert_eq!(s.pixel(Point::new(1, 1)), Some(Color::RED));
+    }
+
+    #[test]
+    fn angle_arc_moves_current_test() {
+        let mut s = surface();
+        s.move_to(Point::new(10, 10));
+        s.angle_arc(Point::new(10, 10), 5, 0.0, 90.0);
+        s.line_to(Point::new(0, 5));
+        assert_eq!(s.pixel(Point::new(15, 10)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(10, 5)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(5, 5)), Some(Color::BLACK));
+    }
+
+    #[test]
+    fn set_pen_and_brush_test() {
+        fn paint(canvas: &mut dyn Canvas) {
+            canvas.set_pen(&GenPen::default().set_line_style(LineStyle::Null));
+            canvas.set_brush(&GenBrush::Hatch(Color::RED, HatchStyle::Horizontal));
+            canvas.rectangle(Rect::new(0, 0, 16, 16));
+        }
+        let mut s = surface();
+        paint(&mut s);
+        assert_eq!(count(&s, Color::BLACK), 0);
+        assert_eq!(count(&s, Color::RED), 32);
+        assert_eq!(s.pixel(Point::new(3, 8)), Some(Color::RED));
+        assert_eq!(s.pixel(Point::new(3, 9)), Some(Color::WHITE));
+    }
+
+    #[test]
+    fn gradient_brush_test() {
+        let mut s = surface();
+        s.set_line_style(LineStyle::Null);
+        let gradient = Gradient::from_colors(&[Color::RED, Color::BLUE]);
+        s.set_brush_linear_gradient(Point::new(0, 0), Point::new(10, 0), gradient.clone());
+        s.rectangle(Rect::new(0, 0, 11, 2));
+        assert_eq!(s.pixel(Point::new(0, 0)), Some(Color::RED));
+        assert_eq!(s.pixel(Point::new(5, 1)), Some(Color::new(128, 0, 128)));
+        assert_eq!(s.pixel(Point::new(10, 0)), Some(Color::BLUE));
+
+        s.set_brush_radial_gradient(Point::new(10, 10), 4, gradient);
+        s.ellipse(Rect::new(2, 2, 19, 19));
+        assert_eq!(s.pixel(Point::new(10, 10)), Some(Color::RED));
+        assert_eq!(s.pixel(Point::new(10, 16)), Some(Color::BLUE));
+        assert_eq!(s.pixel(Point::new(12, 10)), Some(Color::new(128, 0, 128)));
+        // 切换颜色后恢复为纯色画刷
+        s.set_brush_color(Color::LIME);
+        assert_eq!(s.brush(), &GenBrush::Solid(Color::LIME));
+    }
+
+    #[test]
+    fn pattern_brush_test() {
+        let mut s = surface();
+        s.set_line_style(LineStyle::Null);
+        let image = Bitmap::from_pixels(
+            Sizei::new(2, 2),
+            vec![Color::RED, Color::BLUE, Color::BLUE, Color::RED],
+        );
+        s.set_brush(&GenBrush::Pattern(
+            Pattern::new(image.unwrap()).set_transform(Transformf::translation(1.0, 0.0)),
+        ));
+        s.rectangle(Rect::new(0, 0, 20, 20));
+        assert_eq!(s.pixel(Point::new(0, 0)), Some(Color::BLUE));
+        assert_eq!(s.pixel(Point::new(1, 0)), Some(Color::RED));
+        assert_eq!(s.pixel(Point::new(1, 1)), Some(Color::BLUE));
+        assert_eq!(s.pixel(Point::new(18, 19)), Some(Color::RED));
+        assert_eq!(count(&s, Color::RED), 200);
+    }
+
+    #[test]
+    fn draw_pixel_test() {
+        let mut s = surface();
+        s.draw_pixel(Point::new(3, 4), Color::GREEN);
+        s.draw_pixel(Point::new(-1, 40), Color::GREEN);
+        assert_eq!(s.pixel(Point::new(3, 4)), Some(Color::GREEN));
+        assert_eq!(count(&s, Color::GREEN), 1);
+        assert_eq!(s.pixel(Point::new(20, 0)), None);
+    }
+
+    #[test]
+    fn fill_antialiased_test() {
+        let mut s = surface();
+        s.set_brush_color(Color::BLACK);
+        let mut path = Path2D::new();
+        path.move_to(ponsic_types::Pointf::new(2.0, 2.0));
+        path.line_to(ponsic_types::Pointf::new(6.5, 2.0));
+        path.line_to(ponsic_types::Pointf::new(6.5, 6.0));
+        path.line_to(ponsic_types::Pointf::new(2.0, 6.0));
+        s.fill_antialiased(&path, FillRule::NonZero);
+        assert_eq!(s.pixel(Point::new(3, 3)), Some(Color::BLACK));
+        assert_eq!(s.pixel(Point::new(6, 3)), Some(Color::new(127, 127, 127)));
+        assert_eq!(s.pixel(Point::new(7, 3)), Some(Color::WHITE));
+        assert_eq!(count(&s, Color::BLACK), 16);
+    }
+
+    #[test]
+    fn translucent_test() {
+        let mut s = surface();
+        s.set_line_style(LineStyle::Null);
+        s.set_brush_color(Color::rgba(0, 0, 0, 128));
+        s.rectangle(Rect::new(2, 2, 6, 6));
+        s.rectangle(Rect::new(4, 4, 8, 8));
+        assert_eq!(s.pixel(Point::new(2, 2)), Some(Color::new(127, 127, 127)));
+        // 重叠部分两次叠加
+        assert_eq!(s.pixel(Point::new(4, 4)), Some(Color::new(63, 63, 63)));
+        assert_eq!(s.pixel(Point::new(1, 1)), Some(Color::WHITE));
+    }
+}
@@ -0,0 +1,142 @@
+// 无抗锯齿的扫描转换
+// 坐标以像素索引为单位，像素 `(x, y)` 的中心位于整数坐标 `(x, y)` 处
+
+use crate::FillRule;
+
+/// 浮点坐标点
+pub(crate) type Vertex = (f32, f32);
+
+/// 以指定的填充规则填充多边形集合
+///
+/// 对每个像素中心进行采样，位于多边形内部的像素以水平跨度 `(y, x0, x1)` 的形式输出，
+/// 其中 `x1` 不包含在跨度内；跨度已被裁剪至 `width` × `height` 的范围内
+pub(crate) fn fill_spans(
+    figures: &[Vec<Vertex>],
+    rule: FillRule,
+    width: i32,
+    height: i32,
+    mut span: impl FnMut(i32, i32, i32),
+) {
+    let (mut min_y, mut max_y) = (f32::MAX, f32::MIN);
+    for &(_, y) in figures.iter().flatten() {
+        min_y = min_y.min(y);
+        max_y = max_y.max(y);
+    }
+    if min_y > max_y {
+        return;
+    }
+
+    let y0 = (min_y.ceil() as i32).max(0);
+    let y1 = (max_y.ceil() as i32).min(height);
+    let mut xs = Vec::new();
+    for y in y0..y1 {
+        let sy = y as f32;
+        xs.clear();
+        for figure in figures {
+            let n = figure.len();
+            for i in 0..n {
+                let (ax, ay) = figure[i];
+                let (bx, by) = figure[(i + 1) % n];
+                if (ay <= sy && sy < by) || (by <= sy && sy < ay) {
+                    let winding = if ay < by { 1 } else { -1 };
+                    xs.push((ax + (sy - ay) * (bx - ax) / (by - ay), winding));
+                }
+            }
+        }
+        xs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut winding = 0;
+        let mut start = 0.0;
+        for &(x, w) in &xs {
+            let inside = rule.contains(winding);
+            winding += w;
+            match (inside, rule.contains(winding)) {
+                (false, true) => start = x,
+                (true, false) => {
+                    let x0 = (start.ceil() as i32).max(0);
+                    let x1 = (x.ceil() as i32).min(width);
+                    if x0 < x1 {
+                        span(y, x0, x1);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 以 Liang–Barsky 算法将线段裁剪至矩形 `[x0, x1]` × `[y0, y1]` 内
+///
+/// 若线段完全位于矩形之外，则返回 `None`
+///
+/// # Note
+/// 远离矩形的端点在单精度下误差可达数个像素，因此裁剪在双精度下进行
+pub(crate) fn clip_line(
+    a: Vertex,
+    b: Vertex,
+    (x0, y0): Vertex,
+    (x1, y1): Vertex,
+) -> Option<(Vertex, Vertex)> {
+    let (ax, ay) = (a.0 as f64, a.1 as f64);
+    let (dx, dy) = (b.0 as f64 - ax, b.1 as f64 - ay);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for (p, q) in [
+        (-dx, ax - x0 as f64),
+        (dx, x1 as f64 - ax),
+        (-dy, ay - y0 as f64),
+        (dy, y1 as f64 - ay),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let at = |t: f64| ((ax + t * dx) as f32, (ay + t * dy) as f32);
+    let start = if t0 > 0.0 { at(t0) } else { a };
+    let end = if t1 < 1.0 { at(t1) } else { b };
+    Some((start, end))
+}
+
+/// 以 Bresenham 算法光栅化线段
+///
+/// 若 `last` 为 `false`，则不绘制终点像素
+pub(crate) fn line(
+    (mut x, mut y): (i32, i32),
+    (x1, y1): (i32, i32),
+    last: bool,
+    mut plot: impl FnMut(i32, i32),
+) {
+    let dx = (x1 as i64 - x as i64).abs();
+    let dy = -(y1 as i64 - y as i64).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    loop {
+        if x == x1 && y == y1 {
+            if last {
+                plot(x, y);
+            }
+            return;
+        }
+        plot(x, y);
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// 曲线与图形的折线化
+// 角度均采用数学约定（逆时针为正），由于屏幕坐标的 y 轴向下，计算时对 y 取反
+
+use super::scan::Vertex;
+use ponsic_types::{Pointi as Point, Recti as Rect};
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// 折线化所允许的最大误差（像素）
+const TOLERANCE: f32 = 0.25;
+
+/// 单段椭圆弧折线化的最大段数
+const MAX_SEGMENTS: f64 = 65536.0;
+
+/// 计算椭圆弧折线化所需的段数
+///
+/// 段数总是 4 的倍数，以使完整的椭圆关于其两轴对称；
+/// 段数被限制在 `MAX_SEGMENTS` 以内，非有限值视为最少的段数
+fn arc_segments(rx: f32, ry: f32, sweep: f32) -> usize {
+    // 半径很大时 `1 - TOLERANCE / r` 在单精度下会舍入为 1，因此以双精度计算
+    let r = rx.abs().max(ry.abs()) as f64;
+    let tolerance = TOLERANCE as f64;
+    let step = if r > tolerance {
+        2.0 * (1.0 - tolerance / r).acos()
+    } else {
+        std::f64::consts::FRAC_PI_2
+    };
+    let n = (sweep.abs() as f64 / step).ceil();
+    let n = if n.is_nan() {
+        1
+    } else {
+        n.clamp(1.0, MAX_SEGMENTS) as usize
+    };
+    n.next_multiple_of(4)
+}
+
+/// 将椭圆弧折线化，结果包含起点与终点
+pub(crate) fn arc(center: Vertex, rx: f32, ry: f32, start: f32, sweep: f32) -> Vec<Vertex> {
+    let n = arc_segments(rx, ry, sweep);
+    (0..=n)
+        .map(|i| {
+            let t = start + sweep * i as f32 / n as f32;
+            (center.0 + rx * t.cos(), center.1 - ry * t.sin())
+        })
+        .collect()
+}
+
+/// 计算从椭圆中心指向 `p` 的射线与椭圆交点的参数角
+pub(crate) fn radial_angle(center: Vertex, rx: f32, ry: f32, p: Vertex) -> f32 {
+    let dx = p.0 - center.0;
+    let dy = center.1 - p.1;
+    (dy * rx.max(f32::EPSILON)).atan2(dx * ry.max(f32::EPSILON))
+}
+
+/// 计算自 `start` 逆时针旋转至 `end` 所扫过的角度
+///
+/// 若两者重合，则视为完整的一周
+pub(crate) fn ccw_sweep(start: f32, end: f32) -> f32 {
+    let sweep = (end - start).rem_euclid(TAU);
+    if sweep == 0.0 { TAU } else { sweep }
+}
+
+/// 将整数坐标转换为顶点
+pub(crate) fn vertex(p: Point) -> Vertex {
+    (p.x as f32, p.y as f32)
+}
+
+/// 返回描边与填充所用的椭圆参数：中心、描边半径、填充半径
+pub(crate) fn ellipse_params(rect: Rect) -> (Vertex, Vertex, Vertex) {
+    let (l, t) = (rect.left() as f32, rect.top() as f32);
+    let (r, b) = (rect.right() as f32, rect.bottom() as f32);
+    let center = ((l + r - 1.0) / 2.0, (t + b - 1.0) / 2.0);
+    let stroke = (
+        ((r - l - 1.0) / 2.0).max(0.0),
+        ((b - t - 1.0) / 2.0).max(0.0),
+    );
+    let fill = ((r - l) / 2.0, (b - t) / 2.0);
+    (center, stroke, fill)
+}
+
+/// 返回 `rect` 所确定的椭圆上由 `p1`、`p2` 确定的弧的起始角与扫过的角度
+pub(crate) fn arc_angles(rect: Rect, p1: Point, p2: Point) -> (f32, f32) {
+    let (center, (rx, ry), _) = ellipse_params(rect);
+    let start = radial_angle(center, rx, ry, vertex(p1));
+    let end = radial_angle(center, rx, ry, vertex(p2));
+    (start, ccw_sweep(start, end))
+}
+
+/// 将三次贝塞尔曲线折线化，结果不包含起点
+pub(crate) fn bezier(p0: Vertex, p1: Vertex, p2: Vertex, p3: Vertex, out: &mut Vec<Vertex>) {
+    let dist = |a: Vertex, b: Vertex| (b.0 - a.0).hypot(b.1 - a.1);
+    let len = dist(p0, p1) + dist(p1, p2) + dist(p2, p3);
+    let n = ((len / 3.0).ceil() as usize).clamp(1, 1024);
+    for i in 1..=n {
+        let t = i as f32 / n as f32;
+        let u = 1.0 - t;
+        let a = u * u * u;
+        let b = 3.0 * u * u * t;
+        let c = 3.0 * u * t * t;
+        let d = t * t * t;
+        out.push((
+            a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+            a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1,
+        ));
+    }
+}
+
+/// 生成圆角矩形的闭合轮廓
+pub(crate) fn round_rect(x0: f32, y0: f32, x1: f32, y1: f32, rx: f32, ry: f32) -> Vec<Vertex> {
+    let rx = rx.min((x1 - x0) / 2.0).max(0.0);
+    let ry = ry.min((y1 - y0) / 2.0).max(0.0);
+    let corners = [
+        ((x1 - rx, y0 + ry), 0.0),
+        ((x0 + rx, y0 + ry), FRAC_PI_2),
+        ((x0 + rx, y1 - ry), PI),
+        ((x1 - rx, y1 - ry), PI + FRAC_PI_2),
+    ];
+    let mut points = Vec::new();
+    for (center, start) in corners {
+        points.extend(arc(center, rx, ry, start, FRAC_PI_2));
+    }
+    points.dedup();
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ccw_sweep_test() {
+        assert_eq!(ccw_sweep(0.0, FRAC_PI_2), FRAC_PI_2);
+        assert!((ccw_sweep(FRAC_PI_2, 0.0) - 3.0 * FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(ccw_sweep(1.0, 1.0), TAU);
+    }
+
+    #[test]
+    fn radial_angle_test() {
+        let c = (10.0, 10.0);
+        assert_eq!(radial_angle(c, 5.0, 2.0, (20.0, 10.0)), 0.0);
+        assert!((radial_angle(c, 5.0, 2.0, (10.0, 0.0)) - FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn arc_segments_test() {
+        assert_eq!(arc_segments(10.0, 10.0, TAU) % 4, 0);
+        let n = arc_segments(1e8, 1e8, TAU);
+        assert!(n > 4 && n <= MAX_SEGMENTS as usize);
+        assert_eq!(arc_segments(10.0, 10.0, 1e30), MAX_SEGMENTS as usize);
+        assert_eq!(arc_segments(10.0, 10.0, f32::NAN), 4);
+    }
+
+    #[test]
+    fn arc_endpoints_test() {
+        let points = arc((0.0, 0.0), 10.0, 10.0, 0.0, PI);
+        let first = points[0];
+        let last = points[points.len() - 1];
+        assert!((first.0 - 10.0).abs() < 1e-4 && first.1.abs() < 1e-4);
+        assert!((last.0 + 10.0).abs() < 1e-4 && last.1.abs() < 1e-4);
+        // y 轴向下，逆时针的上半圆位于 y < 0 一侧
+        assert!(points[points.len() / 2].1 < -9.0);
+    }
+
+    #[test]
+    fn bezier_endpoint_test() {
+        let mut out = Vec::new();
+        bezier((0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0), &mut out);
+        assert_eq!(*out.last().unwrap(), (10.0, 0.0));
+    }
+}
@@ -0,0 +1,933 @@
+use crate::surface::scan::Vertex;
+use crate::surface::shape::{arc_angles, ellipse_params, vertex};
+use crate::{
+    Canvas, CapStyle, FontFamily, FontWeight, GenBrush, GenFont, GenPen, GenPenBuf, HatchStyle,
+    ImageFormat, JoinStyle, Pattern, TextCanvas, base64,
+};
+use ponsic_color::{Color, Gradient, InterpolationSpace, SpreadMode};
+use ponsic_types::{Pointi as Point, Recti as Rect, Sizei};
+use std::f32::consts::{PI, TAU};
+use std::fmt::{self, Display, Formatter, Write};
+
+/// 非 sRGB 插值空间的渐变在每两个色标之间划分的段数
+const GRADIENT_STEPS: usize = 8;
+
+/// 格式化坐标与长度，保留至多两位小数
+struct Num(f32);
+
+impl Display for Num {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let v = (self.0 * 100.0).round() / 100.0;
+        write!(f, "{}", if v == 0.0 { 0.0 } else { v })
+    }
+}
+
+struct Pt(Vertex);
+
+impl Display for Pt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", Num(self.0.0), Num(self.0.1))
+    }
+}
+
+/// 转义 XML 文本及属性值中的特殊字符
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// 生成椭圆弧的路径数据，返回弧的起点与自起点开始的 `A` 命令
+///
+/// 正的 `sweep` 表示视觉上的逆时针方向；为避免歧义，弧被拆分为不超过半周的若干段；
+/// 超过一周的部分只保留一周加上不足一周的余量，终点不变
+fn arc_data(center: Vertex, rx: f32, ry: f32, start: f32, sweep: f32) -> (Vertex, String) {
+    let at = |t: f32| (center.0 + rx * t.cos(), center.1 - ry * t.sin());
+    let sweep = if sweep.abs() > TAU {
+        sweep.signum() * (TAU + sweep.abs() % TAU)
+    } else {
+        sweep
+    };
+    let n = (sweep.abs() / PI).ceil().max(1.0) as usize;
+    let flag = if sweep > 0.0 { 0 } else { 1 };
+    let mut data = String::new();
+    for i in 1..=n {
+        let p = at(start + sweep * i as f32 / n as f32);
+        let _ = write!(data, " A{},{} 0 0 {} {}", Num(rx), Num(ry), flag, Pt(p));
+    }
+    (at(start), data)
+}
+
+fn polyline_data(points: &[Vertex]) -> String {
+    let mut data = String::new();
+    for (i, &p) in points.iter().enumerate() {
+        let _ = write!(data, "{}{}", if i == 0 { "M" } else { " L" }, Pt(p));
+    }
+    data
+}
+
+fn polygon_data(points: &[Point]) -> String {
+    let points: Vec<_> = points.iter().map(|&p| vertex(p)).collect();
+    polyline_data(&points) + " Z"
+}
+
+/// 生成以 `from` 为起点的贝塞尔曲线的 `C` 命令，返回其终点
+fn bezier_data(from: Point, points: &[Point]) -> (String, Point) {
+    let mut data = String::new();
+    let mut end = from;
+    for group in points.chunks_exact(3) {
+        let _ = write!(
+            data,
+            " C{} {} {}",
+            Pt(vertex(group[0])),
+            Pt(vertex(group[1])),
+            Pt(vertex(group[2]))
+        );
+        end = group[2];
+    }
+    (data, end)
+}
+
+fn ellipse_data(center: Vertex, rx: f32, ry: f32) -> String {
+    let (start, arc) = arc_data(center, rx, ry, 0.0, 2.0 * PI);
+    format!("M{}{} Z", Pt(start), arc)
+}
+
+fn round_rect_data(x0: f32, y0: f32, x1: f32, y1: f32, rx: f32, ry: f32) -> String {
+    let rx = rx.min((x1 - x0) / 2.0).max(0.0);
+    let ry = ry.min((y1 - y0) / 2.0).max(0.0);
+    let a = format!(" A{},{} 0 0 1", Num(rx), Num(ry));
+    format!(
+        "M{} H{}{a} {} V{}{a} {} H{}{a} {} V{}{a} {} Z",
+        Pt((x0 + rx, y0)),
+        Num(x1 - rx),
+        Pt((x1, y0 + ry)),
+        Num(y1 - ry),
+        Pt((x1 - rx, y1)),
+        Num(x0 + rx),
+        Pt((x0, y1 - ry)),
+        Num(y0 + ry),
+        Pt((x0 + rx, y0)),
+    )
+}
+
+/// 生成颜色属性 `name`，不透明度由属性 `opacity` 单独给出
+///
+/// SVG 1.1 不支持含透明度的 `#rrggbbaa` 颜色，不透明的颜色省略 `opacity` 属性
+fn color_attrs(name: &str, opacity: &str, color: Color) -> String {
+    let rgb = Color::new(color.red, color.green, color.blue);
+    let mut attrs = format!(r#" {}="{}""#, name, rgb);
+    if !color.is_opaque() {
+        let value = (color.alpha as f32 / 255.0 * 1000.0).round() / 1000.0;
+        let _ = write!(attrs, r#" {}="{}""#, opacity, value);
+    }
+    attrs
+}
+
+/// 将画笔转换为 SVG 描边属性，与 SVG 默认值相同的属性将被省略
+fn stroke_attrs(pen: &GenPenBuf) -> String {
+    if pen.as_pen().is_null() {
+        return String::from(r#" stroke="none""#);
+    }
+    let width = pen.width.max(1);
+    let mut attrs = color_attrs("stroke", "stroke-opacity", pen.color);
+    if width != 1 {
+        let _ = write!(attrs, r#" stroke-width="{}""#, width);
+    }
+    match pen.join_style {
+        JoinStyle::Bevel => attrs.push_str(r#" stroke-linejoin="bevel""#),
+        JoinStyle::Round => attrs.push_str(r#" stroke-linejoin="round""#),
+        JoinStyle::Miter => {}
+    }
+    match pen.cap_style {
+        CapStyle::Round => attrs.push_str(r#" stroke-linecap="round""#),
+        CapStyle::Square => attrs.push_str(r#" stroke-linecap="square""#),
+        CapStyle::Flat => {}
+    }
+    if let Some(array) = pen.as_pen().line_style.dash_array(width) {
+        let array: Vec<_> = array.iter().map(u32::to_string).collect();
+        let _ = write!(attrs, r#" stroke-dasharray="{}""#, array.join(","));
+    }
+    attrs
+}
+
+fn font_attrs(font: &GenFont) -> String {
+    let mut attrs = String::new();
+    let generic = match font.family {
+        FontFamily::Decorative => Some("fantasy"),
+        FontFamily::Modern => Some("monospace"),
+        FontFamily::Roman => Some("serif"),
+        FontFamily::Script => Some("cursive"),
+        FontFamily::Swiss => Some("sans-serif"),
+        FontFamily::Dontcare => None,
+    };
+    let family = match (font.name.as_str(), generic) {
+        ("", None) => None,
+        ("", Some(generic)) => Some(String::from(generic)),
+        (name, None) => Some(escape(name)),
+        (name, Some(generic)) => Some(format!("{}, {}", escape(name), generic)),
+    };
+    if let Some(family) = family {
+        let _ = write!(attrs, r#" font-family="{}""#, family);
+    }
+    if font.height != 0 {
+        let _ = write!(attrs, r#" font-size="{}""#, font.height.unsigned_abs());
+    }
+    if font.weight != FontWeight::Dontcare {
+        let _ = write!(attrs, r#" font-weight="{}""#, font.weight as u32);
+    }
+    if font.italic {
+        attrs.push_str(r#" font-style="italic""#);
+    }
+    let decoration = match (font.underline, font.strikeout) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    };
+    if let Some(decoration) = decoration {
+        let _ = write!(attrs, r#" text-decoration="{}""#, decoration);
+    }
+    attrs
+}
+
+/// 向路径数据追加内容，新的子路径以空格分隔
+fn append(path: &mut String, data: &str) {
+    if !path.is_empty() && data.starts_with('M') {
+        path.push(' ');
+    }
+    path.push_str(data);
+}
+
+fn hatch_name(style: HatchStyle) -> &'static str {
+    match style {
+        HatchStyle::BDiagonal => "bdiagonal",
+        HatchStyle::Cross => "cross",
+        HatchStyle::DiagCross => "diagcross",
+        HatchStyle::FDiagonal => "fdiagonal",
+        HatchStyle::Horizontal => "horizontal",
+        HatchStyle::Vertical => "vertical",
+    }
+}
+
+/// 路径的记录状态
+#[derive(Debug, Clone, Default)]
+enum PathState {
+    #[default]
+    None,
+    /// 路径数据，以及当前是否有未结束的开放图形
+    Recording(String, bool),
+    Ended(String),
+}
+
+/// SVG 矢量图绘图目标
+///
+/// 将绘图操作转换为 SVG 元素，通过 \[`Display`\] 输出完整的 SVG 文档；
+/// 输出是确定的，可以直接以文本形式比较
+///
+/// # Note
+/// 坐标与 \[`Surface`\](crate::Surface) 的像素约定一致：整数坐标位于像素中心，
+/// 因此宽度为 1 的线条恰好覆盖整像素。
+/// 字体的宽度、字符集、精度与质量等没有对应的 SVG 属性，将被忽略
+#[derive(Debug, Clone)]
+pub struct SvgDocument {
+    size: Sizei,
+    patterns: Vec<(String, String)>,
+    body: String,
+    pen: GenPenBuf,
+    brush: GenBrush,
+    font: GenFont,
+    text_color: Color,
+    current: Point,
+    path: PathState,
+}
+
+impl SvgDocument {
+    /// 创建指定尺寸的空白文档
+    pub fn new(size: Sizei) -> Self {
+        Self {
+            size,
+            patterns: Vec::new(),
+            body: String::new(),
+            pen: GenPenBuf::default(),
+            brush: GenBrush::Solid(Color::WHITE),
+            font: GenFont::default(),
+            text_color: Color::BLACK,
+            current: Point::new(0, 0),
+            path: PathState::None,
+        }
+    }
+
+    /// 返回文档的尺寸
+    pub fn size(&self) -> Sizei {
+        self.size
+    }
+
+    /// 返回画刷对应的 SVG 填充属性，阴影线、渐变与图案画刷将生成对应的定义
+    fn fill_attrs(&mut self) -> String {
+        let (color, style) = match self.brush.clone() {
+            GenBrush::Solid(color) => return color_attrs("fill", "fill-opacity", color),
+            GenBrush::Hatch(color, style) => (color, style),
+            GenBrush::LinearGradient(linear) => {
+                let attrs = format!(
+                    r#"x1="{}" y1="{}" x2="{}" y2="{}""#,
+                    Num(linear.start.x),
+                    Num(linear.start.y),
+                    Num(linear.end.x),
+                    Num(linear.end.y)
+                );
+                let value = self.gradient_value("linearGradient", attrs, &linear.gradient);
+                return format!(r#" fill="{}""#, value);
+            }
+            GenBrush::RadialGradient(radial) => {
+                let attrs = format!(
+                    r#"cx="{}" cy="{}" r="{}""#,
+                    Num(radial.center.x),
+                    Num(radial.center.y),
+                    Num(radial.radius)
+                );
+                let value = self.gradient_value("radialGradient", attrs, &radial.gradient);
+                return format!(r#" fill="{}""#, value);
+            }
+            GenBrush::Pattern(pattern) => {
+                return format!(r#" fill="{}""#, self.pattern_value(&pattern));
+            }
+        };
+        let id = format!("hatch-{}-{}", hatch_name(style), &color.to_string()[1..]);
+        if !self.patterns.iter().any(|(name, _)| *name == id) {
+            // 图案与像素网格对齐，每个被覆盖的像素绘制为一个单位正方形
+            let mut data = String::new();
+            for y in 0..8 {
+                for x in 0..8 {
+                    if style.covers(x, y) {
+                        let _ = write!(data, "M{},{}h1v1h-1z", x, y);
+                    }
+                }
+            }
+            let element = format!(
+                r#"<pattern id="{id}" x="-0.5" y="-0.5" width="8" height="8" patternUnits="userSpaceOnUse"><path d="{data}"{}/></pattern>"#,
+                color_attrs("fill", "fill-opacity", color)
+            );
+            self.patterns.push((id.clone(), element));
+        }
+        format!(r#" fill="url(#{})""#, id)
+    }
+
+    /// 生成渐变定义并返回对其的引用，相同的渐变只定义一次
+    ///
+    /// SVG 只在 sRGB 空间中插值，其它插值空间以额外的色标近似
+    fn gradient_value(&mut self, tag: &str, attrs: String, gradient: &Gradient) -> String {
+        let spread = match gradient.spread() {
+            SpreadMode::Pad => "pad",
+            SpreadMode::Repeat => "repeat",
+            SpreadMode::Reflect => "reflect",
+        };
+        let mut key = format!(
+            r#"{} gradientUnits="userSpaceOnUse" spreadMethod="{}">"#,
+            attrs, spread
+        );
+        let stops = gradient.stops();
+        for (i, stop) in stops.iter().enumerate() {
+            if let Some(prev) = i.checked_sub(1).map(|i| stops[i])
+                && gradient.space() != InterpolationSpace::Srgb
+                && stop.offset > prev.offset
+            {
+                for k in 1..GRADIENT_STEPS {
+                    let t = k as f32 / GRADIENT_STEPS as f32;
+                    let color = gradient.space().interpolate(prev.color, stop.color, t);
+                    let offset = prev.offset + (stop.offset - prev.offset) * t;
+                    let color = color_attrs("stop-color", "stop-opacity", color);
+                    let _ = write!(key, r#"<stop offset="{}"{}/>"#, offset, color);
+                }
+            }
+            let color = color_attrs("stop-color", "stop-opacity", stop.color);
+            let _ = write!(key, r#"<stop offset="{}"{}/>"#, stop.offset, color);
+        }
+        let index = match self.patterns.iter().position(|(name, _)| *name == key) {
+            Some(index) => index,
+            None => {
+                let index = self.patterns.len();
+                let element = format!(r#"<{tag} id="gradient-{index}" {key}</{tag}>"#);
+                self.patterns.push((key, element));
+                index
+            }
+        };
+        format!("url(#gradient-{})", index)
+    }
+
+    /// 生成图案定义并返回对其的引用，相同的图案只定义一次
+    ///
+    /// 图像以 PNG 数据内嵌，并按像素的原样放大
+    fn pattern_value(&mut self, pattern: &Pattern) -> String {
+        let size = pattern.image.size();
+        let t = pattern.transform;
+        // 图案以像素边缘为原点，而正文以像素中心为原点
+        let key = format!(
+            r#"width="{w}" height="{h}" patternUnits="userSpaceOnUse" patternTransform="matrix({} {} {} {} {} {})"><image width="{w}" height="{h}" preserveAspectRatio="none" style="image-rendering:pixelated" href="data:image/png;base64,{}"/>"#,
+            Num(t.m11),
+            Num(t.m12),
+            Num(t.m21),
+            Num(t.m22),
+            Num(t.dx - 0.5),
+            Num(t.dy - 0.5),
+            base64::encode(&pattern.image.encode(ImageFormat::Png)),
+            w = size.width,
+            h = size.height,
+        );
+        let index = match self.patterns.iter().position(|(name, _)| *name == key) {
+            Some(index) => index,
+            None => {
+                let index = self.patterns.len();
+                let element = format!(r#"<pattern id="pattern-{index}" {key}</pattern>"#);
+                self.patterns.push((key, element));
+                index
+            }
+        };
+        format!("url(#pattern-{})", index)
+    }
+
+    fn push_element(&mut self, data: &str, fill: bool, stroke: bool) {
+        let stroke = match stroke {
+            true => stroke_attrs(&self.pen),
+            false => String::from(r#" stroke="none""#),
+        };
+        let fill = match fill {
+            true => self.fill_attrs(),
+            false => String::from(r#" fill="none""#),
+        };
+        let _ = writeln!(self.body, r#"<path d="{}"{}{}/>"#, data, fill, stroke);
+    }
+
+    /// 绘制开放图形；记录路径时则加入路径
+    fn draw_open(&mut self, data: String) {
+        match &mut self.path {
+            PathState::Recording(path, open) => {
+                append(path, &data);
+                *open = true;
+            }
+            _ => {
+                if !self.pen.as_pen().is_null() {
+                    self.push_element(&data, false, true);
+                }
+            }
+        }
+    }
+
+    /// 绘制闭合图形；`outline` 为轮廓所在的几何，`fill` 为不描边时的填充区域
+    fn draw_closed(&mut self, outline: String, fill: String) {
+        match &mut self.path {
+            PathState::Recording(path, open) => {
+                append(path, &outline);
+                *open = false;
+            }
+            _ => match self.pen.as_pen().is_null() {
+                true => self.push_element(&fill, true, false),
+                false => self.push_element(&outline, true, true),
+            },
+        }
+    }
+
+    /// 自当前位置延续图形，`data` 为不含起点的路径数据
+    fn draw_from_current(&mut self, data: String, end: Point) {
+        let start = self.current;
+        self.current = end;
+        match &mut self.path {
+            PathState::Recording(path, open) => {
+                if !*open {
+                    append(path, &format!("M{}", Pt(vertex(start))));
+                    *open = true;
+                }
+                path.push_str(&data);
+            }
+            _ => {
+                if !self.pen.as_pen().is_null() {
+                    let data = format!("M{}{}", Pt(vertex(start)), data);
+                    self.push_element(&data, false, true);
+                }
+            }
+        }
+    }
+
+    fn take_path(&mut self) -> Option<String> {
+        match std::mem::take(&mut self.path) {
+            PathState::Ended(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SvgDocument {
+    /// 输出完整的 SVG 文档
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (w, h) = (self.size.width, self.size.height);
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#
+        )?;
+        if !self.patterns.is_empty() {
+            writeln!(f, "<defs>")?;
+            for (_, pattern) in &self.patterns {
+                writeln!(f, "{}", pattern)?;
+            }
+            writeln!(f, "</defs>")?;
+        }
+        writeln!(
+            f,
+            r#"<g transform="translate(0.5,0.5)" fill-rule="evenodd">"#
+        )?;
+        write!(f, "{}", self.body)?;
+        writeln!(f, "</g>")?;
+        writeln!(f, "</svg>")
+    }
+}
+
+impl Canvas for SvgDocument {
+    fn pen(&self) -> GenPen<'_> {
+        self.pen.as_pen()
+    }
+
+    fn set_pen(&mut self, pen: &GenPen) {
+        self.pen = pen.to_buf();
+    }
+
+    fn brush(&self) -> &GenBrush {
+        &self.brush
+    }
+
+    fn set_brush(&mut self, brush: &GenBrush) {
+        self.brush = brush.clone();
+    }
+
+    fn as_text_canvas(&mut self) -> Option<&mut dyn TextCanvas> {
+        Some(self)
+    }
+
+    fn polyline(&mut self, points: &[Point]) {
+        if points.len() < 2 {
+            return;
+        }
+        let points: Vec<_> = points.iter().map(|&p| vertex(p)).collect();
+        self.draw_open(polyline_data(&points));
+    }
+
+    fn arc(&mut self, border: Rect, p1: Point, p2: Point) {
+        let (center, (rx, ry), _) = ellipse_params(border);
+        let (start, sweep) = arc_angles(border, p1, p2);
+        let (from, arc) = arc_data(center, rx, ry, start, sweep);
+        self.draw_open(format!("M{}{}", Pt(from), arc));
+    }
+
+    fn angle_arc(&mut self, orgin: Point, radius: u32, start: f32, end: f32) {
+        let r = radius as f32;
+        let (start, sweep) = (start.to_radians(), end.to_radians());
+        let (from, arc) = arc_data(vertex(orgin), r, r, start, sweep);
+        let t = start + sweep;
+        let to = Point::new(
+            (orgin.x as f32 + r * t.cos()).round() as i32,
+            (orgin.y as f32 - r * t.sin()).round() as i32,
+        );
+        self.draw_from_current(format!(" L{}{}", Pt(from), arc), to);
+    }
+
+    fn poly_bezier(&mut self, points: &[Point]) {
+        if let Some((&first, rest)) = points.split_first() {
+            let (data, _) = bezier_data(first, rest);
+            self.draw_open(format!("M{}{}", Pt(vertex(first)), data));
+        }
+    }
+
+    fn rectangle(&mut self, rect: Rect) {
+        let (l, t) = (rect.left() as f32, rect.top() as f32);
+        let (r, b) = (rect.right() as f32, rect.bottom() as f32);
+        let data = |l: f32, t: f32, r: f32, b: f32| {
+            format!("M{} H{} V{} H{} Z", Pt((l, t)), Num(r), Num(b), Num(l))
+        };
+        self.draw_closed(
+            data(l, t, r - 1.0, b - 1.0),
+            data(l - 0.5, t - 0.5, r - 0.5, b - 0.5),
+        );
+    }
+
+    fn ellipse(&mut self, rect: Rect) {
+        let (center, (rx, ry), (fx, fy)) = ellipse_params(rect);
+        self.draw_closed(ellipse_data(center, rx, ry), ellipse_data(center, fx, fy));
+    }
+
+    fn polygon(&mut self, points: &[Point]) {
+        if points.len() < 2 {
+            return;
+        }
+        let data = polygon_data(points);
+        self.draw_closed(data.clone(), data);
+    }
+
+    fn pie(&mut self, rect: Rect, p1: Point, p2: Point) {
+        let (center, (rx, ry), (fx, fy)) = ellipse_params(rect);
+        let (start, sweep) = arc_angles(rect, p1, p2);
+        let data = |rx: f32, ry: f32| {
+            let (from, arc) = arc_data(center, rx, ry, start, sweep);
+            format!("M{} L{}{} Z", Pt(center), Pt(from), arc)
+        };
+        self.draw_closed(data(rx, ry), data(fx, fy));
+    }
+
+    fn chord(&mut self, rect: Rect, p1: Point, p2: Point) {
+        let (center, (rx, ry), (fx, fy)) = ellipse_params(rect);
+        let (start, sweep) = arc_angles(rect, p1, p2);
+        let data = |rx: f32, ry: f32| {
+            let (from, arc) = arc_data(center, rx, ry, start, sweep);
+            format!("M{}{} Z", Pt(from), arc)
+        };
+        self.draw_closed(data(rx, ry), data(fx, fy));
+    }
+
+    fn round_rect(&mut self, rect: Rect, dx: u32, dy: u32) {
+        let (l, t) = (rect.left() as f32, rect.top() as f32);
+        let (r, b) = (rect.right() as f32, rect.bottom() as f32);
+        let (rx, ry) = (dx as f32 / 2.0, dy as f32 / 2.0);
+        self.draw_closed(
+            round_rect_data(l, t, r - 1.0, b - 1.0, rx, ry),
+            round_rect_data(l - 0.5, t - 0.5, r - 0.5, b - 0.5, rx, ry),
+        );
+    }
+
+    fn begin_path(&mut self) {
+        self.path = PathState::Recording(String::new(), false);
+    }
+
+    fn end_path(&mut self) {
+        if let PathState::Recording(data, _) = std::mem::take(&mut self.path) {
+            self.path = PathState::Ended(data);
+        }
+    }
+
+    fn move_to(&mut self, p: Point) {
+        self.current = p;
+        if let PathState::Recording(path, open) = &mut self.path {
+            append(path, &format!("M{}", Pt(vertex(p))));
+            *open = true;
+        }
+    }
+
+    fn line_to(&mut self, p: Point) {
+        self.draw_from_current(format!(" L{}", Pt(vertex(p))), p);
+    }
+
+    fn arc_to(&mut self, rect: Rect, p1: Point, p2: Point) {
+        let (center, (rx, ry), _) = ellipse_params(rect);
+        let (start, sweep) = arc_angles(rect, p1, p2);
+        let (from, arc) = arc_data(center, rx, ry, start, sweep);
+        let t = start + sweep;
+        let to = Point::new(
+            (center.0 + rx * t.cos()).round() as i32,
+            (center.1 - ry * t.sin()).round() as i32,
+        );
+        self.draw_from_current(format!(" L{}{}", Pt(from), arc), to);
+    }
+
+    fn poly_bezier_to(&mut self, points: &[Point]) {
+        let (data, end) = bezier_data(self.current, points);
+        self.draw_from_current(data, end);
+    }
+
+    fn polyline_to(&mut self, points: &[Point]) {
+        let Some(&end) = points.last() else {
+            return;
+        };
+        let mut data = String::new();
+        for &p in points {
+            let _ = write!(data, " L{}", Pt(vertex(p)));
+        }
+        self.draw_from_current(data, end);
+    }
+
+    fn close_figure(&mut self) {
+        if let PathState::Recording(path, open) = &mut self.path
+            && *open
+        {
+            path.push_str(" Z");
+            *open = false;
+        }
+    }
+
+    fn stroke_path(&mut self) {
+        if let Some(data) = self.take_path()
+            && !self.pen.as_pen().is_null()
+        {
+            self.push_element(&data, false, true);
+        }
+    }
+
+    fn fill_path(&mut self) {
+        if let Some(data) = self.take_path() {
+            self.push_element(&data, true, false);
+        }
+    }
+
+    fn stroke_and_fill_path(&mut self) {
+        if let Some(data) = self.take_path() {
+            self.push_element(&data, true, true);
+        }
+    }
+
+    fn poly_polygon(&mut self, points: &[Point], count: &[u32]) {
+        let mut data = Vec::new();
+        let mut start = 0;
+        for &n in count {
+            let end = (start + n as usize).min(points.len());
+            if end - start >= 2 {
+                data.push(polygon_data(&points[start..end]));
+            }
+            start = end;
+        }
+        if !data.is_empty() {
+            let data = data.join(" ");
+            self.draw_closed(data.clone(), data);
+        }
+    }
+
+    fn draw_pixel(&mut self, p: Point, color: Color) {
+        let _ = writeln!(
+            self.body,
+            r#"<rect x="{}" y="{}" width="1" height="1"{}/>"#,
+            Num(p.x as f32 - 0.5),
+            Num(p.y as f32 - 0.5),
+            color_attrs("fill", "fill-opacity", color)
+        );
+    }
+}
+
+impl TextCanvas for SvgDocument {
+    fn font(&self) -> &GenFont {
+        &self.font
+    }
+
+    fn set_font(&mut self, font: &GenFont) {
+        self.font = font.clone();
+    }
+
+    fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    fn out_text(&mut self, text: &str, p: Point) {
+        let (x, y) = (p.x as f32 - 0.5, p.y as f32 - 0.5);
+        let mut attrs = font_attrs(&self.font);
+        if self.font.escapement != 0 {
+            // 字符串的倾斜角以十分之一度为单位，逆时针为正
+            let angle = -(self.font.escapement as f32) / 10.0;
+            let _ = write!(
+                attrs,
+                r#" transform="rotate({} {} {})""#,
+                Num(angle),
+                Num(x),
+                Num(y)
+            );
+        }
+        let _ = writeln!(
+            self.body,
+            r#"<text x="{}" y="{}"{}{} dominant-baseline="text-before-edge" xml:space="preserve">{}</text>"#,
+            Num(x),
+            Num(y),
+            color_attrs("fill", "fill-opacity", self.text_color),
+            attrs,
+            escape(text)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bitmap, LineStyle};
+    use ponsic_types::Transformf;
+
+    fn document() -> SvgDocument {
+        SvgDocument::new(Sizei::new(20, 10))
+    }
+
+    #[test]
+    fn rectangle_document_test() {
+        let mut svg = document();
+        svg.set_brush_color(Color::RED);
+        svg.rectangle(Rect::new(2, 2, 8, 6));
+        assert_eq!(
+            svg.to_string(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10" viewBox="0 0 20 10">"#,
+                "\n",
+                r#"<g transform="translate(0.5,0.5)" fill-rule="evenodd">"#,
+                "\n",
+                r##"<path d="M2,2 H7 V5 H2 Z" fill="#ff0000" stroke="#000000"/>"##,
+                "\n</g>\n</svg>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn pen_attrs_test() {
+        let dash = [4, 2];
+        let pen = GenPen::default()
+            .set_line_style(LineStyle::UserDef(&dash))
+            .set_join_style(JoinStyle::Round)
+            .set_cap_style(CapStyle::Square)
+            .set_width(3)
+            .set_color(Color::BLUE);
+        assert_eq!(
+            stroke_attrs(&pen.to_buf()),
+            r##" stroke="#0000ff" stroke-width="3" stroke-linejoin="round" stroke-linecap="square" stroke-dasharray="4,2""##
+        );
+        let pen = GenPen::default()
+            .set_line_style(LineStyle::Dot)
+            .set_width(2);
+        assert!(stroke_attrs(&pen.to_buf()).ends_with(r#"stroke-dasharray="6,6""#));
+        let pen = GenPen::default().set_line_style(LineStyle::Null);
+        assert_eq!(stroke_attrs(&pen.to_buf()), r#" stroke="none""#);
+    }
+
+    #[test]
+    fn hatch_pattern_test() {
+        let mut svg = document();
+        svg.set_brush(&GenBrush::Hatch(Color::RED, HatchStyle::Horizontal));
+        svg.rectangle(Rect::new(0, 0, 4, 4));
+        svg.ellipse(Rect::new(4, 0, 8, 4));
+        let text = svg.to_string();
+        // 同一图案只定义一次
+        assert_eq!(text.matches("<pattern").count(), 1);
+        assert_eq!(text.matches("url(#hatch-horizontal-ff0000)").count(), 2);
+        assert!(text.contains(r#"M0,0h1v1h-1zM1,0h1v1h-1z"#));
+    }
+
+    #[test]
+    fn gradient_test() {
+        let mut svg = document();
+        let gradient = Gradient::from_colors(&[Color::RED, Color::BLUE]);
+        svg.set_brush_linear_gradient(Point::new(0, 0), Point::new(10, 0), gradient.clone());
+        svg.rectangle(Rect::new(0, 0, 4, 4));
+        svg.rectangle(Rect::new(4, 0, 8, 4));
+        svg.set_brush_radial_gradient(
+            Point::new(5, 5),
+            5,
+            gradient.set_space(InterpolationSpace::OkLab),
+        );
+        svg.ellipse(Rect::new(0, 0, 10, 10));
+        let text = svg.to_string();
+        assert!(text.contains(concat!(
+            r##"<linearGradient id="gradient-0" x1="0" y1="0" x2="10" y2="0" "##,
+            r##"gradientUnits="userSpaceOnUse" spreadMethod="pad">"##,
+            r##"<stop offset="0" stop-color="#ff0000"/><stop offset="1" stop-color="#0000ff"/>"##,
+            "</linearGradient>"
+        )));
+        assert_eq!(text.matches("url(#gradient-0)").count(), 2);
+        // OKLab 插值以额外的色标近似
+        assert!(text.contains(r#"<radialGradient id="gradient-1" cx="5" cy="5" r="5""#));
+        assert_eq!(text.matches("<stop").count(), 2 + 9);
+    }
+
+    #[test]
+    fn translucent_test() {
+        // 透明度以单独的属性输出，而非 8 位十六进制颜色
+        let mut svg = document();
+        svg.set_pen(&GenPen::default().set_color(Color::rgba(0, 0, 255, 128)));
+        svg.set_brush_color(Color::rgba(255, 0, 0, 64));
+        svg.rectangle(Rect::new(2, 2, 8, 6));
+        svg.set_brush_linear_gradient(
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Gradient::from_colors(&[Color::TRANSPARENT, Color::BLUE]),
+        );
+        svg.draw_pixel(Point::new(1, 1), Color::rgba(0, 255, 0, 255));
+        svg.rectangle(Rect::new(0, 0, 4, 4));
+        let text = svg.to_string();
+        assert!(text.contains(concat!(
+            r##"fill="#ff0000" fill-opacity="0.251" "##,
+            r##"stroke="#0000ff" stroke-opacity="0.502"/>"##
+        )));
+        assert!(text.contains(r##"<stop offset="0" stop-color="#000000" stop-opacity="0"/>"##));
+        assert!(text.contains(r##"<rect x="0.5" y="0.5" width="1" height="1" fill="#00ff00"/>"##));
+        assert!(!text.contains("#ff000040"));
+    }
+
+    #[test]
+    fn pattern_test() {
+        let mut svg = document();
+        let image = Bitmap::new(Sizei::new(2, 3), Color::RED);
+        let pattern = Pattern::new(image).set_transform(Transformf::translation(1.0, 2.0));
+        svg.set_brush(&GenBrush::Pattern(pattern.clone()));
+        svg.rectangle(Rect::new(0, 0, 4, 4));
+        let scaled = Transformf::scaling(2.0, 2.0).then_translate(1.0, 2.0);
+        svg.set_brush(&GenBrush::Pattern(pattern.set_transform(scaled)));
+        svg.rectangle(Rect::new(4, 0, 8, 4));
+        let text = svg.to_string();
+        assert!(text.contains(concat!(
+            r#"<pattern id="pattern-0" width="2" height="3" patternUnits="userSpaceOnUse" "#,
+            r#"patternTransform="matrix(1 0 0 1 0.5 1.5)"><image width="2" height="3" "#,
+        )));
+        assert!(text.contains(r#"patternTransform="matrix(2 0 0 2 0.5 1.5)""#));
+        assert!(text.contains("href=\"data:image/png;base64,iVBORw0KGgo"));
+        assert_eq!(text.matches("url(#pattern-").count(), 2);
+    }
+
+    #[test]
+    fn path_test() {
+        let mut svg = document();
+        svg.set_line_style(LineStyle::Null);
+        svg.begin_path();
+        svg.move_to(Point::new(1, 1));
+        svg.line_to(Point::new(5, 1));
+        svg.polyline_to(&[Point::new(5, 5)]);
+        svg.close_figure();
+        svg.end_path();
+        svg.fill_path();
+        assert!(
+            svg.to_string()
+                .contains(r##"<path d="M1,1 L5,1 L5,5 Z" fill="#ffffff" stroke="none"/>"##)
+        );
+    }
+
+    #[test]
+    fn arc_test() {
+        let mut svg = document();
+        svg.move_to(Point::new(0, 5));
+        svg.angle_arc(Point::new(5, 5), 2, 0.0, 90.0);
+        svg.line_to(Point::new(0, 0));
+        let text = svg.to_string();
+        assert!(text.contains(r#"d="M0,5 L7,5 A2,2 0 0 0 5,3""#));
+        assert!(text.contains(r#"d="M5,3 L0,0""#));
+
+        // 超过一周的弧至多被拆分为 4 段
+        let (_, data) = arc_data((0.0, 0.0), 3.0, 3.0, 0.0, 1e30);
+        assert!((3..=4).contains(&data.matches('A').count()));
+        let (_, data) = arc_data((0.0, 0.0), 3.0, 3.0, 0.0, -5.0 * PI);
+        assert_eq!(data.matches('A').count(), 3);
+    }
+
+    #[test]
+    fn text_test() {
+        let mut svg = document();
+        svg.set_font(
+            &GenFont::new()
+                .set_height(-12)
+                .set_weight(FontWeight::Bold)
+                .set_underline(true)
+                .set_family(FontFamily::Roman)
+                .set_name(String::from("Times")),
+        );
+        svg.set_text_color(Color::RED);
+        svg.out_text("a < b & \"c\"", Point::new(1, 2));
+        assert!(svg.to_string().contains(concat!(
+            r##"<text x="0.5" y="1.5" fill="#ff0000" font-family="Times, serif" font-size="12" "##,
+            r#"font-weight="700" text-decoration="underline" dominant-baseline="text-before-edge" "#,
+            r#"xml:space="preserve">a &lt; b &amp; &quot;c&quot;</text>"#
+        )));
+    }
+}
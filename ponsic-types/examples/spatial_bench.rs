@@ -0,0 +1,77 @@
+//! R 树与线性查找的简单性能对比，无需窗口环境
+//!
+//! ```text
+//! cargo run --release -p ponsic-types --example spatial_bench [条目数量]
+//! ```
+use ponsic_types::{Point, RTree, Rect};
+use std::time::Instant;
+
+/// 线性同余伪随机数
+fn random(seed: &mut u64) -> f32 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    (*seed >> 40) as f32 / (1u64 << 24) as f32
+}
+
+fn main() {
+    let count: usize = std::env::args()
+        .nth(1)
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(100_000);
+    let mut seed = 1;
+    let rects: Vec<Rect<f32>> = (0..count)
+        .map(|_| {
+            let (x, y) = (random(&mut seed) * 10000.0, random(&mut seed) * 10000.0);
+            let (w, h) = (random(&mut seed) * 40.0, random(&mut seed) * 40.0);
+            Rect::new(x, y, x + w, y + h)
+        })
+        .collect();
+    let points: Vec<Point<f32>> = (0..10_000)
+        .map(|_| Point::new(random(&mut seed) * 10000.0, random(&mut seed) * 10000.0))
+        .collect();
+
+    let start = Instant::now();
+    let bulk = RTree::bulk_load(rects.iter().copied().zip(0..));
+    println!("bulk load {count} items: {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let mut tree = RTree::new();
+    for (i, &rect) in rects.iter().enumerate() {
+        tree.insert(rect, i);
+    }
+    println!("insert {count} items: {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let hits: usize = points.iter().map(|&p| bulk.query_point(p).count()).sum();
+    println!(
+        "{} point queries (bulk loaded): {:?}, {hits} hits",
+        points.len(),
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let hits: usize = points.iter().map(|&p| tree.query_point(p).count()).sum();
+    println!(
+        "{} point queries (inserted): {:?}, {hits} hits",
+        points.len(),
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let hits: usize = points
+        .iter()
+        .map(|&p| rects.iter().filter(|r| r.contains_with_bound(p)).count())
+        .sum();
+    println!(
+        "{} point queries (linear): {:?}, {hits} hits",
+        points.len(),
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    for &p in &points {
+        bulk.nearest(p);
+    }
+    println!("{} nearest queries: {:?}", points.len(), start.elapsed());
+}
@@ -0,0 +1,191 @@
+use super::{Scalar, Size};
+
+/// 矩形四个角各自的圆角半径
+///
+/// # Examples
+/// ```
+/// use ponsic_types::{CornerRadii, Size};
+///
+/// let radii = CornerRadii::top_bottom(8.0, 0.0);
+/// assert_eq!(radii.top_right, 8.0);
+/// // 相邻两角的半径之和超过边长时，所有半径按同一比例缩小
+/// assert_eq!(radii.fit(Size::new(10.0, 20.0)), CornerRadii::top_bottom(5.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CornerRadii<T: Copy> {
+    pub top_left: T,
+    pub top_right: T,
+    pub bottom_right: T,
+    pub bottom_left: T,
+}
+
+impl<T: Copy> CornerRadii<T> {
+    /// 按左上、右上、右下、左下的顺序由各角的半径创建
+    #[inline]
+    pub const fn new(top_left: T, top_right: T, bottom_right: T, bottom_left: T) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// 创建四个角半径相同的圆角
+    #[inline]
+    pub const fn uniform(radius: T) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// 创建上方两角半径为 `top`、下方两角半径为 `bottom` 的圆角
+    #[inline]
+    pub const fn top_bottom(top: T, bottom: T) -> Self {
+        Self::new(top, top, bottom, bottom)
+    }
+
+    /// 创建左侧两角半径为 `left`、右侧两角半径为 `right` 的圆角
+    #[inline]
+    pub const fn left_right(left: T, right: T) -> Self {
+        Self::new(left, right, right, left)
+    }
+}
+
+impl<T: Scalar> CornerRadii<T> {
+    /// 判断四个角的半径是否均不为正
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+        .iter()
+        .all(|&r| r <= T::ZERO)
+    }
+
+    /// 返回适合于指定尺寸矩形的圆角半径
+    ///
+    /// 负的半径视为 0；若某条边上相邻两角的半径之和超过边长，
+    /// 所有半径按同一比例缩小，使各边上的半径之和均不超过边长，整数半径向下取整
+    pub fn fit(&self, size: Size<T>) -> Self {
+        let r = |v: T| if v > T::ZERO { v.to_f64() } else { 0.0 };
+        let [tl, tr, br, bl] = [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+        .map(r);
+        let (w, h) = (size.width.to_f64().max(0.0), size.height.to_f64().max(0.0));
+        let mut scale: f64 = 1.0;
+        for (sum, length) in [(tl + tr, w), (bl + br, w), (tl + bl, h), (tr + br, h)] {
+            if sum > length {
+                scale = scale.min(length / sum);
+            }
+        }
+        let fit = |v: f64| {
+            let v = v * scale;
+            // 整数四舍五入可能使相邻半径之和超过边长
+            let rounded = T::from_f64(v);
+            if rounded.to_f64() > v {
+                T::from_f64(v.floor())
+            } else {
+                rounded
+            }
+        };
+        Self::new(fit(tl), fit(tr), fit(br), fit(bl))
+    }
+}
+
+mod operator_overload {
+    use super::*;
+
+    use std::ops::Add;
+    impl<T: Copy + Add<Output = T>> Add for CornerRadii<T> {
+        type Output = CornerRadii<T>;
+
+        #[inline]
+        fn add(self, rhs: Self) -> Self::Output {
+            Self::new(
+                self.top_left + rhs.top_left,
+                self.top_right + rhs.top_right,
+                self.bottom_right + rhs.bottom_right,
+                self.bottom_left + rhs.bottom_left,
+            )
+        }
+    }
+
+    use std::ops::Sub;
+    impl<T: Copy + Sub<Output = T>> Sub for CornerRadii<T> {
+        type Output = CornerRadii<T>;
+
+        #[inline]
+        fn sub(self, rhs: Self) -> Self::Output {
+            Self::new(
+                self.top_left - rhs.top_left,
+                self.top_right - rhs.top_right,
+                self.bottom_right - rhs.bottom_right,
+                self.bottom_left - rhs.bottom_left,
+            )
+        }
+    }
+
+    use std::ops::Mul;
+    impl<U: Copy, T: Copy + Mul<U, Output = T>> Mul<U> for CornerRadii<T> {
+        type Output = CornerRadii<T>;
+
+        #[inline]
+        fn mul(self, rhs: U) -> Self::Output {
+            Self::new(
+                self.top_left * rhs,
+                self.top_right * rhs,
+                self.bottom_right * rhs,
+                self.bottom_left * rhs,
+            )
+        }
+    }
+
+    use std::ops::Div;
+    impl<U: Copy, T: Copy + Div<U, Output = T>> Div<U> for CornerRadii<T> {
+        type Output = CornerRadii<T>;
+
+        #[inline]
+        fn div(self, rhs: U) -> Self::Output {
+            Self::new(
+                self.top_left / rhs,
+                self.top_right / rhs,
+                self.bottom_right / rhs,
+                self.bottom_left / rhs,
+            )
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_corner_radii_arithmetic() {
+            let a = CornerRadii::new(1, 2, 3, 4);
+            assert_eq!(a + CornerRadii::uniform(1), CornerRadii::new(2, 3, 4, 5));
+            assert_eq!(a - CornerRadii::uniform(1), CornerRadii::new(0, 1, 2, 3));
+            assert_eq!(a * 2, CornerRadii::new(2, 4, 6, 8));
+            assert_eq!(a / 2, CornerRadii::new(0, 1, 1, 2));
+            assert_eq!(CornerRadii::left_right(1, 2), CornerRadii::new(1, 2, 2, 1));
+        }
+
+        #[test]
+        fn test_corner_radii_fit() {
+            let radii = CornerRadii::new(4, 4, 2, 2);
+            assert_eq!(radii.fit(Size::new(100, 100)), radii);
+            assert_eq!(radii.fit(Size::new(6, 100)), CornerRadii::new(3, 3, 1, 1));
+            assert_eq!(
+                CornerRadii::new(-1.0, 10.0, 10.0, 0.0).fit(Size::new(10.0, 10.0)),
+                CornerRadii::new(0.0, 5.0, 5.0, 0.0)
+            );
+            assert!(CornerRadii::uniform(0).is_zero());
+            assert!(!CornerRadii::new(0, 0, 1, 0).is_zero());
+        }
+    }
+}
@@ -0,0 +1,620 @@
+use super::{Float, Point, Rect, Size};
+
+/// 折线化时每条曲线划分的最大段数
+const MAX_SEGMENTS: f64 = 65536.0;
+
+/// 参数化曲线，参数 `t` 的范围为 `[0, 1]`
+///
+/// 折线化、弧长与最近点查询均以 `tolerance` 为精度，即折线与曲线间的最大距离
+pub trait Curve<T: Float>: Copy {
+    /// 返回参数 `t` 处的点
+    fn point_at(&self, t: T) -> Point<T>;
+
+    /// 返回参数 `t` 处对参数的导数，其方向为曲线的切线方向
+    fn derivative_at(&self, t: T) -> Point<T>;
+
+    /// 在参数 `t` 处将曲线分割为两段
+    fn split(&self, t: T) -> (Self, Self);
+
+    /// 返回曲线的紧致外接矩形
+    fn bounding_box(&self) -> Rect<T>;
+
+    /// 返回按参数等分折线化时为满足精度所需的段数，至少为 1
+    fn segment_count(&self, tolerance: T) -> usize;
+
+    /// 返回曲线的起点
+    fn start(&self) -> Point<T> {
+        self.point_at(T::ZERO)
+    }
+
+    /// 返回曲线的终点
+    fn end(&self) -> Point<T> {
+        self.point_at(T::ONE)
+    }
+
+    /// 返回参数 `t` 处的单位切向量，导数为零时返回 `None`
+    fn tangent_at(&self, t: T) -> Option<Point<T>> {
+        self.derivative_at(t).normalize()
+    }
+
+    /// 返回参数 `t0` 至 `t1` 之间的一段曲线
+    fn subsegment(&self, t0: T, t1: T) -> Self {
+        let (_, rest) = self.split(t0);
+        if t0 == T::ONE {
+            return rest;
+        }
+        rest.split((t1 - t0) / (T::ONE - t0)).0
+    }
+
+    /// 将曲线折线化，结果包含起点与终点
+    fn flatten(&self, tolerance: T) -> Vec<Point<T>> {
+        let n = self.segment_count(tolerance);
+        let step = T::ONE / T::from_f64(n as f64);
+        let mut points = Vec::with_capacity(n + 1);
+        points.push(self.start());
+        for i in 1..n {
+            points.push(self.point_at(step * T::from_f64(i as f64)));
+        }
+        points.push(self.end());
+        points
+    }
+
+    /// 返回曲线的近似弧长
+    fn length(&self, tolerance: T) -> T {
+        let points = self.flatten(tolerance);
+        let mut length = T::ZERO;
+        for pair in points.windows(2) {
+            length += pair[0].distance(pair[1]);
+        }
+        length
+    }
+
+    /// 返回曲线上距 `p` 最近的点及其参数
+    ///
+    /// 先按参数等距采样找到最近的采样点，再在其相邻区间内以黄金分割搜索细化
+    fn nearest(&self, p: Point<T>, tolerance: T) -> (T, Point<T>) {
+        let n = self.segment_count(tolerance).max(8) * 2;
+        let step = T::ONE / T::from_f64(n as f64);
+        let distance = |t: T| self.point_at(t).distance_squared(p);
+        let mut best = (T::ZERO, distance(T::ZERO));
+        for i in 1..=n {
+            let t = step * T::from_f64(i as f64);
+            let d = distance(t);
+            if d < best.1 {
+                best = (t, d);
+            }
+        }
+
+        let ratio = T::from_f64(0.618_033_988_749_895);
+        let mut lo = (best.0 - step).max(T::ZERO);
+        let mut hi = (best.0 + step).min(T::ONE);
+        for _ in 0..48 {
+            let a = hi - (hi - lo) * ratio;
+            let b = lo + (hi - lo) * ratio;
+            if distance(a) < distance(b) {
+                hi = b;
+            } else {
+                lo = a;
+            }
+        }
+        let t = (lo + hi) / T::from_f64(2.0);
+        let t = if distance(t) < best.1 { t } else { best.0 };
+        (t, self.point_at(t))
+    }
+
+    /// 返回 `p` 至曲线的近似距离，可用于命中测试
+    fn distance_to(&self, p: Point<T>, tolerance: T) -> T {
+        self.nearest(p, tolerance).1.distance(p)
+    }
+}
+
+/// 将段数限制在 `[1, MAX_SEGMENTS]` 内，非有限值视为 1
+fn segments<T: Float>(n: T) -> usize {
+    let n = n.to_f64().ceil();
+    if n.is_nan() {
+        1
+    } else {
+        n.clamp(1.0, MAX_SEGMENTS) as usize
+    }
+}
+
+/// 由点集创建外接矩形
+fn bounds<T: Float>(points: impl IntoIterator<Item = Point<T>>) -> Rect<T> {
+    let mut points = points.into_iter();
+    let first = points.next().unwrap_or(Point::new(T::ZERO, T::ZERO));
+    let (min, max) = points.fold((first, first), |(min, max), p| {
+        (
+            Point::new(min.x.min(p.x), min.y.min(p.y)),
+            Point::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    });
+    Rect::from((min, Size::new(max.x - min.x, max.y - min.y)))
+}
+
+/// 返回 `a·t² + b·t + c = 0` 在 `(0, 1)` 内的实根
+fn quadratic_roots<T: Float>(a: T, b: T, c: T) -> impl Iterator<Item = T> {
+    let eps = T::EPSILON;
+    let roots = if a.abs() <= eps {
+        [(b.abs() > eps).then(|| -c / b), None]
+    } else {
+        let disc = b * b - T::from_f64(4.0) * a * c;
+        if disc < T::ZERO {
+            [None, None]
+        } else {
+            let sqrt = disc.sqrt();
+            let two_a = T::from_f64(2.0) * a;
+            [Some((-b + sqrt) / two_a), Some((-b - sqrt) / two_a)]
+        }
+    };
+    roots
+        .into_iter()
+        .flatten()
+        .filter(|&t| t > T::ZERO && t < T::ONE)
+}
+
+/// 二次贝塞尔曲线
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct QuadBezier<T: Float> {
+    pub from: Point<T>,
+    pub ctrl: Point<T>,
+    pub to: Point<T>,
+}
+
+impl<T: Float> QuadBezier<T> {
+    /// 创建二次贝塞尔曲线
+    #[inline]
+    pub const fn new(from: Point<T>, ctrl: Point<T>, to: Point<T>) -> Self {
+        Self { from, ctrl, to }
+    }
+
+    /// 升阶为形状相同的三次贝塞尔曲线
+    pub fn to_cubic(&self) -> CubicBezier<T> {
+        let k = T::from_f64(2.0 / 3.0);
+        CubicBezier::new(
+            self.from,
+            self.from.lerp(self.ctrl, k),
+            self.to.lerp(self.ctrl, k),
+            self.to,
+        )
+    }
+}
+
+impl<T: Float> Curve<T> for QuadBezier<T> {
+    fn point_at(&self, t: T) -> Point<T> {
+        let a = self.from.lerp(self.ctrl, t);
+        let b = self.ctrl.lerp(self.to, t);
+        a.lerp(b, t)
+    }
+
+    fn derivative_at(&self, t: T) -> Point<T> {
+        let two = T::from_f64(2.0);
+        ((self.ctrl - self.from).lerp(self.to - self.ctrl, t)) * two
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let a = self.from.lerp(self.ctrl, t);
+        let b = self.ctrl.lerp(self.to, t);
+        let m = a.lerp(b, t);
+        (Self::new(self.from, a, m), Self::new(m, b, self.to))
+    }
+
+    fn bounding_box(&self) -> Rect<T> {
+        let mut points = vec![self.from, self.to];
+        let d = self.from - self.ctrl * T::from_f64(2.0) + self.to;
+        let c = self.ctrl - self.from;
+        // 导数 2·(c + t·d) 的各分量为零处即为极值点
+        for (d, c) in [(d.x, c.x), (d.y, c.y)] {
+            points.extend(quadratic_roots(T::ZERO, d, c).map(|t| self.point_at(t)));
+        }
+        bounds(points)
+    }
+
+    fn segment_count(&self, tolerance: T) -> usize {
+        // 以 n 段等分时弦与曲线的距离不超过 |from - 2·ctrl + to| / (4·n²)
+        let dd = (self.from - self.ctrl * T::from_f64(2.0) + self.to).length();
+        segments((dd / (T::from_f64(4.0) * tolerance)).sqrt())
+    }
+}
+
+/// 三次贝塞尔曲线
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CubicBezier<T: Float> {
+    pub from: Point<T>,
+    pub ctrl1: Point<T>,
+    pub ctrl2: Point<T>,
+    pub to: Point<T>,
+}
+
+impl<T: Float> CubicBezier<T> {
+    /// 创建三次贝塞尔曲线
+    #[inline]
+    pub const fn new(from: Point<T>, ctrl1: Point<T>, ctrl2: Point<T>, to: Point<T>) -> Self {
+        Self {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+}
+
+impl<T: Float> Curve<T> for CubicBezier<T> {
+    fn point_at(&self, t: T) -> Point<T> {
+        self.split(t).0.to
+    }
+
+    fn derivative_at(&self, t: T) -> Point<T> {
+        let three = T::from_f64(3.0);
+        let a = (self.ctrl1 - self.from).lerp(self.ctrl2 - self.ctrl1, t);
+        let b = (self.ctrl2 - self.ctrl1).lerp(self.to - self.ctrl2, t);
+        a.lerp(b, t) * three
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let ab = self.from.lerp(self.ctrl1, t);
+        let bc = self.ctrl1.lerp(self.ctrl2, t);
+        let cd = self.ctrl2.lerp(self.to, t);
+        let abc = ab.lerp(bc, t);
+        let bcd = bc.lerp(cd, t);
+        let m = abc.lerp(bcd, t);
+        (
+            Self::new(self.from, ab, abc, m),
+            Self::new(m, bcd, cd, self.to),
+        )
+    }
+
+    fn bounding_box(&self) -> Rect<T> {
+        let mut points = vec![self.from, self.to];
+        // 导数的三分之一为 a·t² + b·t + c
+        let a = self.to - self.ctrl2 * T::from_f64(3.0) + self.ctrl1 * T::from_f64(3.0) - self.from;
+        let b = (self.ctrl2 - self.ctrl1 * T::from_f64(2.0) + self.from) * T::from_f64(2.0);
+        let c = self.ctrl1 - self.from;
+        for (a, b, c) in [(a.x, b.x, c.x), (a.y, b.y, c.y)] {
+            points.extend(quadratic_roots(a, b, c).map(|t| self.point_at(t)));
+        }
+        bounds(points)
+    }
+
+    fn segment_count(&self, tolerance: T) -> usize {
+        // 二阶导数的模不超过 6·max(|d1|, |d2|)，弦与曲线的距离不超过其 1/(8·n²)
+        let d1 = (self.from - self.ctrl1 * T::from_f64(2.0) + self.ctrl2).length();
+        let d2 = (self.ctrl1 - self.ctrl2 * T::from_f64(2.0) + self.to).length();
+        let dd = d1.max(d2);
+        segments((T::from_f64(0.75) * dd / tolerance).sqrt())
+    }
+}
+
+/// 以中心参数化的椭圆弧
+///
+/// 弧上参数 `t` 对应离心角 `θ = start_angle + t·sweep_angle` 处的点
+/// `center + rotate((rx·cos θ, ry·sin θ), x_rotation)`；
+/// 角度以弧度为单位，在 y 轴向下的坐标系中正的角度沿顺时针方向
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EllipticalArc<T: Float> {
+    pub center: Point<T>,
+    pub radii: Size<T>,
+    pub x_rotation: T,
+    pub start_angle: T,
+    pub sweep_angle: T,
+}
+
+impl<T: Float> EllipticalArc<T> {
+    /// 创建椭圆弧
+    #[inline]
+    pub const fn new(
+        center: Point<T>,
+        radii: Size<T>,
+        x_rotation: T,
+        start_angle: T,
+        sweep_angle: T,
+    ) -> Self {
+        Self {
+            center,
+            radii,
+            x_rotation,
+            start_angle,
+            sweep_angle,
+        }
+    }
+
+    /// 由 SVG 的端点参数创建椭圆弧
+    ///
+    /// `large_arc` 为真时选取大于 180° 的弧，`sweep` 为真时选取沿正角度方向的弧；
+    /// 半径不足以连接两端点时按比例放大，两端点重合或半径为零时返回 `None`
+    pub fn from_endpoints(
+        from: Point<T>,
+        to: Point<T>,
+        radii: Size<T>,
+        x_rotation: T,
+        large_arc: bool,
+        sweep: bool,
+    ) -> Option<Self> {
+        let (mut rx, mut ry) = (radii.width.abs(), radii.height.abs());
+        if from == to || rx == T::ZERO || ry == T::ZERO {
+            return None;
+        }
+        let two = T::from_f64(2.0);
+        // 变换至椭圆轴对齐的坐标系
+        let p = ((from - to) / two).rotate(-x_rotation);
+        let lambda = (p.x * p.x) / (rx * rx) + (p.y * p.y) / (ry * ry);
+        if lambda > T::ONE {
+            let scale = lambda.sqrt();
+            rx = rx * scale;
+            ry = ry * scale;
+        }
+        let num = rx * rx * ry * ry - rx * rx * p.y * p.y - ry * ry * p.x * p.x;
+        let den = rx * rx * p.y * p.y + ry * ry * p.x * p.x;
+        let mut k = (num / den).max(T::ZERO).sqrt();
+        if large_arc == sweep {
+            k = -k;
+        }
+        let c = Point::new(k * rx * p.y / ry, -k * ry * p.x / rx);
+        let center = c.rotate(x_rotation) + (from + to) / two;
+
+        let u = Point::new((p.x - c.x) / rx, (p.y - c.y) / ry);
+        let v = Point::new((-p.x - c.x) / rx, (-p.y - c.y) / ry);
+        let start_angle = u.angle();
+        let mut sweep_angle = u.angle_to(v);
+        let full = T::PI * two;
+        if sweep && sweep_angle < T::ZERO {
+            sweep_angle += full;
+        } else if !sweep && sweep_angle > T::ZERO {
+            sweep_angle -= full;
+        }
+        Some(Self::new(
+            center,
+            Size::new(rx, ry),
+            x_rotation,
+            start_angle,
+            sweep_angle,
+        ))
+    }
+
+    /// 返回离心角 `angle` 处的点
+    fn point_at_angle(&self, angle: T) -> Point<T> {
+        let (sin, cos) = angle.sin_cos();
+        let p = Point::new(self.radii.width * cos, self.radii.height * sin);
+        p.rotate(self.x_rotation) + self.center
+    }
+
+    /// 以三次贝塞尔曲线近似椭圆弧，每段曲线至多覆盖 90°
+    pub fn to_cubic_beziers(&self) -> Vec<CubicBezier<T>> {
+        let quarter = T::PI / T::from_f64(2.0);
+        let n = segments(self.sweep_angle.abs() / quarter);
+        let step = self.sweep_angle / T::from_f64(n as f64);
+        let k = T::from_f64(4.0 / 3.0) * (step / T::from_f64(4.0)).tan();
+        (0..n)
+            .map(|i| {
+                let a0 = self.start_angle + step * T::from_f64(i as f64);
+                let a1 = a0 + step;
+                let (p0, p3) = (self.point_at_angle(a0), self.point_at_angle(a1));
+                let d0 = self.derivative_at_angle(a0) * k;
+                let d1 = self.derivative_at_angle(a1) * k;
+                CubicBezier::new(p0, p0 + d0, p3 - d1, p3)
+            })
+            .collect()
+    }
+
+    /// 返回离心角 `angle` 处对离心角的导数
+    fn derivative_at_angle(&self, angle: T) -> Point<T> {
+        let (sin, cos) = angle.sin_cos();
+        Point::new(-self.radii.width * sin, self.radii.height * cos).rotate(self.x_rotation)
+    }
+}
+
+impl<T: Float> Curve<T> for EllipticalArc<T> {
+    fn point_at(&self, t: T) -> Point<T> {
+        self.point_at_angle(self.start_angle + self.sweep_angle * t)
+    }
+
+    fn derivative_at(&self, t: T) -> Point<T> {
+        self.derivative_at_angle(self.start_angle + self.sweep_angle * t) * self.sweep_angle
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let first = self.sweep_angle * t;
+        (
+            Self {
+                sweep_angle: first,
+                ..*self
+            },
+            Self {
+                start_angle: self.start_angle + first,
+                sweep_angle: self.sweep_angle - first,
+                ..*self
+            },
+        )
+    }
+
+    fn bounding_box(&self) -> Rect<T> {
+        let mut points = vec![self.start(), self.end()];
+        let (rx, ry) = (self.radii.width, self.radii.height);
+        let (sin, cos) = self.x_rotation.sin_cos();
+        // x 与 y 分量各有两个极值点，相差 π
+        let candidates = [(-ry * sin).atan2(rx * cos), (ry * cos).atan2(rx * sin)];
+        for angle in candidates {
+            for angle in [angle, angle + T::PI] {
+                if let Some(t) = self.angle_param(angle) {
+                    points.push(self.point_at(t));
+                }
+            }
+        }
+        bounds(points)
+    }
+
+    fn segment_count(&self, tolerance: T) -> usize {
+        // 半径为 r 的圆弧以角度 θ 等分时，弦与弧的距离为 r·(1 - cos(θ/2))
+        let r = self.radii.width.abs().max(self.radii.height.abs());
+        if tolerance >= r {
+            return segments(self.sweep_angle.abs() / T::PI);
+        }
+        let cos = (T::ONE - tolerance / r).to_f64();
+        let theta = T::from_f64(2.0 * cos.acos());
+        segments(self.sweep_angle.abs() / theta)
+    }
+}
+
+impl<T: Float> EllipticalArc<T> {
+    /// 返回离心角 `angle`（模 2π）在弧上对应的参数，不在弧上时返回 `None`
+    fn angle_param(&self, angle: T) -> Option<T> {
+        if self.sweep_angle == T::ZERO {
+            return None;
+        }
+        let full = T::PI * T::from_f64(2.0);
+        let mut delta = (angle - self.start_angle)
+            .to_f64()
+            .rem_euclid(full.to_f64());
+        if self.sweep_angle < T::ZERO {
+            delta -= full.to_f64();
+        }
+        let t = T::from_f64(delta) / self.sweep_angle;
+        (t >= T::ZERO && t <= T::ONE).then_some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn near(a: Point<f64>, b: Point<f64>, eps: f64) -> bool {
+        a.distance(b) < eps
+    }
+
+    fn rect(r: Rect<f64>) -> [f64; 4] {
+        [r.left(), r.top(), r.right(), r.bottom()]
+    }
+
+    #[test]
+    fn quad_test() {
+        let q = QuadBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(2.0, 0.0),
+        );
+        assert_eq!(q.point_at(0.5), Point::new(1.0, 1.0));
+        assert_eq!(q.derivative_at(0.5), Point::new(2.0, 0.0));
+        assert_eq!(q.tangent_at(0.0), Point::new(1.0, 2.0).normalize());
+        let (a, b) = q.split(0.5);
+        assert_eq!((a.to, b.from), (Point::new(1.0, 1.0), Point::new(1.0, 1.0)));
+        assert_eq!(rect(q.bounding_box()), [0.0, 0.0, 2.0, 1.0]);
+        let c = q.to_cubic();
+        for t in [0.1, 0.5, 0.8] {
+            assert!(near(c.point_at(t), q.point_at(t), 1e-12));
+        }
+    }
+
+    #[test]
+    fn cubic_test() {
+        let c = CubicBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 4.0),
+            Point::new(4.0, 4.0),
+            Point::new(4.0, 0.0),
+        );
+        assert_eq!(c.point_at(0.5), Point::new(2.0, 3.0));
+        assert_eq!(c.derivative_at(0.0), Point::new(0.0, 12.0));
+        assert_eq!(rect(c.bounding_box()), [0.0, 0.0, 4.0, 3.0]);
+        let s = c.subsegment(0.25, 0.75);
+        assert!(near(s.from, c.point_at(0.25), 1e-12));
+        assert!(near(s.to, c.point_at(0.75), 1e-12));
+        assert!(near(s.point_at(0.5), c.point_at(0.5), 1e-12));
+
+        // 折线与曲线的距离不超过容差
+        let points = c.flatten(0.01);
+        assert_eq!((points[0], *points.last().unwrap()), (c.from, c.to));
+        for pair in points.windows(2) {
+            let mid = pair[0].lerp(pair[1], 0.5);
+            assert!(c.distance_to(mid, 0.001) <= 0.01);
+        }
+        let line = CubicBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(3.0, 0.0),
+        );
+        assert_eq!(line.flatten(0.1).len(), 2);
+        assert!((line.length(0.1) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn nearest_test() {
+        let c = CubicBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 4.0),
+            Point::new(4.0, 4.0),
+            Point::new(4.0, 0.0),
+        );
+        let (t, p) = c.nearest(Point::new(2.0, 10.0), 0.01);
+        assert!((t - 0.5).abs() < 1e-6 && near(p, Point::new(2.0, 3.0), 1e-6));
+        let (t, _) = c.nearest(Point::new(-5.0, -5.0), 0.01);
+        assert_eq!(t, 0.0);
+        assert!((c.distance_to(Point::new(2.0, 2.0), 0.01) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn arc_test() {
+        let arc = EllipticalArc::new(Point::new(0.0, 0.0), Size::new(2.0, 1.0), 0.0, 0.0, PI);
+        assert!(near(arc.point_at(0.5), Point::new(0.0, 1.0), 1e-12));
+        assert!(near(arc.end(), Point::new(-2.0, 0.0), 1e-12));
+        let b = rect(arc.bounding_box());
+        assert!(
+            b.iter()
+                .zip([-2.0, 0.0, 2.0, 1.0])
+                .all(|(a, b)| (a - b).abs() < 1e-12)
+        );
+
+        let circle = EllipticalArc::new(
+            Point::new(1.0, 1.0),
+            Size::new(3.0, 3.0),
+            0.0,
+            0.0,
+            -PI * 2.0,
+        );
+        assert!((circle.length(1e-4) - 6.0 * PI).abs() < 1e-3);
+        let b = rect(circle.bounding_box());
+        assert!(
+            b.iter()
+                .zip([-2.0, -2.0, 4.0, 4.0])
+                .all(|(a, b)| (a - b).abs() < 1e-12)
+        );
+        let (first, second) = circle.split(0.25);
+        assert!(near(first.end(), second.start(), 1e-12));
+        assert!(near(second.start(), Point::new(1.0, -2.0), 1e-12));
+
+        for bezier in arc.to_cubic_beziers() {
+            for t in [0.25, 0.5, 0.75] {
+                let p = bezier.point_at(t);
+                let r = (p.x / 2.0).powi(2) + p.y.powi(2);
+                assert!((r - 1.0).abs() < 1e-3);
+            }
+        }
+        assert_eq!(arc.to_cubic_beziers().len(), 2);
+    }
+
+    #[test]
+    fn from_endpoints_test() {
+        let from = Point::new(0.0, 0.0);
+        let to = Point::new(2.0, 0.0);
+        let radii = Size::new(1.0, 1.0);
+        // 沿正角度方向，即 y 轴向下时顺时针，经过上方的半圆
+        let arc = EllipticalArc::from_endpoints(from, to, radii, 0.0, false, true).unwrap();
+        assert!(near(arc.center, Point::new(1.0, 0.0), 1e-12));
+        assert!((arc.sweep_angle - PI).abs() < 1e-12);
+        assert!(near(arc.point_at(0.5), Point::new(1.0, -1.0), 1e-12));
+        let arc = EllipticalArc::from_endpoints(from, to, radii, 0.0, false, false).unwrap();
+        assert!(near(arc.point_at(0.5), Point::new(1.0, 1.0), 1e-12));
+
+        let small = Size::new(0.5, 0.5);
+        let arc = EllipticalArc::from_endpoints(from, to, small, FRAC_PI_2, true, true).unwrap();
+        assert!((arc.radii.width - 1.0).abs() < 1e-12);
+        assert!(near(arc.end(), to, 1e-12));
+
+        let radii = Size::new(2.0, 1.0);
+        let to = Point::new(1.0, 1.0);
+        let arc = EllipticalArc::from_endpoints(from, to, radii, 0.3, true, false).unwrap();
+        assert!(near(arc.start(), from, 1e-12) && near(arc.end(), to, 1e-12));
+        assert!(arc.sweep_angle < -PI);
+        assert!(EllipticalArc::from_endpoints(from, from, radii, 0.0, true, true).is_none());
+    }
+}
@@ -0,0 +1,234 @@
+use super::{Rect, Scalar, Size};
+use std::ops::Add;
+
+/// 矩形四边的距离，用于描述内边距、外边距与边框粗细
+///
+/// # Examples
+/// ```
+/// use ponsic_types::{Insets, Rect, Size};
+///
+/// let padding = Insets::symmetric(4, 2);
+/// assert_eq!(Rect::new(0, 0, 20, 10).deflate(&padding), Rect::new(4, 2, 16, 8));
+/// assert_eq!(Size::new(12, 6) + padding, Size::new(20, 10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Insets<T: Copy> {
+    pub left: T,
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+}
+
+/// 边框粗细，与 [`Insets`] 相同
+pub type Thickness<T> = Insets<T>;
+
+impl<T: Copy> Insets<T> {
+    /// 由四边的距离创建
+    #[inline]
+    pub const fn new(left: T, top: T, right: T, bottom: T) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// 创建四边距离相同的边距
+    #[inline]
+    pub const fn uniform(value: T) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// 创建左右距离为 `horizontal`、上下距离为 `vertical` 的边距
+    #[inline]
+    pub const fn symmetric(horizontal: T, vertical: T) -> Self {
+        Self::new(horizontal, vertical, horizontal, vertical)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Insets<T> {
+    /// 返回左右距离之和
+    #[inline]
+    pub fn horizontal(&self) -> T {
+        self.left + self.right
+    }
+
+    /// 返回上下距离之和
+    #[inline]
+    pub fn vertical(&self) -> T {
+        self.top + self.bottom
+    }
+
+    /// 返回边距在两个方向上占用的总尺寸
+    #[inline]
+    pub fn size(&self) -> Size<T> {
+        Size::new(self.horizontal(), self.vertical())
+    }
+}
+
+impl<T: Scalar> Rect<T> {
+    /// 按边距向内收缩矩形，参见 \[`inset()`\]
+    #[inline]
+    pub fn deflate(&self, insets: &Insets<T>) -> Self {
+        self.inset(insets.left, insets.top, insets.right, insets.bottom)
+    }
+
+    /// 按边距向外扩展矩形，参见 \[`outset()`\]
+    #[inline]
+    pub fn inflate(&self, insets: &Insets<T>) -> Self {
+        self.outset(insets.left, insets.top, insets.right, insets.bottom)
+    }
+}
+
+mod operator_overload {
+    use super::*;
+
+    impl<T: Copy + Add<Output = T>> Add for Insets<T> {
+        type Output = Insets<T>;
+
+        #[inline]
+        fn add(self, rhs: Self) -> Self::Output {
+            Self::new(
+                self.left + rhs.left,
+                self.top + rhs.top,
+                self.right + rhs.right,
+                self.bottom + rhs.bottom,
+            )
+        }
+    }
+
+    use std::ops::Sub;
+    impl<T: Copy + Sub<Output = T>> Sub for Insets<T> {
+        type Output = Insets<T>;
+
+        #[inline]
+        fn sub(self, rhs: Self) -> Self::Output {
+            Self::new(
+                self.left - rhs.left,
+                self.top - rhs.top,
+                self.right - rhs.right,
+                self.bottom - rhs.bottom,
+            )
+        }
+    }
+
+    use std::ops::Mul;
+    impl<U: Copy, T: Copy + Mul<U, Output = T>> Mul<U> for Insets<T> {
+        type Output = Insets<T>;
+
+        #[inline]
+        fn mul(self, rhs: U) -> Self::Output {
+            Self::new(
+                self.left * rhs,
+                self.top * rhs,
+                self.right * rhs,
+                self.bottom * rhs,
+            )
+        }
+    }
+
+    use std::ops::Div;
+    impl<U: Copy, T: Copy + Div<U, Output = T>> Div<U> for Insets<T> {
+        type Output = Insets<T>;
+
+        #[inline]
+        fn div(self, rhs: U) -> Self::Output {
+            Self::new(
+                self.left / rhs,
+                self.top / rhs,
+                self.right / rhs,
+                self.bottom / rhs,
+            )
+        }
+    }
+
+    use std::ops::Neg;
+    impl<T: Copy + Neg<Output = T>> Neg for Insets<T> {
+        type Output = Insets<T>;
+
+        #[inline]
+        fn neg(self) -> Self::Output {
+            Self::new(-self.left, -self.top, -self.right, -self.bottom)
+        }
+    }
+
+    use std::ops::AddAssign;
+    impl<T: Copy + AddAssign> AddAssign for Insets<T> {
+        #[inline]
+        fn add_assign(&mut self, rhs: Self) {
+            self.left += rhs.left;
+            self.top += rhs.top;
+            self.right += rhs.right;
+            self.bottom += rhs.bottom;
+        }
+    }
+
+    use std::ops::SubAssign;
+    impl<T: Copy + SubAssign> SubAssign for Insets<T> {
+        #[inline]
+        fn sub_assign(&mut self, rhs: Self) {
+            self.left -= rhs.left;
+            self.top -= rhs.top;
+            self.right -= rhs.right;
+            self.bottom -= rhs.bottom;
+        }
+    }
+
+    /// 尺寸加上边距在两个方向上占用的尺寸
+    impl<T: Copy + Add<Output = T>> Add<Insets<T>> for Size<T> {
+        type Output = Size<T>;
+
+        #[inline]
+        fn add(self, rhs: Insets<T>) -> Self::Output {
+            Size::new(self.width + rhs.horizontal(), self.height + rhs.vertical())
+        }
+    }
+
+    /// 尺寸减去边距在两个方向上占用的尺寸
+    impl<T: Copy + Add<Output = T> + Sub<Output = T>> Sub<Insets<T>> for Size<T> {
+        type Output = Size<T>;
+
+        #[inline]
+        fn sub(self, rhs: Insets<T>) -> Self::Output {
+            Size::new(self.width - rhs.horizontal(), self.height - rhs.vertical())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_insets_arithmetic() {
+            let a = Insets::new(1, 2, 3, 4);
+            let b = Insets::uniform(1);
+            assert_eq!(a + b, Insets::new(2, 3, 4, 5));
+            assert_eq!(a - b, Insets::new(0, 1, 2, 3));
+            assert_eq!(a * 2, Insets::new(2, 4, 6, 8));
+            assert_eq!(a / 2, Insets::new(0, 1, 1, 2));
+            assert_eq!(-b, Insets::new(-1, -1, -1, -1));
+            let mut c = a;
+            c += b;
+            c -= Insets::symmetric(2, 1);
+            assert_eq!(c, Insets::new(0, 2, 2, 4));
+            assert_eq!(a.size(), Size::new(4, 6));
+        }
+
+        #[test]
+        fn test_insets_size() {
+            let size = Size::new(10u32, 8);
+            let border = Thickness::new(1, 2, 3, 4);
+            assert_eq!(size + border, Size::new(14, 14));
+            assert_eq!(size - border, Size::new(6, 2));
+        }
+
+        #[test]
+        fn test_insets_rect() {
+            let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+            let margin = Insets::new(1.0, 2.0, 3.0, 4.0);
+            assert_eq!(rect.deflate(&margin), Rect::new(1.0, 2.0, 7.0, 6.0));
+            assert_eq!(rect.deflate(&margin).inflate(&margin), rect);
+        }
+    }
+}
@@ -1,10 +1,33 @@
+mod corner_radii;
+mod curve;
+mod float;
+mod insets;
 mod point;
+mod polygon;
 mod rect;
+mod region;
+mod scalar;
 mod size;
+mod spatial;
+mod transform;
+mod unit;
 
+pub use corner_radii::CornerRadii;
+pub use curve::{CubicBezier, Curve, EllipticalArc, QuadBezier};
+pub use float::Float;
+pub use insets::{Insets, Thickness};
 pub use point::Point;
-pub use rect::Rect;
+pub use polygon::{BooleanOp, Orientation, Polygon};
+pub use rect::{Anchor, Rect};
+pub use region::Region;
+pub use scalar::Scalar;
 pub use size::Size;
+pub use spatial::{ItemId, Query, RTree};
+pub use transform::Transform2D;
+pub use unit::{
+    Logical, LogicalPoint, LogicalRect, LogicalSize, Physical, PhysicalPoint, PhysicalRect,
+    PhysicalSize, Rounding, Scalable, ScaleFactor,
+};
 
 pub type Pointi = Point<i32>;
 pub type Pointf = Point<f32>;
@@ -12,3 +35,5 @@ pub type Sizei = Size<u32>;
 pub type Sizef = Size<f32>;
 pub type Recti = Rect<i32>;
 pub type Rectf = Rect<f32>;
+pub type Transformf = Transform2D<f32>;
+pub type Transformd = Transform2D<f64>;
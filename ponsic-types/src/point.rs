@@ -1,4 +1,9 @@
+use super::Float;
+use std::ops::{Add, Mul, Neg, Sub};
+
 /// 点结构体
+///
+/// 点也可视为自原点出发的向量，并提供点积、叉积、长度等向量运算
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Point<T: Copy> {
     pub x: T,
@@ -204,3 +209,241 @@ impl<T: Copy> From<(T, T)> for Point<T> {
         Self { x, y }
     }
 }
+
+impl<T: Copy> Point<T> {
+    /// 转换为另一种分量类型的点，仅支持无损转换
+    pub fn convert<U: Copy>(self) -> Point<U>
+    where
+        T: Into<U>,
+    {
+        Point {
+            x: self.x.into(),
+            y: self.y.into(),
+        }
+    }
+}
+
+impl Point<i32> {
+    /// 转换为 `f32` 坐标的点，绝对值大于 2^24 的坐标将损失精度
+    #[inline]
+    pub fn to_f32(self) -> Point<f32> {
+        Point::new(self.x as f32, self.y as f32)
+    }
+
+    /// 转换为 `f64` 坐标的点
+    #[inline]
+    pub fn to_f64(self) -> Point<f64> {
+        self.convert()
+    }
+}
+
+macro_rules! impl_to_i32 {
+    ($($t:ident),*) => {$(
+        impl Point<$t> {
+            /// 向零取整转换为 `i32` 坐标的点，超出范围的坐标取 `i32` 的边界值，NaN 取 0
+            ///
+            /// 如需其它取整方式，可先调用 [`Point::round()`]、[`Point::floor()`] 或 [`Point::ceil()`]
+            #[inline]
+            pub fn to_i32(self) -> Point<i32> {
+                Point::new(self.x as i32, self.y as i32)
+            }
+        }
+    )*};
+}
+
+impl_to_i32!(f32, f64);
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Point<T> {
+    /// 返回两向量的点积
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// 返回两向量的叉积，即 `self.x * other.y - self.y * other.x`
+    ///
+    /// 在 y 轴向下的坐标系中，`other` 位于 `self` 的顺时针方向时叉积为正
+    #[inline]
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// 返回向量长度的平方
+    #[inline]
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// 返回两点间距离的平方
+    #[inline]
+    pub fn distance_squared(self, other: Self) -> T {
+        (other - self).length_squared()
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Point<T> {
+    /// 返回旋转 90° 的垂直向量 `(-y, x)`，旋转方向与 [`Point::rotate()`] 的正方向相同
+    #[inline]
+    pub fn perpendicular(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+}
+
+impl<T: Copy + PartialOrd> Point<T> {
+    /// 返回各分量的较小值组成的点
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        Self::new(min(self.x, other.x), min(self.y, other.y))
+    }
+
+    /// 返回各分量的较大值组成的点
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        let max = |a: T, b: T| if b > a { b } else { a };
+        Self::new(max(self.x, other.x), max(self.y, other.y))
+    }
+
+    /// 将各分量限制在 `min` 与 `max` 的对应分量之间
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl<T: Copy + PartialOrd + Neg<Output = T> + Default> Point<T> {
+    /// 返回各分量的绝对值组成的点
+    #[inline]
+    pub fn abs(self) -> Self {
+        let abs = |v: T| if v < T::default() { -v } else { v };
+        Self::new(abs(self.x), abs(self.y))
+    }
+}
+
+impl<T: Float> Point<T> {
+    /// 返回向量的长度
+    #[inline]
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// 返回两点间的距离
+    #[inline]
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
+    /// 返回方向相同的单位向量，向量长度为零或不是有限值时返回 `None`
+    #[inline]
+    pub fn normalize(self) -> Option<Self> {
+        let length = self.length();
+        (length != T::ZERO && length.is_finite())
+            .then(|| Self::new(self.x / length, self.y / length))
+    }
+
+    /// 返回绕原点旋转 `radians` 弧度后的向量
+    ///
+    /// 在 y 轴向下的坐标系中，正的角度使向量顺时针旋转，与 [`Transform2D::rotation()`] 相同
+    ///
+    /// [`Transform2D::rotation()`]: crate::Transform2D::rotation
+    #[inline]
+    pub fn rotate(self, radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// 返回向量与 x 轴正方向的夹角，范围为 `[-π, π]`
+    #[inline]
+    pub fn angle(self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    /// 返回自 `self` 旋转至 `other` 的有向角，范围为 `[-π, π]`
+    #[inline]
+    pub fn angle_to(self, other: Self) -> T {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// 返回 `self` 与 `other` 间的线性插值，`t` 为 0 时得到 `self`，为 1 时得到 `other`
+    #[inline]
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+
+    /// 返回各分量四舍五入后的点
+    #[inline]
+    pub fn round(self) -> Self {
+        Self::new(self.x.round(), self.y.round())
+    }
+
+    /// 返回各分量向下取整后的点
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self::new(self.x.floor(), self.y.floor())
+    }
+
+    /// 返回各分量向上取整后的点
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn vector_test() {
+        let a = Point::new(3, 4);
+        let b = Point::new(-4, 3);
+        assert_eq!(a.dot(b), 0);
+        assert_eq!(a.cross(b), 25);
+        assert_eq!(a.length_squared(), 25);
+        assert_eq!(a.distance_squared(b), 50);
+        assert_eq!(a.perpendicular(), b);
+        assert_eq!(b.abs(), Point::new(4, 3));
+        assert_eq!(a.min(b), Point::new(-4, 3));
+        assert_eq!(a.max(b), Point::new(3, 4));
+        assert_eq!(
+            Point::new(-1, 9).clamp(Point::new(0, 0), Point::new(5, 5)),
+            Point::new(0, 5)
+        );
+    }
+
+    #[test]
+    fn float_vector_test() {
+        let a = Point::new(3.0, 4.0);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.distance(Point::new(0.0, 0.0)), 5.0);
+        assert_eq!(a.normalize(), Some(Point::new(0.6, 0.8)));
+        assert_eq!(Point::new(0.0, 0.0).normalize(), None);
+        assert_eq!(a.lerp(Point::new(5.0, 0.0), 0.5), Point::new(4.0, 2.0));
+        let r = Point::new(1.0, 0.0).rotate(FRAC_PI_2);
+        assert!(r.x.abs() < 1e-12 && (r.y - 1.0).abs() < 1e-12);
+        assert_eq!(Point::new(0.0, 1.0).angle(), FRAC_PI_2);
+        assert_eq!(
+            Point::new(1.0, 0.0).angle_to(Point::new(0.0, -1.0)),
+            -FRAC_PI_2
+        );
+        assert_eq!(Point::new(1.0, 0.0).angle_to(Point::new(-1.0, 0.0)), PI);
+    }
+
+    #[test]
+    fn convert_test() {
+        let p = Point::new(-3, 7);
+        assert_eq!(p.to_f32(), Point::new(-3.0f32, 7.0));
+        assert_eq!(p.to_f64(), Point::new(-3.0, 7.0));
+        assert_eq!(
+            Point::new(1.0f32, 2.5).convert::<f64>(),
+            Point::new(1.0, 2.5)
+        );
+        let f = Point::new(-1.5f32, 2.5);
+        assert_eq!(f.to_i32(), Point::new(-1, 2));
+        assert_eq!(f.round().to_i32(), Point::new(-2, 3));
+        assert_eq!(f.floor().to_i32(), Point::new(-2, 2));
+        assert_eq!(f.ceil().to_i32(), Point::new(-1, 3));
+        assert_eq!(Point::new(f64::NAN, 1e20).to_i32(), Point::new(0, i32::MAX));
+    }
+}
@@ -0,0 +1,703 @@
+use super::{Float, Point, Rect, Size};
+use std::collections::HashMap;
+
+/// 布尔运算的距离容差，以坐标量级下的机器精度为单位
+const TOLERANCE_ULPS: f64 = 16.0;
+
+/// 多边形顶点的环绕方向
+///
+/// 方向以 y 轴向下的屏幕坐标系为准
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// 顺时针，有向面积为正
+    Clockwise,
+    /// 逆时针，有向面积为负
+    CounterClockwise,
+}
+
+/// 多边形布尔运算
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    /// 并集
+    Union,
+    /// 交集
+    Intersection,
+    /// 差集，即位于第一个多边形内而不在第二个多边形内的区域
+    Difference,
+    /// 对称差，即恰好位于一个多边形内的区域
+    Xor,
+}
+
+impl BooleanOp {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BooleanOp::Union => a || b,
+            BooleanOp::Intersection => a && b,
+            BooleanOp::Difference => a && !b,
+            BooleanOp::Xor => a != b,
+        }
+    }
+}
+
+/// 由顶点序列描述的闭合多边形，末顶点与首顶点之间隐含一条边
+///
+/// 除特别说明外，多边形内部以非零环绕规则判定
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polygon<T: Float> {
+    points: Vec<Point<T>>,
+}
+
+impl<T: Float> From<Vec<Point<T>>> for Polygon<T> {
+    fn from(points: Vec<Point<T>>) -> Self {
+        Self { points }
+    }
+}
+
+impl<T: Float> Polygon<T> {
+    /// 由顶点序列创建多边形
+    pub fn new(points: Vec<Point<T>>) -> Self {
+        Self { points }
+    }
+
+    /// 返回多边形的顶点
+    pub fn points(&self) -> &[Point<T>] {
+        &self.points
+    }
+
+    /// 返回多边形的边，依次为每条边的起点与终点
+    pub fn edges(&self) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+        let n = self.points.len();
+        (0..n).map(move |i| (self.points[i], self.points[(i + 1) % n]))
+    }
+
+    /// 返回有向面积，顺时针为正，逆时针为负
+    pub fn signed_area(&self) -> T {
+        let mut sum = T::ZERO;
+        for (a, b) in self.edges() {
+            sum += a.cross(b);
+        }
+        sum / T::from_f64(2.0)
+    }
+
+    /// 返回面积
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// 返回周长
+    pub fn perimeter(&self) -> T {
+        let mut sum = T::ZERO;
+        for (a, b) in self.edges() {
+            sum += a.distance(b);
+        }
+        sum
+    }
+
+    /// 返回顶点的环绕方向，面积为零时返回 `None`
+    pub fn orientation(&self) -> Option<Orientation> {
+        let area = self.signed_area();
+        if area > T::ZERO {
+            Some(Orientation::Clockwise)
+        } else if area < T::ZERO {
+            Some(Orientation::CounterClockwise)
+        } else {
+            None
+        }
+    }
+
+    /// 反转顶点的顺序
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+
+    /// 返回多边形的质心，面积为零时返回 `None`
+    pub fn centroid(&self) -> Option<Point<T>> {
+        let area = self.signed_area();
+        if area == T::ZERO {
+            return None;
+        }
+        let (mut cx, mut cy) = (T::ZERO, T::ZERO);
+        for (a, b) in self.edges() {
+            let cross = a.cross(b);
+            cx += (a.x + b.x) * cross;
+            cy += (a.y + b.y) * cross;
+        }
+        let k = T::from_f64(6.0) * area;
+        Some(Point::new(cx / k, cy / k))
+    }
+
+    /// 返回外接矩形，多边形没有顶点时返回 `None`
+    pub fn bounding_box(&self) -> Option<Rect<T>> {
+        let first = *self.points.first()?;
+        let (min, max) = self.points.iter().fold((first, first), |(min, max), &p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        Some(Rect::from((min, Size::new(max.x - min.x, max.y - min.y))))
+    }
+
+    /// 返回多边形绕点 `p` 的环绕数，顺时针环绕为正
+    pub fn winding_number(&self, p: Point<T>) -> i32 {
+        let mut winding = 0;
+        for (a, b) in self.edges() {
+            let side = (b - a).cross(p - a);
+            if a.y <= p.y {
+                if b.y > p.y && side > T::ZERO {
+                    winding += 1;
+                }
+            } else if b.y <= p.y && side < T::ZERO {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    /// 以非零环绕规则判断点是否位于多边形内，位于边上的点的结果不确定
+    pub fn contains(&self, p: Point<T>) -> bool {
+        self.winding_number(p) != 0
+    }
+
+    /// 以奇偶规则判断点是否位于多边形内，位于边上的点的结果不确定
+    pub fn contains_even_odd(&self, p: Point<T>) -> bool {
+        self.winding_number(p) % 2 != 0
+    }
+
+    /// 判断多边形是否为凸多边形，共线的顶点不影响结果，自相交的多边形不是凸多边形
+    pub fn is_convex(&self) -> bool {
+        let n = self.points.len();
+        let (mut sign, mut turning) = (T::ZERO, T::ZERO);
+        for i in 0..n {
+            let (a, b, c) = (
+                self.points[i],
+                self.points[(i + 1) % n],
+                self.points[(i + 2) % n],
+            );
+            let cross = (b - a).cross(c - b);
+            if cross != T::ZERO {
+                if sign != T::ZERO && (cross > T::ZERO) != (sign > T::ZERO) {
+                    return false;
+                }
+                sign = cross;
+                turning += (b - a).angle_to(c - b);
+            }
+        }
+        // 简单凸多边形的外角和恰为一周
+        let full = T::PI * T::from_f64(2.0);
+        n >= 3 && (turning.abs() - full).abs() < T::from_f64(1e-3)
+    }
+
+    /// 返回点集的凸包，顶点按顺时针排列且不含共线的顶点
+    pub fn convex_hull(points: &[Point<T>]) -> Self {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.y.partial_cmp(&b.y).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return Self::new(sorted);
+        }
+        // Andrew 单调链算法，分别构造上下两条链
+        let mut hull: Vec<Point<T>> = Vec::with_capacity(sorted.len() + 1);
+        for pass in 0..2 {
+            let start = hull.len();
+            for &p in sorted.iter() {
+                while hull.len() >= start + 2 {
+                    let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                    if (b - a).cross(p - b) > T::ZERO {
+                        break;
+                    }
+                    hull.pop();
+                }
+                hull.push(p);
+            }
+            hull.pop();
+            if pass == 0 {
+                sorted.reverse();
+            }
+        }
+        Self::new(hull)
+    }
+
+    /// 以 Ramer–Douglas–Peucker 算法简化多边形，简化后的边与原有顶点的距离不超过 `tolerance`
+    pub fn simplify(&self, tolerance: T) -> Self {
+        let n = self.points.len();
+        if n <= 3 {
+            return self.clone();
+        }
+        // 以首顶点与距其最远的顶点将多边形分为两条折线
+        let first = self.points[0];
+        let far = (1..n)
+            .max_by(|&i, &j| {
+                let (di, dj) = (
+                    first.distance_squared(self.points[i]),
+                    first.distance_squared(self.points[j]),
+                );
+                di.partial_cmp(&dj).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or(1);
+        let mut keep = vec![false; n + 1];
+        keep[0] = true;
+        keep[far] = true;
+        keep[n] = true;
+        let mut closed = self.points.clone();
+        closed.push(first);
+        rdp(&closed, 0, far, tolerance, &mut keep);
+        rdp(&closed, far, n, tolerance, &mut keep);
+        let points = (0..n).filter(|&i| keep[i]).map(|i| closed[i]).collect();
+        Self::new(points)
+    }
+
+    /// 将多边形的边沿法向平移 `distance`，正值向外扩张，负值向内收缩
+    ///
+    /// 相邻边的交点超出 `miter_limit` 倍距离时以斜角连接；
+    /// 收缩距离过大时结果可能自相交
+    pub fn offset(&self, distance: T, miter_limit: T) -> Self {
+        let mut points = self.points.clone();
+        points.dedup();
+        while points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        let n = points.len();
+        if n < 3 || distance == T::ZERO {
+            return Self::new(points);
+        }
+        // 顺时针多边形的内部位于边方向的右侧，外法向为其左侧
+        let sign = if self.signed_area() < T::ZERO {
+            -T::ONE
+        } else {
+            T::ONE
+        };
+        let normal = |a: Point<T>, b: Point<T>| {
+            let n = -(b - a).perpendicular() * sign;
+            n.normalize().unwrap_or_default()
+        };
+        let mut result = Vec::with_capacity(n * 2);
+        for i in 0..n {
+            let (prev, p, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            let (n0, n1) = (normal(prev, p), normal(p, next));
+            let k = T::ONE + n0.dot(n1);
+            let miter = (n0 + n1) / k;
+            if k > T::EPSILON && miter.length() <= miter_limit {
+                result.push(p + miter * distance);
+            } else {
+                result.push(p + n0 * distance);
+                result.push(p + n1 * distance);
+            }
+        }
+        Self::new(result)
+    }
+
+    /// 计算两多边形的布尔运算，结果为若干闭合轮廓
+    ///
+    /// 输入以非零环绕规则判定内部；结果中外轮廓按顺时针排列，
+    /// 孔洞按逆时针排列，以非零或奇偶规则填充均可得到相同的区域
+    pub fn boolean(&self, other: &Self, op: BooleanOp) -> Vec<Self> {
+        let mut edges: Vec<(Point<T>, Point<T>)> = self
+            .edges()
+            .chain(other.edges())
+            .filter(|(a, b)| a != b)
+            .collect();
+        let splits = split_points(&edges);
+
+        let key = |p: Point<T>| (p.x.to_f64().to_bits(), p.y.to_f64().to_bits());
+        let mut kept = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        for (i, (a, b)) in edges.drain(..).enumerate() {
+            let mut points = splits[i].clone();
+            points.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+            let chain = std::iter::once(a)
+                .chain(points.into_iter().map(|(_, p)| p))
+                .chain(std::iter::once(b))
+                .collect::<Vec<_>>();
+            for pair in chain.windows(2) {
+                let (p, q) = (pair[0], pair[1]);
+                if p == q {
+                    continue;
+                }
+                // 在子边两侧各取一点判断结果区域的内外，偏移量不受子边长度影响
+                let mid = p.lerp(q, T::from_f64(0.5));
+                let Some(normal) = (q - p).perpendicular().normalize() else {
+                    continue;
+                };
+                let side = normal * tolerance(mid);
+                let inside = |p: Point<T>| op.apply(self.contains(p), other.contains(p));
+                let (left, right) = (inside(mid + side), inside(mid - side));
+                let edge = match (left, right) {
+                    (true, false) => (p, q),
+                    (false, true) => (q, p),
+                    _ => continue,
+                };
+                if seen.insert((key(edge.0), key(edge.1))) {
+                    kept.push(edge);
+                }
+            }
+        }
+
+        // 按端点将子边连接为闭合轮廓
+        let mut outgoing: HashMap<_, Vec<usize>> = HashMap::new();
+        for (i, &(p, _)) in kept.iter().enumerate() {
+            outgoing.entry(key(p)).or_default().push(i);
+        }
+        let mut used = vec![false; kept.len()];
+        let mut result = Vec::new();
+        for start in 0..kept.len() {
+            if used[start] {
+                continue;
+            }
+            let mut contour = Vec::new();
+            let mut current = start;
+            loop {
+                used[current] = true;
+                let (p, q) = kept[current];
+                contour.push(p);
+                if key(q) == key(kept[start].0) {
+                    break;
+                }
+                // 有多条出边时选取向内部偏转最多的一条，使仅在顶点处接触的区域保持分离
+                let next = outgoing.get(&key(q)).and_then(|list| {
+                    list.iter().copied().filter(|&i| !used[i]).max_by(|&i, &j| {
+                        let turn = |k: usize| (q - p).angle_to(kept[k].1 - kept[k].0);
+                        turn(i)
+                            .partial_cmp(&turn(j))
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                });
+                // 数值误差导致无法回到起点时，以已连接的部分作为闭合轮廓，而不是将其丢弃
+                match next {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+            let contour = remove_collinear(contour);
+            if contour.len() >= 3 {
+                result.push(Self::new(contour));
+            }
+        }
+        result
+    }
+
+    /// 返回两多边形的并集，参见 [`Polygon::boolean()`]
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        self.boolean(other, BooleanOp::Union)
+    }
+
+    /// 返回两多边形的交集，参见 [`Polygon::boolean()`]
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        self.boolean(other, BooleanOp::Intersection)
+    }
+
+    /// 返回两多边形的差集，参见 [`Polygon::boolean()`]
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        self.boolean(other, BooleanOp::Difference)
+    }
+
+    /// 返回两多边形的对称差，参见 [`Polygon::boolean()`]
+    pub fn xor(&self, other: &Self) -> Vec<Self> {
+        self.boolean(other, BooleanOp::Xor)
+    }
+}
+
+/// 标记折线 `points[from..=to]` 中需要保留的顶点
+fn rdp<T: Float>(points: &[Point<T>], from: usize, to: usize, tolerance: T, keep: &mut [bool]) {
+    if to <= from + 1 {
+        return;
+    }
+    let (a, b) = (points[from], points[to]);
+    let mut best = (from, T::ZERO);
+    for (i, &p) in points.iter().enumerate().take(to).skip(from + 1) {
+        let d = segment_distance(p, a, b);
+        if d > best.1 {
+            best = (i, d);
+        }
+    }
+    if best.1 > tolerance {
+        keep[best.0] = true;
+        rdp(points, from, best.0, tolerance, keep);
+        rdp(points, best.0, to, tolerance, keep);
+    }
+}
+
+/// 返回点至线段的距离
+fn segment_distance<T: Float>(p: Point<T>, a: Point<T>, b: Point<T>) -> T {
+    let ab = b - a;
+    let len = ab.length_squared();
+    if len == T::ZERO {
+        return p.distance(a);
+    }
+    let t = ((p - a).dot(ab) / len).max(T::ZERO).min(T::ONE);
+    p.distance(a + ab * t)
+}
+
+/// 返回每条边上与其它边的交点及其在边上的参数
+///
+/// 交点恰好位于顶点时取该顶点本身，使相交的两条边得到完全相同的分割点
+fn split_points<T: Float>(edges: &[(Point<T>, Point<T>)]) -> Vec<Vec<(T, Point<T>)>> {
+    let eps = T::EPSILON.sqrt();
+    let mut splits = vec![Vec::new(); edges.len()];
+    let param =
+        |(a, b): (Point<T>, Point<T>), p: Point<T>| (p - a).dot(b - a) / (b - a).length_squared();
+    // 以距离而非参数判断交点是否位于边的内部，使短边上的交点不受长边参数精度的影响
+    let interior = |(a, b): (Point<T>, Point<T>), t: T| {
+        let (length, tol) = ((b - a).length(), tolerance(a).max(tolerance(b)));
+        t * length > tol && (T::ONE - t) * length > tol
+    };
+    for i in 0..edges.len() {
+        for j in i + 1..edges.len() {
+            let ((p, p2), (q, q2)) = (edges[i], edges[j]);
+            let (r, s) = (p2 - p, q2 - q);
+            let denom = r.cross(s);
+            if denom.abs() <= eps * r.length() * s.length() {
+                // 平行的边仅在共线时于彼此的端点处分割
+                let tol = tolerance(p).max(tolerance(q));
+                if (q - p).cross(r).abs() > tol * r.length() {
+                    continue;
+                }
+                for (k, other) in [(i, edges[j]), (j, edges[i])] {
+                    for end in [other.0, other.1] {
+                        let t = param(edges[k], end);
+                        if interior(edges[k], t) {
+                            splits[k].push((t, end));
+                        }
+                    }
+                }
+                continue;
+            }
+            let t = (q - p).cross(s) / denom;
+            let u = (q - p).cross(r) / denom;
+            if t < -eps || t > T::ONE + eps || u < -eps || u > T::ONE + eps {
+                continue;
+            }
+            let (t_interior, u_interior) = (interior(edges[i], t), interior(edges[j], u));
+            let point = if !u_interior {
+                if u < T::from_f64(0.5) { q } else { q2 }
+            } else if !t_interior {
+                if t < T::from_f64(0.5) { p } else { p2 }
+            } else {
+                p + r * t
+            };
+            if t_interior {
+                splits[i].push((t, point));
+            }
+            if u_interior {
+                splits[j].push((u, point));
+            }
+        }
+    }
+    splits
+}
+
+/// 返回点附近的距离容差，与坐标的量级成正比，使偏移该距离后的点在浮点精度下总与原点不同
+fn tolerance<T: Float>(p: Point<T>) -> T {
+    let magnitude = p.x.abs().max(p.y.abs()).max(T::ONE);
+    magnitude * T::EPSILON * T::from_f64(TOLERANCE_ULPS)
+}
+
+/// 删除轮廓中重复与共线的顶点
+fn remove_collinear<T: Float>(mut points: Vec<Point<T>>) -> Vec<Point<T>> {
+    let mut changed = true;
+    while changed && points.len() >= 3 {
+        changed = false;
+        let n = points.len();
+        for i in 0..n {
+            let (a, b, c) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            let (ab, bc) = (b - a, c - b);
+            if b == a
+                || ab.cross(bc).abs() <= T::EPSILON * ab.length() * bc.length()
+                    && ab.dot(bc) >= T::ZERO
+            {
+                points.remove(i);
+                changed = true;
+                break;
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        Polygon::new(vec![
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x, y + size),
+        ])
+    }
+
+    fn total_area(polygons: &[Polygon<f64>]) -> f64 {
+        polygons.iter().map(Polygon::signed_area).sum()
+    }
+
+    #[test]
+    fn measure_test() {
+        let mut s = square(1.0, 1.0, 2.0);
+        assert_eq!(s.signed_area(), 4.0);
+        assert_eq!(s.perimeter(), 8.0);
+        assert_eq!(s.orientation(), Some(Orientation::Clockwise));
+        assert_eq!(s.centroid(), Some(Point::new(2.0, 2.0)));
+        let b = s.bounding_box().unwrap();
+        assert_eq!(
+            (b.left(), b.top(), b.right(), b.bottom()),
+            (1.0, 1.0, 3.0, 3.0)
+        );
+        s.reverse();
+        assert_eq!(s.signed_area(), -4.0);
+        assert_eq!(s.orientation(), Some(Orientation::CounterClockwise));
+        assert!(s.is_convex());
+        assert_eq!(Polygon::<f32>::default().centroid(), None);
+    }
+
+    #[test]
+    fn contains_test() {
+        let s = square(0.0, 0.0, 2.0);
+        assert_eq!(s.winding_number(Point::new(1.0, 1.0)), 1);
+        assert!(!s.contains(Point::new(3.0, 1.0)));
+        // 五角星的中心被环绕两次
+        let star = Polygon::new(
+            (0..5)
+                .map(|i| Point::new(0.0, -1.0).rotate(i as f64 * 4.0 * std::f64::consts::PI / 5.0))
+                .collect(),
+        );
+        assert!(!star.is_convex());
+        assert_eq!(star.winding_number(Point::new(0.0, 0.0)).abs(), 2);
+        assert!(star.contains(Point::new(0.0, 0.0)));
+        assert!(!star.contains_even_odd(Point::new(0.0, 0.0)));
+        assert!(star.contains_even_odd(Point::new(0.0, -0.7)));
+    }
+
+    #[test]
+    fn hull_test() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 2.0),
+        ];
+        let hull = Polygon::convex_hull(&points);
+        assert_eq!(hull.points().len(), 4);
+        assert_eq!(hull.signed_area(), 4.0);
+        assert!(hull.is_convex());
+    }
+
+    #[test]
+    fn simplify_test() {
+        let noisy = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.01),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(2.01, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        let simple = noisy.simplify(0.1);
+        assert_eq!(simple.points().len(), 4);
+        assert_eq!(noisy.simplify(0.001), noisy);
+    }
+
+    #[test]
+    fn offset_test() {
+        let s = square(0.0, 0.0, 2.0);
+        assert_eq!(s.offset(1.0, 2.0).signed_area(), 16.0);
+        assert_eq!(s.offset(-0.5, 2.0).signed_area(), 1.0);
+        let mut reversed = s.clone();
+        reversed.reverse();
+        assert_eq!(reversed.offset(1.0, 2.0).signed_area(), -16.0);
+        // 尖角限制较小时以斜角连接，四个角各切去面积 0.5
+        let bevel = s.offset(1.0, 1.0);
+        assert_eq!(bevel.points().len(), 8);
+        assert_eq!(bevel.signed_area(), 14.0);
+    }
+
+    #[test]
+    fn boolean_test() {
+        let a = square(0.0, 0.0, 2.0);
+        let b = square(1.0, 1.0, 2.0);
+        assert_eq!(total_area(&a.union(&b)), 7.0);
+        assert_eq!(a.union(&b)[0].points().len(), 8);
+        assert_eq!(total_area(&a.intersection(&b)), 1.0);
+        assert_eq!(total_area(&a.difference(&b)), 3.0);
+        assert_eq!(total_area(&a.xor(&b)), 6.0);
+        assert_eq!(a.xor(&b).len(), 2);
+
+        // 不相交与共享边的情况
+        let far = square(5.0, 5.0, 1.0);
+        assert_eq!(a.union(&far).len(), 2);
+        assert!(a.intersection(&far).is_empty());
+        let side = square(2.0, 0.0, 2.0);
+        let merged = a.union(&side);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].points().len(), 4);
+        assert_eq!(total_area(&merged), 8.0);
+        assert_eq!(total_area(&a.union(&a)), 4.0);
+        assert!(a.difference(&a).is_empty());
+
+        // 差集产生逆时针的孔洞
+        let big = square(0.0, 0.0, 4.0);
+        let hole = big.difference(&square(1.0, 1.0, 2.0));
+        assert_eq!(hole.len(), 2);
+        let mut areas: Vec<f64> = hole.iter().map(Polygon::signed_area).collect();
+        areas.sort_by(f64::total_cmp);
+        assert_eq!(areas, [-4.0, 16.0]);
+    }
+
+    #[test]
+    fn boolean_diagonal_test() {
+        let triangle = Polygon::new(vec![
+            Point::new(0.0f32, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let square = Polygon::new(vec![
+            Point::new(1.0f32, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 3.0),
+            Point::new(1.0, 3.0),
+        ]);
+        let area = |p: Vec<Polygon<f32>>| p.iter().map(Polygon::signed_area).sum::<f32>();
+        assert!((area(triangle.intersection(&square)) - 2.0).abs() < 1e-4);
+        assert!((area(triangle.union(&square)) - 10.0).abs() < 1e-4);
+        assert!((area(square.difference(&triangle)) - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn boolean_thin_overlap_test() {
+        // f32 下极窄的重叠部分产生很短的子边，不能因此丢失轮廓
+        let sq = |x: f32, y: f32, s: f32| {
+            Polygon::new(vec![
+                Point::new(x, y),
+                Point::new(x + s, y),
+                Point::new(x + s, y + s),
+                Point::new(x, y + s),
+            ])
+        };
+        let area = |p: Vec<Polygon<f32>>| p.iter().map(Polygon::area).sum::<f32>();
+        let union = sq(0.0, 0.0, 100.0).union(&sq(99.99, 10.0, 50.0));
+        assert_eq!(union.len(), 1);
+        assert!((area(union) - 12499.5).abs() < 0.5);
+        let union = sq(1000.0, 0.0, 100.0).union(&sq(1099.99, 10.0, 50.0));
+        assert_eq!(union.len(), 1);
+        assert!((area(union) - 12499.5).abs() < 0.5);
+        let overlap = sq(1000.0, 0.0, 100.0).intersection(&sq(1099.99, 10.0, 50.0));
+        assert!((area(overlap) - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn boolean_orientation_test() {
+        // 输入的环绕方向不影响结果
+        let a = square(0.0, 0.0, 2.0);
+        let mut b = square(1.0, 0.0, 2.0);
+        b.reverse();
+        assert_eq!(total_area(&a.union(&b)), 6.0);
+        assert_eq!(total_area(&a.intersection(&b)), 2.0);
+    }
+}
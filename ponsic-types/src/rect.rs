@@ -1,6 +1,85 @@
-use super::{Point, Size};
+use super::{Float, Point, Scalar, Size};
 use std::ops::{Add, AddAssign, Div, Sub};
 
+#[inline]
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+#[inline]
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+
+#[inline]
+fn clamp<T: PartialOrd>(value: T, lo: T, hi: T) -> T {
+    min(max(value, lo), hi)
+}
+
+/// 若 `lo > hi`，将两者合并为它们的中点
+#[inline]
+fn collapse<T: Scalar>(lo: T, hi: T) -> (T, T) {
+    if lo > hi {
+        // 分别减半以避免 `lo - hi` 溢出，再补上两者余数之和的一半
+        let two = T::ONE + T::ONE;
+        let rem = (lo - lo / two * two) + (hi - hi / two * two);
+        let mid = lo / two + hi / two + rem / two;
+        (mid, mid)
+    } else {
+        (lo, hi)
+    }
+}
+
+/// 将区间 `[lo, hi]` 平移至 `[min, max]` 内，区间更长时取 `[min, max]`
+///
+/// 整数的长度可能超出其类型的范围，因此长度以 `f64` 计算
+#[inline]
+fn fit<T: Scalar>(lo: T, hi: T, min: T, max: T) -> (T, T) {
+    let len = hi.to_f64() - lo.to_f64();
+    if len >= max.to_f64() - min.to_f64() {
+        (min, max)
+    } else if lo < min {
+        (min, self::min(T::from_f64(min.to_f64() + len), max))
+    } else if hi > max {
+        (self::max(T::from_f64(max.to_f64() - len), min), max)
+    } else {
+        (lo, hi)
+    }
+}
+
+/// 尺寸在矩形内对齐时的九个锚点位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// 返回水平与竖直方向上的位置，0 为左（上），1 为居中，2 为右（下）
+    #[inline]
+    fn positions(self) -> (u8, u8) {
+        match self {
+            Self::TopLeft => (0, 0),
+            Self::Top => (1, 0),
+            Self::TopRight => (2, 0),
+            Self::Left => (0, 1),
+            Self::Center => (1, 1),
+            Self::Right => (2, 1),
+            Self::BottomLeft => (0, 2),
+            Self::Bottom => (1, 2),
+            Self::BottomRight => (2, 2),
+        }
+    }
+}
+
 /// 矩形结构体
 ///
 /// # Note
@@ -14,7 +93,7 @@ pub struct Rect<T: Copy> {
     bottom: T,
 }
 
-impl<T: Copy + Ord> Rect<T> {
+impl<T: Copy + PartialOrd> Rect<T> {
     /// 创建一个新的矩形
     ///
     /// # Example
@@ -41,7 +120,7 @@ impl<T: Copy> Rect<T> {
     ///
     /// # Example
     /// ```
-    /// use wy_core::Rect;
+    /// use ponsic_types::Rect;
     /// let rect = Rect::new(1, 2, 3, 4);
     /// ```
     #[cfg(feature = "perf")]
@@ -195,7 +274,7 @@ impl<T: Copy> Rect<T> {
 
 /// 若启用`perf`feature，以下方法将不会进行标准化检查，以提高性能
 #[cfg(not(feature = "perf"))]
-impl<T: Copy + Ord> Rect<T> {
+impl<T: Copy + PartialOrd> Rect<T> {
     /// 设置矩形的左边界坐标
     #[inline]
     pub fn set_left(&mut self, left: T) {
@@ -289,7 +368,7 @@ impl<T: Copy + Sub<Output = T>> Rect<T> {
     }
 }
 
-impl<T: Copy + Ord> Rect<T> {
+impl<T: Copy + PartialOrd> Rect<T> {
     /// 判断矩形是否标准
     ///
     /// # Note
@@ -381,10 +460,10 @@ impl<T: Copy + Ord> Rect<T> {
     pub fn intersected(&self, other: &Self) -> Option<Self> {
         if self.intersects(other) {
             Some(Self {
-                left: self.left.max(other.left),
-                top: self.top.max(other.top),
-                right: self.right.min(other.right),
-                bottom: self.bottom.min(other.bottom),
+                left: max(self.left, other.left),
+                top: max(self.top, other.top),
+                right: min(self.right, other.right),
+                bottom: min(self.bottom, other.bottom),
             })
         } else {
             None
@@ -397,10 +476,10 @@ impl<T: Copy + Ord> Rect<T> {
     #[inline]
     pub fn united(&self, other: &Rect<T>) -> Rect<T> {
         Self {
-            left: self.left.min(other.left),
-            top: self.top.min(other.top),
-            right: self.right.max(other.right),
-            bottom: self.bottom.max(other.bottom),
+            left: min(self.left, other.left),
+            top: min(self.top, other.top),
+            right: max(self.right, other.right),
+            bottom: max(self.bottom, other.bottom),
         }
     }
 }
@@ -418,7 +497,7 @@ impl<T: Copy + AddAssign> Rect<T> {
 }
 
 #[cfg(not(feature = "perf"))]
-impl<T: Copy + AddAssign + Ord> Rect<T> {
+impl<T: Copy + AddAssign + PartialOrd> Rect<T> {
     /// 调整矩形的位置
     #[inline]
     pub fn adjust(&mut self, left: T, top: T, right: T, bottom: T) {
@@ -438,6 +517,328 @@ impl<T: Copy + Add<Output = T> + Div<i32, Output = T>> Rect<T> {
     }
 }
 
+impl<T: Scalar> Rect<T> {
+    /// 各边分别向内收缩指定的距离
+    ///
+    /// 收缩后宽度或高度为负时，该方向收缩为剩余范围中点处的零宽度或零高度；
+    /// 整数坐标超出范围时取其边界值
+    ///
+    /// # Example
+    /// ```
+    /// use ponsic_types::Rect;
+    /// let rect = Rect::new(0, 0, 10, 10).inset(1, 2, 3, 4);
+    /// assert_eq!(rect, Rect::new(1, 2, 7, 6));
+    /// ```
+    #[inline]
+    pub fn inset(&self, left: T, top: T, right: T, bottom: T) -> Self {
+        let (l, r) = collapse(
+            self.left.saturating_add(left),
+            self.right.saturating_sub(right),
+        );
+        let (t, b) = collapse(
+            self.top.saturating_add(top),
+            self.bottom.saturating_sub(bottom),
+        );
+        Self {
+            left: l,
+            top: t,
+            right: r,
+            bottom: b,
+        }
+    }
+
+    /// 各边分别向外扩展指定的距离
+    ///
+    /// 距离为负时向内收缩，规则同 \[`inset()`\]
+    #[inline]
+    pub fn outset(&self, left: T, top: T, right: T, bottom: T) -> Self {
+        let (l, r) = collapse(
+            self.left.saturating_sub(left),
+            self.right.saturating_add(right),
+        );
+        let (t, b) = collapse(
+            self.top.saturating_sub(top),
+            self.bottom.saturating_add(bottom),
+        );
+        Self {
+            left: l,
+            top: t,
+            right: r,
+            bottom: b,
+        }
+    }
+
+    /// 返回将 `size` 按锚点对齐放置在矩形内所得的矩形
+    ///
+    /// 居中时整数坐标向零取整；`size` 大于矩形时结果超出矩形，整数坐标超出范围时取其边界值。
+    /// `size` 的类型可与矩形的坐标类型不同，如 \[`Sizei`\] 与 \[`Recti`\]
+    ///
+    /// # Example
+    /// ```
+    /// use ponsic_types::{Anchor, Rect, Recti, Size, Sizei};
+    /// let rect = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(rect.align(Size::new(4, 2), Anchor::Center), Rect::new(3, 4, 7, 6));
+    /// assert_eq!(rect.align(Size::new(4, 2), Anchor::BottomRight), Rect::new(6, 8, 10, 10));
+    /// let rect: Recti = rect;
+    /// assert_eq!(rect.align(Sizei::new(12, 2), Anchor::Top), Rect::new(-1, 0, 11, 2));
+    /// ```
+    pub fn align<U: Scalar>(&self, size: Size<U>, anchor: Anchor) -> Self {
+        let (width, height) = (
+            T::from_f64(size.width.to_f64()),
+            T::from_f64(size.height.to_f64()),
+        );
+        // 尺寸超出矩形时向起点一侧偏移，避免无符号类型的减法溢出
+        let place = |start: T, length: T, size: T, position: u8| {
+            let offset = |free: T| match position {
+                0 => T::ZERO,
+                1 => free / (T::ONE + T::ONE),
+                _ => free,
+            };
+            if size <= length {
+                start + offset(length - size)
+            } else {
+                start.saturating_sub(offset(size - length))
+            }
+        };
+        let (h, v) = anchor.positions();
+        let left = place(self.left, self.width(), width, h);
+        let top = place(self.top, self.height(), height, v);
+        Self {
+            left,
+            top,
+            right: left.saturating_add(width),
+            bottom: top.saturating_add(height),
+        }
+    }
+
+    /// 在距左边界 `offset` 处将矩形分为左右两部分
+    ///
+    /// `offset` 被限制在 `[0, width]` 内
+    #[inline]
+    pub fn split_at_x(&self, offset: T) -> (Self, Self) {
+        let x = self.left + clamp(offset, T::ZERO, self.width());
+        (Self { right: x, ..*self }, Self { left: x, ..*self })
+    }
+
+    /// 在距上边界 `offset` 处将矩形分为上下两部分
+    ///
+    /// `offset` 被限制在 `[0, height]` 内
+    #[inline]
+    pub fn split_at_y(&self, offset: T) -> (Self, Self) {
+        let y = self.top + clamp(offset, T::ZERO, self.height());
+        (Self { bottom: y, ..*self }, Self { top: y, ..*self })
+    }
+
+    /// 将矩形分为左右两部分，左侧部分的宽度为 `width * ratio`
+    ///
+    /// 整数坐标四舍五入，`ratio` 被限制在 `[0, 1]` 内
+    ///
+    /// # Example
+    /// ```
+    /// use ponsic_types::Rect;
+    /// let (left, right) = Rect::new(0, 0, 10, 4).split_x_ratio(0.25);
+    /// assert_eq!((left, right), (Rect::new(0, 0, 3, 4), Rect::new(3, 0, 10, 4)));
+    /// ```
+    #[inline]
+    pub fn split_x_ratio(&self, ratio: f64) -> (Self, Self) {
+        self.split_at_x(T::from_f64(self.width().to_f64() * ratio.clamp(0.0, 1.0)))
+    }
+
+    /// 将矩形分为上下两部分，上侧部分的高度为 `height * ratio`
+    ///
+    /// 整数坐标四舍五入，`ratio` 被限制在 `[0, 1]` 内
+    #[inline]
+    pub fn split_y_ratio(&self, ratio: f64) -> (Self, Self) {
+        self.split_at_y(T::from_f64(self.height().to_f64() * ratio.clamp(0.0, 1.0)))
+    }
+
+    /// 将矩形分为 `rows` 行 `cols` 列的网格，按行依次返回各单元格
+    ///
+    /// 相邻单元格共享边界，整数坐标四舍五入，因此单元格的尺寸最多相差 1；
+    /// `rows` 或 `cols` 为 0 时返回空列表
+    ///
+    /// # Example
+    /// ```
+    /// use ponsic_types::Rect;
+    /// let cells = Rect::new(0, 0, 10, 4).grid(2, 3);
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[1], Rect::new(3, 0, 7, 2));
+    /// assert_eq!(cells[5], Rect::new(7, 2, 10, 4));
+    /// ```
+    pub fn grid(&self, rows: usize, cols: usize) -> Vec<Self> {
+        let lines = |start: T, end: T, count: usize| {
+            let length = (end - start).to_f64();
+            (0..=count)
+                .map(|i| match i {
+                    0 => start,
+                    i if i == count => end,
+                    i => start + T::from_f64(length * i as f64 / count as f64),
+                })
+                .collect::<Vec<_>>()
+        };
+        if rows == 0 || cols == 0 {
+            return Vec::new();
+        }
+        let xs = lines(self.left, self.right, cols);
+        let ys = lines(self.top, self.bottom, rows);
+        ys.windows(2)
+            .flat_map(|y| {
+                xs.windows(2).map(move |x| Self {
+                    left: x[0],
+                    top: y[0],
+                    right: x[1],
+                    bottom: y[1],
+                })
+            })
+            .collect()
+    }
+
+    /// 以 `origin` 为中心缩放矩形
+    ///
+    /// 整数坐标四舍五入；缩放因子为负时矩形被翻转，结果仍是标准化的
+    ///
+    /// # Example
+    /// ```
+    /// use ponsic_types::{Point, Rect};
+    /// let rect = Rect::new(2, 2, 4, 6).scale_about(Point::new(2, 2), 2.0, 0.5);
+    /// assert_eq!(rect, Rect::new(2, 2, 6, 4));
+    /// ```
+    pub fn scale_about(&self, origin: Point<T>, sx: f64, sy: f64) -> Self {
+        let scale = |value: T, origin: T, factor: f64| {
+            let origin = origin.to_f64();
+            T::from_f64(origin + (value.to_f64() - origin) * factor)
+        };
+        let (l, r) = (
+            scale(self.left, origin.x, sx),
+            scale(self.right, origin.x, sx),
+        );
+        let (t, b) = (
+            scale(self.top, origin.y, sy),
+            scale(self.bottom, origin.y, sy),
+        );
+        Self {
+            left: min(l, r),
+            top: min(t, b),
+            right: max(l, r),
+            bottom: max(t, b),
+        }
+    }
+
+    /// 返回矩形内（含边界）与 `point` 最接近的点
+    #[inline]
+    pub fn clamp_point(&self, point: Point<T>) -> Point<T> {
+        Point::new(
+            clamp(point.x, self.left, self.right),
+            clamp(point.y, self.top, self.bottom),
+        )
+    }
+
+    /// 平移 `other` 使其位于矩形内，返回平移后的矩形
+    ///
+    /// 若 `other` 在某一方向上比矩形大，该方向上取矩形的范围
+    ///
+    /// # Example
+    /// ```
+    /// use ponsic_types::Rect;
+    /// let screen = Rect::new(0, 0, 100, 50);
+    /// assert_eq!(screen.clamp_rect(&Rect::new(90, -5, 110, 5)), Rect::new(80, 0, 100, 10));
+    /// assert_eq!(screen.clamp_rect(&Rect::new(10, -10, 20, 80)), Rect::new(10, 0, 20, 50));
+    /// ```
+    #[inline]
+    pub fn clamp_rect(&self, other: &Self) -> Self {
+        let (left, right) = fit(other.left, other.right, self.left, self.right);
+        let (top, bottom) = fit(other.top, other.bottom, self.top, self.bottom);
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+}
+
+impl<T: Float> Rect<T> {
+    /// 返回各边四舍五入后的矩形
+    #[inline]
+    pub fn round(&self) -> Self {
+        self.map(T::round, T::round)
+    }
+
+    /// 返回包含此矩形的最小整数坐标矩形
+    #[inline]
+    pub fn round_out(&self) -> Self {
+        self.map(T::floor, T::ceil)
+    }
+
+    /// 返回包含于此矩形内的最大整数坐标矩形
+    ///
+    /// 某一方向上不存在这样的整数范围时，该方向收缩为零宽度或零高度
+    #[inline]
+    pub fn round_in(&self) -> Self {
+        let rect = self.map(T::ceil, T::floor);
+        Self {
+            right: max(rect.left, rect.right),
+            bottom: max(rect.top, rect.bottom),
+            ..rect
+        }
+    }
+
+    #[inline]
+    fn map(&self, lo: impl Fn(T) -> T, hi: impl Fn(T) -> T) -> Self {
+        Self {
+            left: lo(self.left),
+            top: lo(self.top),
+            right: hi(self.right),
+            bottom: hi(self.bottom),
+        }
+    }
+}
+
+impl Rect<i32> {
+    /// 转换为 `f32` 坐标的矩形
+    #[inline]
+    pub fn to_f32(&self) -> Rect<f32> {
+        Rect {
+            left: self.left as f32,
+            top: self.top as f32,
+            right: self.right as f32,
+            bottom: self.bottom as f32,
+        }
+    }
+
+    /// 转换为 `f64` 坐标的矩形
+    #[inline]
+    pub fn to_f64(&self) -> Rect<f64> {
+        Rect {
+            left: self.left as f64,
+            top: self.top as f64,
+            right: self.right as f64,
+            bottom: self.bottom as f64,
+        }
+    }
+}
+
+macro_rules! impl_to_i32 {
+    ($($t:ident),*) => {$(
+        impl Rect<$t> {
+            /// 向零取整转换为 `i32` 坐标的矩形，超出范围的坐标取 `i32` 的边界值，NaN 取 0
+            ///
+            /// 如需其它取整方式，可先调用 [`Rect::round()`]、[`Rect::round_out()`] 或 [`Rect::round_in()`]
+            #[inline]
+            pub fn to_i32(&self) -> Rect<i32> {
+                Rect {
+                    left: self.left as i32,
+                    top: self.top as i32,
+                    right: self.right as i32,
+                    bottom: self.bottom as i32,
+                }
+            }
+        }
+    )*};
+}
+
+impl_to_i32!(f32, f64);
+
 impl<T: Copy + Add<Output = T>> From<(Point<T>, Size<T>)> for Rect<T> {
     #[inline]
     fn from((pos, size): (Point<T>, Size<T>)) -> Self {
@@ -454,7 +855,7 @@ mod operator_overload {
     use super::*;
 
     use std::ops::BitAnd;
-    impl<T: Copy + Ord> BitAnd for Rect<T> {
+    impl<T: Copy + PartialOrd> BitAnd for Rect<T> {
         type Output = Option<Self>;
 
         fn bitand(self, rhs: Self) -> Self::Output {
@@ -463,7 +864,7 @@ mod operator_overload {
     }
 
     use std::ops::BitOr;
-    impl<T: Copy + Ord> BitOr for Rect<T> {
+    impl<T: Copy + PartialOrd> BitOr for Rect<T> {
         type Output = Self;
 
         fn bitor(self, rhs: Self) -> Self::Output {
@@ -475,6 +876,7 @@ mod operator_overload {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Recti, Sizei};
 
     #[test]
     #[cfg(feature = "perf")]
@@ -544,4 +946,153 @@ mod tests {
         let rect = Rect::new(1, 2, 3, 4);
         assert_eq!(rect.center(), Point::new(2, 3));
     }
+
+    #[test]
+    fn inset_test() {
+        let rect = Rect::new(0, 0, 10, 10);
+        assert_eq!(rect.inset(1, 2, 3, 4), Rect::new(1, 2, 7, 6));
+        assert_eq!(rect.outset(1, 2, 3, 4), Rect::new(-1, -2, 13, 14));
+        assert_eq!(rect.inset(8, 0, 8, 0), Rect::new(5, 0, 5, 10));
+        assert_eq!(rect.outset(-6, 0, -6, 0), Rect::new(5, 0, 5, 10));
+        // 无符号坐标超出范围时取边界值
+        let unsigned = Rect::<u32>::new(2, 2, 6, 6);
+        assert_eq!(unsigned.inset(0, 0, 10, 1), Rect::new(1, 2, 1, 5));
+        assert_eq!(unsigned.outset(5, 0, 0, 0), Rect::new(0, 2, 6, 6));
+        assert_eq!(
+            Rect::<u8>::new(0, 0, 250, 10).outset(0, 0, 10, 0),
+            Rect::new(0, 0, 255, 10)
+        );
+        // 合并中点时不因坐标差超出范围而溢出
+        assert_eq!(rect.inset(i32::MAX, 0, i32::MAX, 0), Rect::new(5, 0, 5, 10));
+        assert_eq!(
+            rect.outset(i32::MIN, 0, i32::MIN, 0),
+            Rect::new(4, 0, 4, 10)
+        );
+    }
+
+    #[test]
+    fn align_test() {
+        let rect = Rect::new(0.0, 0.0, 10.0, 6.0);
+        let size = Size::new(4.0, 2.0);
+        assert_eq!(
+            rect.align(size, Anchor::TopLeft),
+            Rect::new(0.0, 0.0, 4.0, 2.0)
+        );
+        assert_eq!(rect.align(size, Anchor::Top), Rect::new(3.0, 0.0, 7.0, 2.0));
+        assert_eq!(
+            rect.align(size, Anchor::Right),
+            Rect::new(6.0, 2.0, 10.0, 4.0)
+        );
+        assert_eq!(
+            rect.align(size, Anchor::BottomLeft),
+            Rect::new(0.0, 4.0, 4.0, 6.0)
+        );
+        assert_eq!(
+            Rect::new(0, 0, 5, 5).align(Size::new(2, 2), Anchor::Center),
+            Rect::new(1, 1, 3, 3)
+        );
+        // 尺寸大于矩形
+        assert_eq!(
+            Rect::new(0, 0, 5, 5).align(Size::new(8, 9), Anchor::Center),
+            Rect::new(-1, -2, 7, 7)
+        );
+        let recti: Recti = Rect::new(0, 0, 10, 10);
+        assert_eq!(
+            recti.align(Sizei::new(4, 2), Anchor::BottomRight),
+            Rect::new(6, 8, 10, 10)
+        );
+        let unsigned = Rect::<u32>::new(2, 2, 6, 6);
+        assert_eq!(
+            unsigned.align(Size::new(8u32, 2), Anchor::Center),
+            Rect::new(0, 3, 8, 5)
+        );
+        assert_eq!(
+            unsigned.align(Size::new(8u32, 2), Anchor::Right),
+            Rect::new(0, 3, 8, 5)
+        );
+        assert_eq!(
+            unsigned.align(Size::new(3u32, 2), Anchor::Right),
+            Rect::new(3, 3, 6, 5)
+        );
+    }
+
+    #[test]
+    fn split_test() {
+        let rect = Rect::new(0, 0, 10, 4);
+        assert_eq!(
+            rect.split_at_x(4),
+            (Rect::new(0, 0, 4, 4), Rect::new(4, 0, 10, 4))
+        );
+        assert_eq!(rect.split_at_y(20), (rect, Rect::new(0, 4, 10, 4)));
+        assert_eq!(
+            rect.split_y_ratio(0.5),
+            (Rect::new(0, 0, 10, 2), Rect::new(0, 2, 10, 4))
+        );
+        let (a, b) = Rect::new(1.0, 0.0, 2.0, 1.0).split_x_ratio(0.25);
+        assert_eq!((a.right(), b.left()), (1.25, 1.25));
+    }
+
+    #[test]
+    fn grid_test() {
+        let cells = Rect::new(0, 0, 10, 10).grid(3, 2);
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], Rect::new(0, 0, 5, 3));
+        assert_eq!(cells[3], Rect::new(5, 3, 10, 7));
+        assert_eq!(cells[5], Rect::new(5, 7, 10, 10));
+        assert!(Rect::new(0, 0, 10, 10).grid(0, 2).is_empty());
+    }
+
+    #[test]
+    fn scale_about_test() {
+        let rect = Rect::new(0.0, 0.0, 4.0, 2.0);
+        assert_eq!(
+            rect.scale_about(Point::new(2.0, 1.0), 0.5, 2.0),
+            Rect::new(1.0, -1.0, 3.0, 3.0)
+        );
+        assert_eq!(
+            rect.scale_about(Point::new(0.0, 0.0), -1.0, 1.0),
+            Rect::new(-4.0, 0.0, 0.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn clamp_test() {
+        let rect = Rect::new(0, 0, 10, 10);
+        assert_eq!(rect.clamp_point(Point::new(-3, 12)), Point::new(0, 10));
+        assert_eq!(rect.clamp_point(Point::new(4, 5)), Point::new(4, 5));
+        assert_eq!(
+            rect.clamp_rect(&Rect::new(-2, 8, 3, 13)),
+            Rect::new(0, 5, 5, 10)
+        );
+        assert_eq!(
+            rect.clamp_rect(&Rect::new(-5, 2, 20, 4)),
+            Rect::new(0, 2, 10, 4)
+        );
+        // 长度超出坐标类型范围时不溢出
+        let wide = Rect::new(i32::MIN, 0, i32::MAX, 10);
+        assert_eq!(rect.clamp_rect(&wide), Rect::new(0, 0, 10, 10));
+        assert_eq!(
+            wide.clamp_rect(&Rect::new(-5, 2, 20, 4)),
+            Rect::new(-5, 2, 20, 4)
+        );
+        assert_eq!(
+            Rect::new(i32::MIN + 1, 0, i32::MAX, 10).clamp_rect(&Rect::new(i32::MIN, 0, 0, 10)),
+            Rect::new(i32::MIN + 1, 0, 1, 10)
+        );
+    }
+
+    #[test]
+    fn convert_test() {
+        let rect = Rect::new(-1, 2, 3, 4);
+        assert_eq!(rect.to_f32(), Rect::new(-1.0, 2.0, 3.0, 4.0));
+        assert_eq!(rect.to_f64().to_i32(), rect);
+
+        let rect = Rect::new(0.4f32, -0.6, 2.5, 3.2);
+        assert_eq!(rect.to_i32(), Rect::new(0, 0, 2, 3));
+        assert_eq!(rect.round().to_i32(), Rect::new(0, -1, 3, 3));
+        assert_eq!(rect.round_out().to_i32(), Rect::new(0, -1, 3, 4));
+        assert_eq!(rect.round_in().to_i32(), Rect::new(1, 0, 2, 3));
+        let thin = Rect::new(0.2, 0.0, 0.8, 1.0).round_in();
+        assert_eq!(thin, Rect::new(1.0, 0.0, 1.0, 1.0));
+    }
 }
@@ -0,0 +1,377 @@
+use super::{Point, Rect};
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+/// 区域，由互不重叠的矩形组成的整数点集
+///
+/// 矩形按水平带组织：每条带内的矩形具有相同的上下边界并按从左到右排列，
+/// 相邻且矩形排列相同的带会被合并，因此相同的点集总有相同的表示。
+/// 区域中的矩形 `(left, top, right, bottom)` 覆盖 `[left, right) × [top, bottom)`，
+/// 宽度或高度不为正的矩形视为空
+///
+/// # Examples
+/// ```
+/// use ponsic_types::{Point, Recti, Region};
+///
+/// let a = Region::from(Recti::new(0, 0, 10, 10));
+/// let b = Region::from(Recti::new(5, 5, 15, 15));
+/// let union = &a | &b;
+/// assert_eq!(union.rects().len(), 3);
+/// assert_eq!(union.area(), 175);
+/// assert!(union.contains(Point::new(12, 12)));
+/// assert_eq!((&a - &b).area(), 75);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Region {
+    rects: Vec<Rect<i32>>,
+}
+
+/// 区域布尔运算
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+impl SetOp {
+    #[inline]
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::Union => a || b,
+            Self::Intersection => a && b,
+            Self::Difference => a && !b,
+            Self::Xor => a != b,
+        }
+    }
+}
+
+#[inline]
+fn is_empty_rect(rect: &Rect<i32>) -> bool {
+    rect.left() >= rect.right() || rect.top() >= rect.bottom()
+}
+
+/// 返回 `[top, bottom)` 范围内区域所覆盖的、按左边界排列的水平区间
+fn spans(rects: &[Rect<i32>], top: i32, bottom: i32) -> Vec<(i32, i32)> {
+    let mut spans: Vec<(i32, i32)> = rects
+        .iter()
+        .filter(|r| r.top() <= top && r.bottom() >= bottom)
+        .map(|r| (r.left(), r.right()))
+        .collect();
+    spans.sort_unstable();
+    spans
+}
+
+#[inline]
+fn covered(spans: &[(i32, i32)], x: i32) -> bool {
+    spans.iter().any(|&(l, r)| l <= x && x < r)
+}
+
+/// 对两组水平区间进行布尔运算，返回合并相邻部分后的区间
+fn combine(a: &[(i32, i32)], b: &[(i32, i32)], op: SetOp) -> Vec<(i32, i32)> {
+    let mut xs: Vec<i32> = a.iter().chain(b).flat_map(|&(l, r)| [l, r]).collect();
+    xs.sort_unstable();
+    xs.dedup();
+    let mut result: Vec<(i32, i32)> = Vec::new();
+    for x in xs.windows(2) {
+        if !op.apply(covered(a, x[0]), covered(b, x[0])) {
+            continue;
+        }
+        match result.last_mut() {
+            Some(last) if last.1 == x[0] => last.1 = x[1],
+            _ => result.push((x[0], x[1])),
+        }
+    }
+    result
+}
+
+impl Region {
+    /// 创建空区域
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 由可能重叠的矩形创建区域，结果为这些矩形的并集
+    pub fn from_rects(rects: impl IntoIterator<Item = Rect<i32>>) -> Self {
+        let rects: Vec<Rect<i32>> = rects.into_iter().filter(|r| !is_empty_rect(r)).collect();
+        Self::build(&rects, &[], SetOp::Union)
+    }
+
+    /// 判断区域是否为空
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// 返回组成区域的矩形，按从上到下、从左到右排列
+    #[inline]
+    pub fn rects(&self) -> &[Rect<i32>] {
+        &self.rects
+    }
+
+    /// 返回遍历组成区域的矩形的迭代器
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Rect<i32>> {
+        self.rects.iter()
+    }
+
+    /// 返回区域所覆盖的点的数量
+    pub fn area(&self) -> i64 {
+        self.rects
+            .iter()
+            .map(|r| r.width() as i64 * r.height() as i64)
+            .sum()
+    }
+
+    /// 返回区域的外接矩形，区域为空时返回 `None`
+    pub fn bounding_box(&self) -> Option<Rect<i32>> {
+        let first = self.rects.first()?;
+        let last = self.rects.last()?;
+        let left = self.rects.iter().map(Rect::left).min()?;
+        let right = self.rects.iter().map(Rect::right).max()?;
+        Some(Rect::new(left, first.top(), right, last.bottom()))
+    }
+
+    /// 判断点是否在区域内
+    #[inline]
+    pub fn contains(&self, point: Point<i32>) -> bool {
+        self.rects.iter().any(|r| {
+            r.left() <= point.x && point.x < r.right() && r.top() <= point.y && point.y < r.bottom()
+        })
+    }
+
+    /// 判断矩形是否完全包含于区域内，空矩形总是被包含
+    pub fn contains_rect(&self, rect: &Rect<i32>) -> bool {
+        Region::from(*rect).difference(self).is_empty()
+    }
+
+    /// 判断矩形与区域是否存在公共部分
+    pub fn intersects_rect(&self, rect: &Rect<i32>) -> bool {
+        !is_empty_rect(rect)
+            && self.rects.iter().any(|r| {
+                r.left() < rect.right()
+                    && rect.left() < r.right()
+                    && r.top() < rect.bottom()
+                    && rect.top() < r.bottom()
+            })
+    }
+
+    /// 返回两区域的并集
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::build(&self.rects, &other.rects, SetOp::Union)
+    }
+
+    /// 返回两区域的交集
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::build(&self.rects, &other.rects, SetOp::Intersection)
+    }
+
+    /// 返回从此区域中减去 `other` 后的区域
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::build(&self.rects, &other.rects, SetOp::Difference)
+    }
+
+    /// 返回仅属于两区域之一的部分
+    #[inline]
+    pub fn xor(&self, other: &Self) -> Self {
+        Self::build(&self.rects, &other.rects, SetOp::Xor)
+    }
+
+    /// 平移区域
+    ///
+    /// 坐标超出 `i32` 范围时取其边界值，即平移后超出范围的部分被裁剪
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        let mut clipped = false;
+        let mut shift = |v: i32, d: i32| {
+            v.checked_add(d).unwrap_or_else(|| {
+                clipped = true;
+                v.saturating_add(d)
+            })
+        };
+        for rect in &mut self.rects {
+            *rect = Rect::new(
+                shift(rect.left(), dx),
+                shift(rect.top(), dy),
+                shift(rect.right(), dx),
+                shift(rect.bottom(), dy),
+            );
+        }
+        // 被裁剪的矩形可能变为空，或使相邻的带可以合并，需重新整理
+        if clipped {
+            *self = Self::from_rects(std::mem::take(&mut self.rects));
+        }
+    }
+
+    /// 返回平移后的区域
+    #[inline]
+    pub fn translated(&self, dx: i32, dy: i32) -> Self {
+        let mut region = self.clone();
+        region.translate(dx, dy);
+        region
+    }
+
+    /// 沿水平带扫描两组矩形，逐带进行布尔运算并合并相同的相邻带
+    fn build(a: &[Rect<i32>], b: &[Rect<i32>], op: SetOp) -> Self {
+        let mut ys: Vec<i32> = a
+            .iter()
+            .chain(b)
+            .flat_map(|r| [r.top(), r.bottom()])
+            .collect();
+        ys.sort_unstable();
+        ys.dedup();
+
+        let mut rects: Vec<Rect<i32>> = Vec::new();
+        // 上一条带在 `rects` 中的起始位置及其水平区间，带不连续时区间为空
+        let (mut start, mut previous) = (0, Vec::new());
+        for y in ys.windows(2) {
+            let (top, bottom) = (y[0], y[1]);
+            let band = combine(&spans(a, top, bottom), &spans(b, top, bottom), op);
+            if !band.is_empty() && band == previous {
+                for rect in &mut rects[start..] {
+                    *rect = Rect::new(rect.left(), rect.top(), rect.right(), bottom);
+                }
+                continue;
+            }
+            start = rects.len();
+            rects.extend(band.iter().map(|&(l, r)| Rect::new(l, top, r, bottom)));
+            previous = band;
+        }
+        Self { rects }
+    }
+}
+
+impl From<Rect<i32>> for Region {
+    #[inline]
+    fn from(rect: Rect<i32>) -> Self {
+        if is_empty_rect(&rect) {
+            Self::new()
+        } else {
+            Self { rects: vec![rect] }
+        }
+    }
+}
+
+impl FromIterator<Rect<i32>> for Region {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Rect<i32>>>(iter: I) -> Self {
+        Self::from_rects(iter)
+    }
+}
+
+impl<'a> IntoIterator for &'a Region {
+    type Item = &'a Rect<i32>;
+    type IntoIter = std::slice::Iter<'a, Rect<i32>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl BitOr for &Region {
+    type Output = Region;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for &Region {
+    type Output = Region;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for &Region {
+    type Output = Region;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl BitXor for &Region {
+    type Output = Region;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.xor(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(rects: &[(i32, i32, i32, i32)]) -> Region {
+        rects
+            .iter()
+            .map(|&(l, t, r, b)| Rect::new(l, t, r, b))
+            .collect()
+    }
+
+    #[test]
+    fn union_test() {
+        let a = region(&[(0, 0, 10, 10)]);
+        let b = region(&[(5, 5, 15, 15)]);
+        assert_eq!(
+            a.union(&b),
+            region(&[(0, 0, 10, 5), (0, 5, 15, 10), (5, 10, 15, 15)])
+        );
+        assert_eq!(a.union(&b).rects().len(), 3);
+        // 相邻矩形被合并，表示与构造顺序无关
+        let c = region(&[(0, 5, 10, 10), (0, 0, 5, 5), (5, 0, 10, 5)]);
+        assert_eq!(c, a);
+        assert_eq!(c.rects(), [Rect::new(0, 0, 10, 10)]);
+        assert!(region(&[(0, 0, 0, 10)]).is_empty());
+    }
+
+    #[test]
+    fn set_operation_test() {
+        let a = region(&[(0, 0, 10, 10)]);
+        let b = region(&[(5, 5, 15, 15)]);
+        assert_eq!(&a & &b, region(&[(5, 5, 10, 10)]));
+        let diff = &a - &b;
+        assert_eq!(diff.area(), 75);
+        assert!(diff.contains(Point::new(2, 8)));
+        assert!(!diff.contains(Point::new(7, 7)));
+        let xor = &a ^ &b;
+        assert_eq!(xor.area(), 150);
+        assert_eq!(xor, &(&a | &b) - &(&a & &b));
+        assert!((&a & &region(&[(10, 0, 20, 10)])).is_empty());
+    }
+
+    #[test]
+    fn query_test() {
+        let r = region(&[(0, 0, 4, 4), (6, 0, 10, 4)]);
+        assert_eq!(r.bounding_box(), Some(Rect::new(0, 0, 10, 4)));
+        assert_eq!(Region::new().bounding_box(), None);
+        assert!(r.contains(Point::new(0, 0)));
+        assert!(!r.contains(Point::new(4, 0)));
+        assert!(r.contains_rect(&Rect::new(1, 1, 3, 3)));
+        assert!(!r.contains_rect(&Rect::new(3, 1, 7, 3)));
+        assert!(r.intersects_rect(&Rect::new(3, 3, 7, 7)));
+        assert!(!r.intersects_rect(&Rect::new(4, 0, 6, 4)));
+        assert_eq!(r.iter().count(), 2);
+
+        let moved = r.translated(1, -1);
+        assert_eq!(moved.bounding_box(), Some(Rect::new(1, -1, 11, 3)));
+        assert!(moved.contains(Point::new(10, 2)));
+
+        // 超出坐标范围的部分被裁剪
+        let clipped = r.translated(i32::MAX - 8, 0);
+        assert_eq!(clipped.rects().len(), 2);
+        assert_eq!(
+            clipped.bounding_box(),
+            Some(Rect::new(i32::MAX - 8, 0, i32::MAX, 4))
+        );
+        assert_eq!(clipped.area(), 4 * 4 + 2 * 4);
+        assert!(r.translated(i32::MAX, 0).is_empty());
+        assert!(r.translated(0, -5).translated(0, i32::MIN).is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+/// 数值类型，为整数与浮点数实现
+///
+/// 整数由浮点数转换时四舍五入，超出范围的值取其边界值
+pub trait Scalar:
+    Copy
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    /// 加法，整数结果超出范围时取其边界值
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// 减法，整数结果超出范围时取其边界值
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ident),* ; $round:expr, $add:ident, $sub:ident) => {$(
+        impl Scalar for $t {
+            const ZERO: Self = 0 as $t;
+            const ONE: Self = 1 as $t;
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                $round(value) as $t
+            }
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self {
+                self.$add(rhs)
+            }
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                self.$sub(rhs)
+            }
+        }
+    )*};
+}
+
+impl_scalar!(
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize;
+    f64::round, saturating_add, saturating_sub
+);
+impl_scalar!(f32, f64; std::convert::identity, add, sub);
@@ -0,0 +1,607 @@
+use super::{Point, Rect, Scalar};
+
+/// 节点中子节点或条目的最大数量
+const MAX_ENTRIES: usize = 16;
+/// 分裂节点时每组的最小数量
+const MIN_ENTRIES: usize = 4;
+
+/// [`RTree`] 中条目的标识
+///
+/// 条目被移除后，其标识可能被之后插入的条目重新使用
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemId(usize);
+
+impl ItemId {
+    /// 返回标识的序号
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node<T: Scalar> {
+    Leaf(Vec<(Rect<T>, ItemId)>),
+    Branch(Vec<(Rect<T>, Node<T>)>),
+}
+
+impl<T: Scalar> Node<T> {
+    fn bounds(&self) -> Option<Rect<T>> {
+        match self {
+            Self::Leaf(entries) => bounds(entries),
+            Self::Branch(children) => bounds(children),
+        }
+    }
+
+    /// 插入条目，节点分裂时返回新分出的节点
+    fn insert(&mut self, rect: Rect<T>, id: ItemId) -> Option<(Rect<T>, Node<T>)> {
+        match self {
+            Self::Leaf(entries) => {
+                entries.push((rect, id));
+                (entries.len() > MAX_ENTRIES).then(|| {
+                    let rest = split(entries);
+                    (bounds(&rest).unwrap(), Self::Leaf(rest))
+                })
+            }
+            Self::Branch(children) => {
+                let index = choose_subtree(children, &rect);
+                let child = &mut children[index];
+                child.0 = child.0.united(&rect);
+                if let Some(sibling) = child.1.insert(rect, id) {
+                    child.0 = child.1.bounds().unwrap();
+                    children.push(sibling);
+                }
+                (children.len() > MAX_ENTRIES).then(|| {
+                    let rest = split(children);
+                    (bounds(&rest).unwrap(), Self::Branch(rest))
+                })
+            }
+        }
+    }
+
+    /// 移除条目，返回是否找到该条目；变为空的子节点被一并移除
+    fn remove(&mut self, rect: &Rect<T>, id: ItemId) -> bool {
+        match self {
+            Self::Leaf(entries) => match entries.iter().position(|&(_, e)| e == id) {
+                Some(index) => {
+                    entries.swap_remove(index);
+                    true
+                }
+                None => false,
+            },
+            Self::Branch(children) => {
+                for index in 0..children.len() {
+                    let child = &mut children[index];
+                    if !child.0.intersects(rect) || !child.1.remove(rect, id) {
+                        continue;
+                    }
+                    match child.1.bounds() {
+                        Some(b) => child.0 = b,
+                        None => {
+                            children.swap_remove(index);
+                        }
+                    }
+                    return true;
+                }
+                false
+            }
+        }
+    }
+}
+
+/// 返回所有条目的外接矩形
+fn bounds<T: Scalar, X>(entries: &[(Rect<T>, X)]) -> Option<Rect<T>> {
+    let (first, rest) = entries.split_first()?;
+    Some(rest.iter().fold(first.0, |acc, (r, _)| acc.united(r)))
+}
+
+#[inline]
+fn area<T: Scalar>(rect: &Rect<T>) -> f64 {
+    rect.width().to_f64() * rect.height().to_f64()
+}
+
+#[inline]
+fn margin<T: Scalar>(rect: &Rect<T>) -> f64 {
+    rect.width().to_f64() + rect.height().to_f64()
+}
+
+/// 返回点到矩形距离的平方，点在矩形内时为 0
+fn distance_squared<T: Scalar>(rect: &Rect<T>, point: Point<T>) -> f64 {
+    let axis = |lo: T, hi: T, v: T| {
+        let (lo, hi, v) = (lo.to_f64(), hi.to_f64(), v.to_f64());
+        (lo - v).max(v - hi).max(0.0)
+    };
+    let dx = axis(rect.left(), rect.right(), point.x);
+    let dy = axis(rect.top(), rect.bottom(), point.y);
+    dx * dx + dy * dy
+}
+
+/// 选择加入矩形后面积增量最小的子节点，增量相同时取面积较小者
+fn choose_subtree<T: Scalar>(children: &[(Rect<T>, Node<T>)], rect: &Rect<T>) -> usize {
+    let cost = |r: &Rect<T>| {
+        let a = area(r);
+        (area(&r.united(rect)) - a, a)
+    };
+    (0..children.len())
+        .min_by(|&i, &j| {
+            let (a, b) = (cost(&children[i].0), cost(&children[j].0));
+            a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+        })
+        .unwrap()
+}
+
+/// 将溢出的条目分为两组，返回第二组
+///
+/// 先选择各种分法的边长之和最小的坐标轴，再在该轴上选择两组重叠面积最小的分法
+fn split<T: Scalar, X>(entries: &mut Vec<(Rect<T>, X)>) -> Vec<(Rect<T>, X)> {
+    let key = |rect: &Rect<T>, axis: bool| {
+        if axis {
+            (rect.top().to_f64(), rect.bottom().to_f64())
+        } else {
+            (rect.left().to_f64(), rect.right().to_f64())
+        }
+    };
+    let sort = |entries: &mut Vec<(Rect<T>, X)>, axis: bool| {
+        entries.sort_by(|a, b| {
+            let (a, b) = (key(&a.0, axis), key(&b.0, axis));
+            a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+        })
+    };
+
+    let mut best_axis = (false, f64::INFINITY);
+    for axis in [false, true] {
+        sort(entries, axis);
+        let total: f64 = distributions(entries)
+            .map(|(_, a, b)| margin(&a) + margin(&b))
+            .sum();
+        if total < best_axis.1 {
+            best_axis = (axis, total);
+        }
+    }
+    sort(entries, best_axis.0);
+    let (index, ..) = distributions(entries)
+        .map(|(k, a, b)| {
+            let overlap = a.intersected(&b).map_or(0.0, |r| area(&r));
+            (k, overlap, area(&a) + area(&b))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+        .unwrap();
+    entries.split_off(index)
+}
+
+/// 返回将有序条目分为前后两组的所有分法的分界位置及两组的外接矩形
+fn distributions<T: Scalar, X>(
+    entries: &[(Rect<T>, X)],
+) -> impl Iterator<Item = (usize, Rect<T>, Rect<T>)> + '_ {
+    (MIN_ENTRIES..=entries.len() - MIN_ENTRIES).map(move |k| {
+        let (a, b) = entries.split_at(k);
+        (k, bounds(a).unwrap(), bounds(b).unwrap())
+    })
+}
+
+/// 按 Sort-Tile-Recursive 方法将条目分为至多 `MAX_ENTRIES` 个一组
+fn pack<T: Scalar, X>(mut entries: Vec<(Rect<T>, X)>) -> Vec<Vec<(Rect<T>, X)>> {
+    let center = |rect: &Rect<T>, axis: bool| {
+        if axis {
+            rect.top().to_f64() + rect.bottom().to_f64()
+        } else {
+            rect.left().to_f64() + rect.right().to_f64()
+        }
+    };
+    let nodes = entries.len().div_ceil(MAX_ENTRIES);
+    let slice = (nodes as f64).sqrt().ceil() as usize * MAX_ENTRIES;
+    entries.sort_by(|a, b| center(&a.0, false).total_cmp(&center(&b.0, false)));
+
+    let mut groups = Vec::with_capacity(nodes);
+    let mut entries = entries.into_iter().peekable();
+    while entries.peek().is_some() {
+        let mut column: Vec<_> = entries.by_ref().take(slice).collect();
+        column.sort_by(|a, b| center(&a.0, true).total_cmp(&center(&b.0, true)));
+        let mut column = column.into_iter().peekable();
+        while column.peek().is_some() {
+            groups.push(column.by_ref().take(MAX_ENTRIES).collect());
+        }
+    }
+    groups
+}
+
+/// 以矩形为键的 R 树空间索引
+///
+/// 用于在大量图形中按点或矩形查找，以及查找与某点最近的图形；
+/// 坐标类型可以是整数或浮点数，查询时矩形的边界视为其内部
+///
+/// # Examples
+/// ```
+/// use ponsic_types::{Point, Recti, RTree};
+///
+/// let mut tree = RTree::new();
+/// let a = tree.insert(Recti::new(0, 0, 10, 10), "a");
+/// let b = tree.insert(Recti::new(20, 0, 30, 10), "b");
+/// let hits: Vec<_> = tree.query_point(Point::new(5, 5)).map(|(_, _, v)| *v).collect();
+/// assert_eq!(hits, ["a"]);
+/// assert_eq!(tree.nearest(Point::new(18, 5)).map(|(id, ..)| id), Some(b));
+///
+/// tree.update(a, Recti::new(40, 40, 50, 50));
+/// assert_eq!(tree.query_rect(Recti::new(0, 0, 15, 15)).count(), 0);
+/// assert_eq!(tree.remove(b), Some("b"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RTree<T: Scalar, V> {
+    root: Node<T>,
+    slots: Vec<Option<(Rect<T>, V)>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T: Scalar, V> Default for RTree<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar, V> RTree<T, V> {
+    /// 创建空的 R 树
+    pub fn new() -> Self {
+        Self {
+            root: Node::Leaf(Vec::new()),
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// 由一组条目批量创建 R 树
+    ///
+    /// 比逐个插入更快；第 `i` 个条目的标识序号为 `i`
+    pub fn bulk_load(items: impl IntoIterator<Item = (Rect<T>, V)>) -> Self {
+        let slots: Vec<Option<(Rect<T>, V)>> = items.into_iter().map(Some).collect();
+        let entries: Vec<(Rect<T>, ItemId)> = slots
+            .iter()
+            .enumerate()
+            .map(|(i, slot)| (slot.as_ref().unwrap().0, ItemId(i)))
+            .collect();
+
+        let mut nodes: Vec<Node<T>> = pack(entries).into_iter().map(Node::Leaf).collect();
+        while nodes.len() > 1 {
+            let children = nodes
+                .into_iter()
+                .map(|node| (node.bounds().unwrap(), node))
+                .collect();
+            nodes = pack(children).into_iter().map(Node::Branch).collect();
+        }
+        Self {
+            root: nodes.pop().unwrap_or(Node::Leaf(Vec::new())),
+            len: slots.len(),
+            slots,
+            free: Vec::new(),
+        }
+    }
+
+    /// 返回条目的数量
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断 R 树是否为空
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 移除所有条目
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// 返回所有条目的外接矩形，R 树为空时返回 `None`
+    #[inline]
+    pub fn bounds(&self) -> Option<Rect<T>> {
+        self.root.bounds()
+    }
+
+    /// 插入条目，返回其标识
+    pub fn insert(&mut self, rect: Rect<T>, value: V) -> ItemId {
+        let id = match self.free.pop() {
+            Some(index) => {
+                self.slots[index] = Some((rect, value));
+                ItemId(index)
+            }
+            None => {
+                self.slots.push(Some((rect, value)));
+                ItemId(self.slots.len() - 1)
+            }
+        };
+        self.len += 1;
+        self.insert_entry(rect, id);
+        id
+    }
+
+    fn insert_entry(&mut self, rect: Rect<T>, id: ItemId) {
+        if let Some(sibling) = self.root.insert(rect, id) {
+            let root = std::mem::replace(&mut self.root, Node::Leaf(Vec::new()));
+            self.root = Node::Branch(vec![(root.bounds().unwrap(), root), sibling]);
+        }
+    }
+
+    fn remove_entry(&mut self, rect: &Rect<T>, id: ItemId) {
+        self.root.remove(rect, id);
+        while let Node::Branch(children) = &mut self.root {
+            match children.len() {
+                0 => self.root = Node::Leaf(Vec::new()),
+                1 => self.root = children.pop().unwrap().1,
+                _ => break,
+            }
+        }
+    }
+
+    /// 移除条目，返回其值；条目不存在时返回 `None`
+    pub fn remove(&mut self, id: ItemId) -> Option<V> {
+        let (rect, value) = self.slots.get_mut(id.0)?.take()?;
+        self.remove_entry(&rect, id);
+        self.free.push(id.0);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// 更新条目的矩形，条目不存在时返回 `false`
+    pub fn update(&mut self, id: ItemId, rect: Rect<T>) -> bool {
+        let Some(Some((old, _))) = self.slots.get(id.0) else {
+            return false;
+        };
+        let old = *old;
+        self.remove_entry(&old, id);
+        self.slots[id.0].as_mut().unwrap().0 = rect;
+        self.insert_entry(rect, id);
+        true
+    }
+
+    /// 返回条目的矩形与值
+    #[inline]
+    pub fn get(&self, id: ItemId) -> Option<(&Rect<T>, &V)> {
+        self.slots
+            .get(id.0)?
+            .as_ref()
+            .map(|(rect, value)| (rect, value))
+    }
+
+    /// 返回条目的值的可变引用
+    #[inline]
+    pub fn get_mut(&mut self, id: ItemId) -> Option<&mut V> {
+        self.slots.get_mut(id.0)?.as_mut().map(|(_, value)| value)
+    }
+
+    /// 返回遍历所有条目的迭代器
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, &Rect<T>, &V)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slot)| slot.as_ref().map(|(r, v)| (ItemId(i), r, v)))
+    }
+
+    /// 返回包含点（含边界）的所有条目
+    pub fn query_point(&self, point: Point<T>) -> Query<'_, T, V> {
+        Query::new(self, Target::Point(point))
+    }
+
+    /// 返回与矩形相交（含边界相接）的所有条目
+    pub fn query_rect(&self, rect: Rect<T>) -> Query<'_, T, V> {
+        Query::new(self, Target::Rect(rect))
+    }
+
+    /// 返回与点距离最近的条目，点在矩形内时距离为 0；R 树为空时返回 `None`
+    pub fn nearest(&self, point: Point<T>) -> Option<(ItemId, &Rect<T>, &V)> {
+        let mut best = None;
+        nearest_in(&self.root, point, &mut best);
+        best.map(|(_, id)| {
+            let (rect, value) = self.get(id).unwrap();
+            (id, rect, value)
+        })
+    }
+}
+
+fn nearest_in<T: Scalar>(node: &Node<T>, point: Point<T>, best: &mut Option<(f64, ItemId)>) {
+    let closer = |d: f64, best: &Option<(f64, ItemId)>| best.is_none_or(|(b, _)| d < b);
+    match node {
+        Node::Leaf(entries) => {
+            for &(rect, id) in entries {
+                let d = distance_squared(&rect, point);
+                if closer(d, best) {
+                    *best = Some((d, id));
+                }
+            }
+        }
+        Node::Branch(children) => {
+            let mut order: Vec<(f64, &Node<T>)> = children
+                .iter()
+                .map(|(rect, node)| (distance_squared(rect, point), node))
+                .collect();
+            order.sort_by(|a, b| a.0.total_cmp(&b.0));
+            for (d, node) in order {
+                if !closer(d, best) {
+                    break;
+                }
+                nearest_in(node, point, best);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target<T: Scalar> {
+    Point(Point<T>),
+    Rect(Rect<T>),
+}
+
+impl<T: Scalar> Target<T> {
+    #[inline]
+    fn hits(&self, rect: &Rect<T>) -> bool {
+        match self {
+            Self::Point(p) => rect.contains_with_bound(*p),
+            Self::Rect(r) => rect.intersects(r),
+        }
+    }
+}
+
+/// [`RTree`] 的查询结果迭代器，参见 [`RTree::query_point()`] 与 [`RTree::query_rect()`]
+#[derive(Debug, Clone)]
+pub struct Query<'a, T: Scalar, V> {
+    tree: &'a RTree<T, V>,
+    target: Target<T>,
+    nodes: Vec<&'a Node<T>>,
+    entries: std::slice::Iter<'a, (Rect<T>, ItemId)>,
+}
+
+impl<'a, T: Scalar, V> Query<'a, T, V> {
+    fn new(tree: &'a RTree<T, V>, target: Target<T>) -> Self {
+        Self {
+            tree,
+            target,
+            nodes: vec![&tree.root],
+            entries: [].iter(),
+        }
+    }
+}
+
+impl<'a, T: Scalar, V> Iterator for Query<'a, T, V> {
+    type Item = (ItemId, &'a Rect<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for (rect, id) in self.entries.by_ref() {
+                if self.target.hits(rect) {
+                    let (_, value) = self.tree.get(*id).unwrap();
+                    return Some((*id, rect, value));
+                }
+            }
+            match self.nodes.pop()? {
+                Node::Leaf(entries) => self.entries = entries.iter(),
+                Node::Branch(children) => self.nodes.extend(
+                    children
+                        .iter()
+                        .filter(|(rect, _)| self.target.hits(rect))
+                        .map(|(_, node)| node),
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 线性同余伪随机数
+    fn random(seed: &mut u64) -> i32 {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*seed >> 33) as i32
+    }
+
+    fn random_rects(count: usize, seed: u64) -> Vec<Rect<i32>> {
+        let mut seed = seed;
+        (0..count)
+            .map(|_| {
+                let (x, y) = (random(&mut seed) % 1000, random(&mut seed) % 1000);
+                let (w, h) = (random(&mut seed) % 30, random(&mut seed) % 30);
+                Rect::new(x, y, x + w, y + h)
+            })
+            .collect()
+    }
+
+    fn sorted(ids: impl Iterator<Item = ItemId>) -> Vec<ItemId> {
+        let mut ids: Vec<ItemId> = ids.collect();
+        ids.sort();
+        ids
+    }
+
+    fn check(tree: &RTree<i32, usize>, seed: u64) {
+        let mut seed = seed;
+        for _ in 0..50 {
+            let p = Point::new(random(&mut seed) % 1000, random(&mut seed) % 1000);
+            let expected = sorted(
+                tree.iter()
+                    .filter(|(_, r, _)| r.contains_with_bound(p))
+                    .map(|(id, ..)| id),
+            );
+            assert_eq!(sorted(tree.query_point(p).map(|(id, ..)| id)), expected);
+
+            let area = Rect::new(p.x, p.y, p.x + 50, p.y + 20);
+            let expected = sorted(
+                tree.iter()
+                    .filter(|(_, r, _)| r.intersects(&area))
+                    .map(|(id, ..)| id),
+            );
+            assert_eq!(sorted(tree.query_rect(area).map(|(id, ..)| id)), expected);
+
+            let best = tree
+                .iter()
+                .map(|(_, r, _)| distance_squared(r, p))
+                .min_by(f64::total_cmp);
+            let found = tree.nearest(p).map(|(_, r, _)| distance_squared(r, p));
+            assert_eq!(found, best);
+        }
+    }
+
+    #[test]
+    fn insert_remove_test() {
+        let rects = random_rects(500, 1);
+        let mut tree = RTree::new();
+        let ids: Vec<ItemId> = rects
+            .iter()
+            .enumerate()
+            .map(|(i, &r)| tree.insert(r, i))
+            .collect();
+        assert_eq!(tree.len(), 500);
+        check(&tree, 2);
+
+        for &id in ids.iter().step_by(2) {
+            assert_eq!(tree.remove(id), Some(id.index()));
+        }
+        assert_eq!(tree.remove(ids[0]), None);
+        assert_eq!(tree.len(), 250);
+        check(&tree, 3);
+
+        for (&id, rect) in ids.iter().skip(1).step_by(2).zip(random_rects(250, 4)) {
+            assert!(tree.update(id, rect));
+        }
+        assert!(!tree.update(ids[0], Rect::new(0, 0, 1, 1)));
+        check(&tree, 5);
+
+        for &id in ids.iter().skip(1).step_by(2) {
+            tree.remove(id);
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.bounds(), None);
+        assert!(tree.nearest(Point::new(0, 0)).is_none());
+    }
+
+    #[test]
+    fn bulk_load_test() {
+        let rects = random_rects(1000, 6);
+        let tree = RTree::bulk_load(rects.iter().copied().zip(0..));
+        assert_eq!(tree.len(), 1000);
+        assert_eq!(tree.get(ItemId(3)), Some((&rects[3], &3)));
+        check(&tree, 7);
+        assert!(RTree::<i32, ()>::bulk_load([]).is_empty());
+    }
+
+    #[test]
+    fn float_test() {
+        let mut tree = RTree::new();
+        tree.insert(Rect::new(0.0, 0.0, 1.5, 1.5), 'a');
+        let b = tree.insert(Rect::new(2.0, 2.0, 3.0, 3.0), 'b');
+        let hits: Vec<char> = tree
+            .query_point(Point::new(1.5f32, 0.5))
+            .map(|(_, _, &v)| v)
+            .collect();
+        assert_eq!(hits, ['a']);
+        assert_eq!(
+            tree.nearest(Point::new(2.5, 3.5)).map(|(id, ..)| id),
+            Some(b)
+        );
+        *tree.get_mut(b).unwrap() = 'c';
+        assert_eq!(tree.get(b).map(|(_, &v)| v), Some('c'));
+    }
+}
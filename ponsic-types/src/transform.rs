@@ -0,0 +1,308 @@
+use super::{Float, Point, Rect, Size};
+use std::ops::{Mul, MulAssign};
+
+/// 二维仿射变换
+///
+/// 点 `(x, y)` 被变换为 `(x·m11 + y·m21 + dx, x·m12 + y·m22 + dy)`，
+/// 分量的排列与 GDI 的 `XFORM` 及 SVG 的 `matrix(m11 m12 m21 m22 dx dy)` 相同；
+/// 在 y 轴向下的坐标系中，正的旋转角度使图形顺时针旋转
+///
+/// # Examples
+/// ```
+/// use ponsic_types::{Pointf, Transformf};
+///
+/// let t = Transformf::scaling(2.0, 2.0).then_translate(1.0, 0.0);
+/// assert_eq!(t.transform_point(Pointf::new(1.0, 1.0)), Pointf::new(3.0, 2.0));
+/// assert_eq!(t * t.inverse().unwrap(), Transformf::IDENTITY);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D<T: Float> {
+    pub m11: T,
+    pub m12: T,
+    pub m21: T,
+    pub m22: T,
+    pub dx: T,
+    pub dy: T,
+}
+
+impl<T: Float> Default for Transform2D<T> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<T: Float> Transform2D<T> {
+    /// 恒等变换
+    pub const IDENTITY: Self = Self::new(T::ONE, T::ZERO, T::ZERO, T::ONE, T::ZERO, T::ZERO);
+
+    /// 由各分量创建变换
+    #[inline]
+    pub const fn new(m11: T, m12: T, m21: T, m22: T, dx: T, dy: T) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            dx,
+            dy,
+        }
+    }
+
+    /// 创建平移变换
+    #[inline]
+    pub fn translation(x: T, y: T) -> Self {
+        Self::new(T::ONE, T::ZERO, T::ZERO, T::ONE, x, y)
+    }
+
+    /// 创建以原点为中心的缩放变换
+    #[inline]
+    pub fn scaling(sx: T, sy: T) -> Self {
+        Self::new(sx, T::ZERO, T::ZERO, sy, T::ZERO, T::ZERO)
+    }
+
+    /// 创建绕原点旋转 `radians` 弧度的变换
+    #[inline]
+    pub fn rotation(radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(cos, sin, -sin, cos, T::ZERO, T::ZERO)
+    }
+
+    /// 创建错切变换，`x` 与 `y` 分别为沿 x 轴与 y 轴错切的弧度
+    #[inline]
+    pub fn skewing(x: T, y: T) -> Self {
+        Self::new(T::ONE, y.tan(), x.tan(), T::ONE, T::ZERO, T::ZERO)
+    }
+
+    /// 返回先应用 `self` 再应用 `other` 的变换
+    #[inline]
+    pub fn then(&self, other: &Self) -> Self {
+        Self::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.dx * other.m11 + self.dy * other.m21 + other.dx,
+            self.dx * other.m12 + self.dy * other.m22 + other.dy,
+        )
+    }
+
+    /// 返回先应用 `self` 再平移的变换
+    #[inline]
+    pub fn then_translate(&self, x: T, y: T) -> Self {
+        self.then(&Self::translation(x, y))
+    }
+
+    /// 返回先应用 `self` 再缩放的变换
+    #[inline]
+    pub fn then_scale(&self, sx: T, sy: T) -> Self {
+        self.then(&Self::scaling(sx, sy))
+    }
+
+    /// 返回先应用 `self` 再旋转的变换
+    #[inline]
+    pub fn then_rotate(&self, radians: T) -> Self {
+        self.then(&Self::rotation(radians))
+    }
+
+    /// 返回先应用 `self` 再错切的变换
+    #[inline]
+    pub fn then_skew(&self, x: T, y: T) -> Self {
+        self.then(&Self::skewing(x, y))
+    }
+
+    /// 返回绕 `center` 旋转 `radians` 弧度的变换
+    #[inline]
+    pub fn rotation_around(center: Point<T>, radians: T) -> Self {
+        Self::translation(-center.x, -center.y)
+            .then_rotate(radians)
+            .then_translate(center.x, center.y)
+    }
+
+    /// 返回变换矩阵的行列式
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// 判断变换是否可逆
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        let det = self.determinant();
+        det != T::ZERO && det.is_finite()
+    }
+
+    /// 判断变换是否为恒等变换
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// 返回逆变换，变换不可逆时返回 `None`
+    pub fn inverse(&self) -> Option<Self> {
+        if !self.is_invertible() {
+            return None;
+        }
+        let det = self.determinant();
+        let (m11, m12) = (self.m22 / det, -self.m12 / det);
+        let (m21, m22) = (-self.m21 / det, self.m11 / det);
+        Some(Self::new(
+            m11,
+            m12,
+            m21,
+            m22,
+            -(self.dx * m11 + self.dy * m21),
+            -(self.dx * m12 + self.dy * m22),
+        ))
+    }
+
+    /// 变换点
+    #[inline]
+    pub fn transform_point(&self, p: Point<T>) -> Point<T> {
+        Point::new(
+            p.x * self.m11 + p.y * self.m21 + self.dx,
+            p.x * self.m12 + p.y * self.m22 + self.dy,
+        )
+    }
+
+    /// 变换向量，平移分量不起作用
+    #[inline]
+    pub fn transform_vector(&self, v: Point<T>) -> Point<T> {
+        Point::new(
+            v.x * self.m11 + v.y * self.m21,
+            v.x * self.m12 + v.y * self.m22,
+        )
+    }
+
+    /// 返回矩形经变换后的外接矩形
+    pub fn transform_rect(&self, rect: &Rect<T>) -> Rect<T> {
+        let corners = [
+            rect.left_top(),
+            rect.right_top(),
+            rect.left_bottom(),
+            rect.right_bottom(),
+        ]
+        .map(|p| self.transform_point(p));
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for p in &corners[1..] {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        }
+        Rect::from((min, Size::new(max.x - min.x, max.y - min.y)))
+    }
+}
+
+/// `a * b` 表示先应用 `a` 再应用 `b` 的变换
+impl<T: Float> Mul for Transform2D<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.then(&rhs)
+    }
+}
+
+impl<T: Float> MulAssign for Transform2D<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.then(&rhs);
+    }
+}
+
+impl From<Transform2D<f32>> for Transform2D<f64> {
+    #[inline]
+    fn from(t: Transform2D<f32>) -> Self {
+        Self::new(
+            t.m11 as f64,
+            t.m12 as f64,
+            t.m21 as f64,
+            t.m22 as f64,
+            t.dx as f64,
+            t.dy as f64,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn assert_near(a: Point<f64>, b: Point<f64>) {
+        assert!(
+            (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn transform_point_test() {
+        let p = Point::new(2.0, 1.0);
+        let t = Transform2D::translation(1.0, -1.0);
+        assert_eq!(t.transform_point(p), Point::new(3.0, 0.0));
+        assert_eq!(t.transform_vector(p), p);
+        // y 轴向下时，x 轴正方向旋转 90° 后指向 y 轴正方向
+        let r = Transform2D::rotation(FRAC_PI_2);
+        assert_near(
+            r.transform_point(Point::new(1.0, 0.0)),
+            Point::new(0.0, 1.0),
+        );
+        let s = Transform2D::skewing(FRAC_PI_2 / 2.0, 0.0);
+        assert_near(
+            s.transform_point(Point::new(0.0, 2.0)),
+            Point::new(2.0, 2.0),
+        );
+        let around = Transform2D::rotation_around(Point::new(1.0, 1.0), FRAC_PI_2);
+        assert_near(
+            around.transform_point(Point::new(2.0, 1.0)),
+            Point::new(1.0, 2.0),
+        );
+    }
+
+    #[test]
+    fn compose_test() {
+        let t = Transform2D::translation(1.0, 2.0).then_scale(2.0, 3.0);
+        assert_eq!(
+            t.transform_point(Point::new(0.0, 0.0)),
+            Point::new(2.0, 6.0)
+        );
+        let u = Transform2D::scaling(2.0, 3.0).then_translate(1.0, 2.0);
+        assert_eq!(
+            u.transform_point(Point::new(0.0, 0.0)),
+            Point::new(1.0, 2.0)
+        );
+        let mut v = Transform2D::IDENTITY;
+        v *= Transform2D::translation(1.0, 2.0);
+        v *= Transform2D::scaling(2.0, 3.0);
+        assert_eq!(v, t);
+    }
+
+    #[test]
+    fn inverse_test() {
+        let t = Transform2D::rotation(0.3)
+            .then_scale(2.0, 0.5)
+            .then_skew(0.2, -0.1)
+            .then_translate(5.0, -7.0);
+        let inv = t.inverse().unwrap();
+        let p = Point::new(3.0, 4.0);
+        assert_near(inv.transform_point(t.transform_point(p)), p);
+        assert!(Transform2D::scaling(0.0, 1.0).inverse().is_none());
+        assert!(Transform2D::<f32>::default().is_identity());
+        assert_eq!(
+            Transform2D::<f64>::from(Transform2D::<f32>::IDENTITY),
+            Transform2D::IDENTITY
+        );
+    }
+
+    #[test]
+    fn transform_rect_test() {
+        let rect = Rect::from((Point::new(0.0, 0.0), Size::new(4.0, 2.0)));
+        let t = Transform2D::scaling(-1.0, 2.0).then_translate(10.0, 0.0);
+        let r = t.transform_rect(&rect);
+        assert_eq!(
+            (r.left(), r.top(), r.right(), r.bottom()),
+            (6.0, 0.0, 10.0, 4.0)
+        );
+        let r = Transform2D::rotation(FRAC_PI_2).transform_rect(&rect);
+        assert!((r.left() + 2.0).abs() < 1e-9 && (r.bottom() - 4.0).abs() < 1e-9);
+    }
+}
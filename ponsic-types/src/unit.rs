@@ -0,0 +1,336 @@
+use super::{Point, Rect, Scalar, Size};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// 以逻辑像素为单位的值
+///
+/// 逻辑像素与 96 DPI 下的物理像素等大；不同单位的值不能直接运算，
+/// 需通过 [`ScaleFactor`] 显式转换
+///
+/// # Examples
+/// ```
+/// use ponsic_types::{LogicalPoint, Pointf, Pointi, Rounding, ScaleFactor};
+///
+/// let scale = ScaleFactor::from_dpi(144).unwrap();
+/// let p = LogicalPoint::new(Pointf::new(10.0, 20.5));
+/// assert_eq!(p.to_physical(scale, Rounding::Nearest).0, Pointi::new(15, 31));
+/// ```
+///
+/// 逻辑像素与物理像素不能混合运算：
+/// ```compile_fail
+/// use ponsic_types::{LogicalPoint, PhysicalPoint, Pointf, Pointi};
+///
+/// let _ = LogicalPoint::new(Pointf::new(1.0, 1.0)) + PhysicalPoint::new(Pointi::new(1, 1));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Logical<T>(pub T);
+
+/// 以物理（设备）像素为单位的值，参见 [`Logical`]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Physical<T>(pub T);
+
+pub type LogicalPoint = Logical<Point<f32>>;
+pub type LogicalSize = Logical<Size<f32>>;
+pub type LogicalRect = Logical<Rect<f32>>;
+pub type PhysicalPoint = Physical<Point<i32>>;
+pub type PhysicalSize = Physical<Size<u32>>;
+pub type PhysicalRect = Physical<Rect<i32>>;
+
+/// 物理像素与逻辑像素之比
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ScaleFactor(f64);
+
+impl Default for ScaleFactor {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+impl ScaleFactor {
+    /// 逻辑像素所对应的 DPI
+    pub const BASE_DPI: u32 = 96;
+
+    /// 物理像素与逻辑像素等大时的缩放比例
+    pub const ONE: Self = Self(1.0);
+
+    /// 创建缩放比例，`factor` 不是有限正数时返回 `None`
+    #[inline]
+    pub fn new(factor: f64) -> Option<Self> {
+        (factor.is_finite() && factor > 0.0).then_some(Self(factor))
+    }
+
+    /// 由显示器的 DPI 创建缩放比例，`dpi` 为 0 时返回 `None`
+    #[inline]
+    pub fn from_dpi(dpi: u32) -> Option<Self> {
+        Self::new(dpi as f64 / Self::BASE_DPI as f64)
+    }
+
+    /// 返回缩放比例的值
+    #[inline]
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// 返回缩放比例对应的 DPI，四舍五入
+    #[inline]
+    pub fn dpi(self) -> u32 {
+        (self.0 * Self::BASE_DPI as f64).round() as u32
+    }
+}
+
+/// 单位转换时的取整方式
+///
+/// 取整作用于转换后的每个分量，目标类型为浮点数时同样生效；
+/// 需要保留小数部分时使用 [`Rounding::Exact`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// 不取整，目标类型为整数时四舍五入
+    Exact,
+    /// 四舍五入
+    #[default]
+    Nearest,
+    /// 向下取整
+    Floor,
+    /// 向上取整
+    Ceil,
+    /// 向外取整：矩形取包含它的最小整数矩形，尺寸向上取整，点四舍五入
+    Outward,
+    /// 向内取整：矩形取包含于其中的最大整数矩形，尺寸向下取整，点四舍五入
+    Inward,
+}
+
+impl Rounding {
+    /// `grow` 为向外取整的方向：`Some(true)` 为向上，`Some(false)` 为向下，`None` 表示没有内外之分
+    fn apply(self, value: f64, grow: Option<bool>) -> f64 {
+        // 忽略缩放带来的浮点误差，避免整数值被向上或向下取整至相邻的整数
+        let nearest = value.round();
+        let snapped = if (value - nearest).abs() < 1e-9 {
+            nearest
+        } else {
+            value
+        };
+        let toward = |up: bool| if up { snapped.ceil() } else { snapped.floor() };
+        match (self, grow) {
+            (Self::Exact, _) => value,
+            (Self::Nearest, _) | (Self::Outward | Self::Inward, None) => nearest,
+            (Self::Floor, _) => snapped.floor(),
+            (Self::Ceil, _) => snapped.ceil(),
+            (Self::Outward, Some(up)) => toward(up),
+            (Self::Inward, Some(up)) => toward(!up),
+        }
+    }
+}
+
+/// 可在逻辑像素与物理像素间转换的几何类型
+pub trait Scalable: Copy {
+    /// 坐标类型替换为 `U` 后的类型
+    type Cast<U: Scalar>;
+
+    /// 将各分量乘以 `factor`，按 `rounding` 取整后转换为 `U`
+    fn scale<U: Scalar>(self, factor: f64, rounding: Rounding) -> Self::Cast<U>;
+}
+
+impl<T: Scalar> Scalable for Point<T> {
+    type Cast<U: Scalar> = Point<U>;
+
+    #[inline]
+    fn scale<U: Scalar>(self, factor: f64, rounding: Rounding) -> Point<U> {
+        let f = |v: T| U::from_f64(rounding.apply(v.to_f64() * factor, None));
+        Point::new(f(self.x), f(self.y))
+    }
+}
+
+impl<T: Scalar> Scalable for Size<T> {
+    type Cast<U: Scalar> = Size<U>;
+
+    #[inline]
+    fn scale<U: Scalar>(self, factor: f64, rounding: Rounding) -> Size<U> {
+        let f = |v: T| U::from_f64(rounding.apply(v.to_f64() * factor, Some(true)));
+        Size::new(f(self.width), f(self.height))
+    }
+}
+
+impl<T: Scalar> Scalable for Rect<T> {
+    type Cast<U: Scalar> = Rect<U>;
+
+    fn scale<U: Scalar>(self, factor: f64, rounding: Rounding) -> Rect<U> {
+        let f = |v: T, up: bool| U::from_f64(rounding.apply(v.to_f64() * factor, Some(up)));
+        let (left, top) = (f(self.left(), false), f(self.top(), false));
+        let (right, bottom) = (f(self.right(), true), f(self.bottom(), true));
+        // 向内取整可能使过窄的矩形左右或上下颠倒
+        let right = if right < left { left } else { right };
+        let bottom = if bottom < top { top } else { bottom };
+        Rect::new(left, top, right, bottom)
+    }
+}
+
+impl<T> Logical<T> {
+    /// 以逻辑像素为单位包装值
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Scalable> Logical<T> {
+    /// 按缩放比例转换为物理像素
+    #[inline]
+    pub fn to_physical<U: Scalar>(
+        self,
+        scale: ScaleFactor,
+        rounding: Rounding,
+    ) -> Physical<T::Cast<U>> {
+        Physical(self.0.scale(scale.0, rounding))
+    }
+}
+
+impl<T> Physical<T> {
+    /// 以物理像素为单位包装值
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Scalable> Physical<T> {
+    /// 按缩放比例转换为逻辑像素
+    #[inline]
+    pub fn to_logical<U: Scalar>(
+        self,
+        scale: ScaleFactor,
+        rounding: Rounding,
+    ) -> Logical<T::Cast<U>> {
+        Logical(self.0.scale(1.0 / scale.0, rounding))
+    }
+}
+
+macro_rules! impl_unit_ops {
+    ($($unit:ident),*) => {$(
+        impl<T: Add<Output = T>> Add for $unit<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $unit<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $unit<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $unit<T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $unit<T> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+    )*};
+}
+
+impl_unit_ops!(Logical, Physical);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_factor_test() {
+        let scale = ScaleFactor::from_dpi(120).unwrap();
+        assert_eq!(scale.value(), 1.25);
+        assert_eq!(scale.dpi(), 120);
+        assert_eq!(ScaleFactor::default(), ScaleFactor::ONE);
+        assert!(ScaleFactor::from_dpi(0).is_none());
+        assert!(ScaleFactor::new(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn point_size_test() {
+        let scale = ScaleFactor::new(1.5).unwrap();
+        let p = LogicalPoint::new(Point::new(10.3, -2.5));
+        let to = |r| p.to_physical::<i32>(scale, r).0;
+        assert_eq!(to(Rounding::Nearest), Point::new(15, -4));
+        assert_eq!(to(Rounding::Floor), Point::new(15, -4));
+        assert_eq!(to(Rounding::Ceil), Point::new(16, -3));
+        assert_eq!(to(Rounding::Outward), to(Rounding::Nearest));
+
+        let size = PhysicalSize::new(Size::new(15, 16));
+        let to = |r| size.to_logical::<u32>(scale, r).0;
+        assert_eq!(to(Rounding::Outward), Size::new(10, 11));
+        assert_eq!(to(Rounding::Inward), Size::new(10, 10));
+        assert_eq!(
+            size.to_logical::<f32>(scale, Rounding::Nearest).0,
+            Size::new(10.0, 11.0)
+        );
+    }
+
+    #[test]
+    fn exact_test() {
+        let scale = ScaleFactor::new(1.5).unwrap();
+        let physical = PhysicalPoint::new(Point::new(16, 1));
+        let logical = physical.to_logical::<f32>(scale, Rounding::Exact);
+        assert!((logical.0.x - 32.0 / 3.0).abs() < 1e-5);
+        assert!((logical.0.y - 2.0 / 3.0).abs() < 1e-5);
+        assert_eq!(logical.to_physical(scale, Rounding::Nearest), physical);
+        assert_eq!(logical.to_physical(scale, Rounding::Exact), physical);
+
+        let rect = LogicalRect::new(Rect::new(0.25, 0.5, 1.0, 1.25));
+        let scaled = rect.to_physical::<f64>(scale, Rounding::Exact).0;
+        assert_eq!(scaled, Rect::new(0.375, 0.75, 1.5, 1.875));
+        assert_eq!(
+            LogicalSize::new(Size::new(0.5, 1.0))
+                .to_physical::<u32>(scale, Rounding::Exact)
+                .0,
+            Size::new(1, 2)
+        );
+    }
+
+    #[test]
+    fn rect_test() {
+        let scale = ScaleFactor::from_dpi(144).unwrap();
+        let rect = LogicalRect::new(Rect::new(0.5, 1.0, 3.5, 1.2));
+        let to = |r| rect.to_physical::<i32>(scale, r).0;
+        assert_eq!(to(Rounding::Nearest), Rect::new(1, 2, 5, 2));
+        assert_eq!(to(Rounding::Outward), Rect::new(0, 1, 6, 2));
+        assert_eq!(to(Rounding::Inward), Rect::new(1, 2, 5, 2));
+
+        let physical = PhysicalRect::new(Rect::new(-3, 0, 9, 6));
+        let logical = physical.to_logical::<f32>(scale, Rounding::Nearest);
+        assert_eq!(logical.0, Rect::new(-2.0, 0.0, 6.0, 4.0));
+        assert_eq!(logical.to_physical(scale, Rounding::Nearest), physical);
+    }
+
+    #[test]
+    fn ops_test() {
+        let a = LogicalPoint::new(Point::new(1.0, 2.0));
+        let mut b = a + a;
+        b -= a;
+        assert_eq!(b, a);
+        assert_eq!(-a - a, Logical(Point::new(-2.0, -4.0)));
+        let mut c = PhysicalSize::new(Size::new(4, 4));
+        c += PhysicalSize::new(Size::new(1, 2));
+        assert_eq!(c.0, Size::new(5, 6));
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(not(target_os = "windows"))]
 fn main() {}
 
+#[cfg(target_os = "windows")]
 use ponsic_winsafe::graphics::context_2d::Context2D;
 #[cfg(target_os = "windows")]
 use ponsic_winsafe::*;
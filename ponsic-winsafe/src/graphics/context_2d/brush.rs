@@ -1,31 +1,24 @@
+use ponsic_types::{Sizei, Transformf};
 use winapi::shared::windef::HBRUSH;
 use winapi::um::wingdi::{
-    CreateHatchBrush, CreateSolidBrush, HS_BDIAGONAL, HS_CROSS, HS_DIAGCROSS, HS_FDIAGONAL,
+    BI_RGB, BITMAPINFOHEADER, CreateDIBPatternBrushPt, CreateHatchBrush, CreateSolidBrush,
+    DIB_RGB_COLORS, DeleteObject, HS_BDIAGONAL, HS_CROSS, HS_DIAGCROSS, HS_FDIAGONAL,
     HS_HORIZONTAL, HS_VERTICAL, RGB,
 };
+use winapi::um::winuser::{GetSystemMetrics, SM_CXSCREEN, SM_CYSCREEN};
 
 use super::Color;
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub enum HatchStyle {
-    BDiagonal,
-    Cross,
-    DiagCross,
-    FDiagonal,
-    Horizontal,
-    Vertical,
-}
+pub use ponsic_graphics::{GenBrush, HatchStyle, LinearGradient, Pattern, RadialGradient};
 
-impl HatchStyle {
-    fn to_sys_enum(self) -> u32 {
-        match self {
-            HatchStyle::BDiagonal => HS_BDIAGONAL,
-            HatchStyle::Cross => HS_CROSS,
-            HatchStyle::DiagCross => HS_DIAGCROSS,
-            HatchStyle::FDiagonal => HS_FDIAGONAL,
-            HatchStyle::Horizontal => HS_HORIZONTAL,
-            HatchStyle::Vertical => HS_VERTICAL,
-        }
+fn hatch_style_to_sys(style: HatchStyle) -> u32 {
+    match style {
+        HatchStyle::BDiagonal => HS_BDIAGONAL,
+        HatchStyle::Cross => HS_CROSS,
+        HatchStyle::DiagCross => HS_DIAGCROSS,
+        HatchStyle::FDiagonal => HS_FDIAGONAL,
+        HatchStyle::Horizontal => HS_HORIZONTAL,
+        HatchStyle::Vertical => HS_VERTICAL,
     }
 }
 
@@ -33,46 +26,107 @@ pub struct Brush {
     pub(crate) brush: HBRUSH,
 }
 
+impl Drop for Brush {
+    fn drop(&mut self) {
+        unsafe { DeleteObject(self.brush as _) };
+    }
+}
+
 impl Brush {
     pub unsafe fn handle(&self) -> HBRUSH {
         self.brush
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum GenBrush {
-    Solid(Color),
-    Hatch(Color, HatchStyle),
-}
-
-impl Default for GenBrush {
-    fn default() -> Self {
-        GenBrush::Solid(Color::BLACK)
+/// 由画刷描述创建 GDI 画刷
+///
+/// # Note
+/// 渐变画刷被绘制为覆盖整个主屏幕的图案画刷，轴对齐的线性渐变只需绘制一行或一列
+impl From<GenBrush> for Brush {
+    fn from(brush: GenBrush) -> Self {
+        let size = unsafe {
+            Sizei::new(
+                GetSystemMetrics(SM_CXSCREEN) as _,
+                GetSystemMetrics(SM_CYSCREEN) as _,
+            )
+        };
+        create_brush(&brush, size)
     }
 }
 
-impl GenBrush {
-    pub fn create(self) -> Brush {
-        self.create_by_ref()
-    }
-
-    pub(crate) fn create_by_ref(&self) -> Brush {
-        match self {
-            GenBrush::Solid(Color {
+/// 创建 GDI 画刷，渐变与图案画刷被绘制为覆盖 `area` 的图案画刷
+///
+/// 图案与绘图坐标的原点对齐，`area` 之外的部分将重复图案
+pub(crate) fn create_brush(brush: &GenBrush, area: Sizei) -> Brush {
+    match brush {
+        GenBrush::Solid(Color {
+            red, green, blue, ..
+        }) => Brush {
+            brush: unsafe { CreateSolidBrush(RGB(*red, *green, *blue)) },
+        },
+        GenBrush::Hatch(
+            Color {
                 red, green, blue, ..
-            }) => Brush {
-                brush: unsafe { CreateSolidBrush(RGB(*red, *green, *blue)) },
             },
-            GenBrush::Hatch(
-                Color {
-                    red, green, blue, ..
-                },
-                hatch,
-            ) => Brush {
-                brush: unsafe {
-                    CreateHatchBrush(hatch.to_sys_enum() as _, RGB(*red, *green, *blue))
-                },
+            hatch,
+        ) => Brush {
+            brush: unsafe {
+                CreateHatchBrush(hatch_style_to_sys(*hatch) as _, RGB(*red, *green, *blue))
             },
+        },
+        GenBrush::LinearGradient(_) | GenBrush::RadialGradient(_) | GenBrush::Pattern(_) => {
+            create_pattern_brush(brush, area)
+        }
+    }
+}
+
+/// 返回在 `area` 内与画刷等效的最小平铺图案的尺寸
+///
+/// 轴对齐的线性渐变只需一行或一列像素，未经变换的图案只需图像本身
+fn tile_size(brush: &GenBrush, area: Sizei) -> Sizei {
+    match brush {
+        GenBrush::LinearGradient(linear) if linear.start.y == linear.end.y => {
+            Sizei::new(area.width, 1)
+        }
+        GenBrush::LinearGradient(linear) if linear.start.x == linear.end.x => {
+            Sizei::new(1, area.height)
+        }
+        GenBrush::Pattern(pattern) if pattern.transform == Transformf::IDENTITY => {
+            pattern.image.size()
         }
+        _ => area,
+    }
+}
+
+/// 以 32 位 DIB 创建图案画刷，透明度被忽略
+fn create_pattern_brush(brush: &GenBrush, area: Sizei) -> Brush {
+    let size = tile_size(brush, area);
+    let size = Sizei::new(size.width.max(1), size.height.max(1));
+    let bitmap = brush.render(size);
+    let header = BITMAPINFOHEADER {
+        biSize: size_of::<BITMAPINFOHEADER>() as _,
+        biWidth: size.width as _,
+        // 高度为正数表示自下而上排列的位图
+        biHeight: size.height as _,
+        biPlanes: 1,
+        biBitCount: 32,
+        biCompression: BI_RGB,
+        ..unsafe { std::mem::zeroed() }
+    };
+    // 以 u32 存放打包的 DIB 以满足对齐要求
+    let header_len = size_of::<BITMAPINFOHEADER>() / 4;
+    let mut packed = vec![0u32; header_len];
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            &header as *const _ as *const u32,
+            packed.as_mut_ptr(),
+            header_len,
+        );
+    }
+    for row in bitmap.pixels().chunks_exact(size.width as usize).rev() {
+        packed.extend(row.iter().map(|c| c.to_argb() & 0x00ff_ffff));
+    }
+    Brush {
+        brush: unsafe { CreateDIBPatternBrushPt(packed.as_ptr() as _, DIB_RGB_COLORS) },
     }
 }
@@ -0,0 +1,158 @@
+use super::{
+    Context2D, Context2DData, DrawClose, DrawOpen, DrawPath, DrawPixel, DrawPolygon, DrawText,
+    EnvironmentSetter, GenBrush, GenFont, GenPen,
+};
+use ponsic_color::Color;
+use ponsic_graphics::{Canvas, TextCanvas};
+use ponsic_types::Pointi as Point;
+use ponsic_types::Recti as Rect;
+use winapi::um::wingdi::{FillPath, StrokeAndFillPath, StrokePath};
+
+impl Canvas for Context2D<'_> {
+    fn pen(&self) -> GenPen<'_> {
+        self.pen_data.as_pen()
+    }
+
+    fn set_pen(&mut self, pen: &GenPen) {
+        self.pen_data = pen.to_buf();
+        self.update_pen();
+    }
+
+    fn brush(&self) -> &GenBrush {
+        &self.brush_data
+    }
+
+    fn set_brush(&mut self, brush: &GenBrush) {
+        self.brush_data = brush.clone();
+        self.update_brush();
+    }
+
+    fn as_text_canvas(&mut self) -> Option<&mut dyn TextCanvas> {
+        Some(self)
+    }
+
+    fn line(&mut self, p1: Point, p2: Point) {
+        DrawOpen::line(self, p1, p2);
+    }
+
+    fn polyline(&mut self, points: &[Point]) {
+        DrawOpen::polyline(self, points);
+    }
+
+    fn arc(&mut self, border: Rect, p1: Point, p2: Point) {
+        DrawOpen::arc(self, border, p1, p2);
+    }
+
+    fn angle_arc(&mut self, orgin: Point, radius: u32, start: f32, end: f32) {
+        DrawOpen::angle_arc(self, orgin, radius, start, end);
+    }
+
+    fn poly_bezier(&mut self, points: &[Point]) {
+        DrawOpen::poly_bezier(self, points);
+    }
+
+    fn rectangle(&mut self, rect: Rect) {
+        DrawClose::rectangle(self, rect);
+    }
+
+    fn ellipse(&mut self, rect: Rect) {
+        DrawClose::ellipse(self, rect);
+    }
+
+    fn polygon(&mut self, points: &[Point]) {
+        DrawClose::polygon(self, points);
+    }
+
+    fn pie(&mut self, rect: Rect, p1: Point, p2: Point) {
+        DrawClose::pie(self, rect, p1, p2);
+    }
+
+    fn chord(&mut self, rect: Rect, p1: Point, p2: Point) {
+        DrawClose::chord(self, rect, p1, p2);
+    }
+
+    fn round_rect(&mut self, rect: Rect, dx: u32, dy: u32) {
+        DrawClose::round_rect(self, rect, dx, dy);
+    }
+
+    fn begin_path(&mut self) {
+        DrawPath::begin_path(self);
+    }
+
+    fn end_path(&mut self) {
+        DrawPath::end_path(self);
+    }
+
+    fn move_to(&mut self, p: Point) {
+        DrawPath::move_to(self, p);
+    }
+
+    fn line_to(&mut self, p: Point) {
+        DrawPath::line_to(self, p);
+    }
+
+    fn arc_to(&mut self, rect: Rect, p1: Point, p2: Point) {
+        DrawPath::arc_to(self, rect, p1, p2);
+    }
+
+    fn poly_bezier_to(&mut self, points: &[Point]) {
+        DrawPath::poly_bezier_to(self, points);
+    }
+
+    fn polyline_to(&mut self, points: &[Point]) {
+        DrawPath::polyline_to(self, points);
+    }
+
+    fn close_figure(&mut self) {
+        DrawPath::close_figure(self);
+    }
+
+    fn stroke_path(&mut self) {
+        unsafe {
+            StrokePath(self.hdc());
+        }
+    }
+
+    fn fill_path(&mut self) {
+        unsafe {
+            FillPath(self.hdc());
+        }
+    }
+
+    fn stroke_and_fill_path(&mut self) {
+        unsafe {
+            StrokeAndFillPath(self.hdc());
+        }
+    }
+
+    fn poly_polygon(&mut self, points: &[Point], count: &[u32]) {
+        DrawPolygon::poly_polygon(self, points, count);
+    }
+
+    fn poly_polyline(&mut self, points: &[Point], count: &[u32]) {
+        DrawPolygon::poly_polyline(self, points, count);
+    }
+
+    fn draw_pixel(&mut self, p: Point, color: Color) {
+        DrawPixel::draw_pixel(self, p, color);
+    }
+}
+
+impl TextCanvas for Context2D<'_> {
+    fn font(&self) -> &GenFont {
+        &self.font_data
+    }
+
+    fn set_font(&mut self, font: &GenFont) {
+        self.font_data = font.clone();
+        self.update_font();
+    }
+
+    fn set_text_color(&mut self, color: Color) {
+        EnvironmentSetter::set_text_color(self, color);
+    }
+
+    fn out_text(&mut self, text: &str, p: Point) {
+        DrawText::out_text(self, text, p);
+    }
+}
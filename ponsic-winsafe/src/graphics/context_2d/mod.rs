@@ -1,4 +1,5 @@
 mod brush;
+mod canvas;
 mod pen;
 mod text;
 mod traits;
@@ -6,12 +7,16 @@ mod traits;
 use super::context::Context;
 pub use brush::*;
 pub use pen::*;
-use ponsic_color::Color;
+use ponsic_color::{Color, Gradient};
+use ponsic_graphics::Canvas;
+use ponsic_types::{Pointi as Point, Sizei};
 use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
 pub use text::*;
 pub use traits::*;
 use winapi::{
-    shared::windef::{HDC, HWND},
+    shared::windef::{HDC, HWND, RECT},
     um::{wingdi::*, winuser::*},
 };
 
@@ -20,14 +25,21 @@ pub struct Context2D<'a> {
     hwnd: HWND,
     hdc: HDC,
     ps: PAINTSTRUCT,
-    pen_data: GenPen<'a>,
+    pen_data: GenPenBuf,
     pen: Pen,
     brush_data: GenBrush,
-    brush: Brush,
+    brush: Rc<Brush>,
+    /// 最近使用的渐变与图案画刷，避免反复绘制图案
+    brush_cache: Vec<(GenBrush, Sizei, Rc<Brush>)>,
     font_data: GenFont,
     font: Font,
+    /// 画笔描述已自行保存虚线数组，保留生命周期参数以兼容既有代码
+    _marker: PhantomData<&'a ()>,
 }
 
+/// 上下文缓存的渐变与图案画刷的数量
+const BRUSH_CACHE_SIZE: usize = 4;
+
 /// 高性能 2D 绘图上下文
 pub struct FastContext2D {
     hwnd: HWND,
@@ -98,12 +110,14 @@ impl Context2D<'_> {
             hwnd,
             hdc,
             ps,
-            pen_data: GenPen::default(),
+            pen_data: GenPenBuf::default(),
             pen: GenPen::default().create(),
             brush_data: GenBrush::Solid(Color::WHITE),
-            brush: GenBrush::Solid(Color::WHITE).create(),
+            brush: Rc::new(GenBrush::Solid(Color::WHITE).create()),
+            brush_cache: Vec::new(),
             font_data: GenFont::default(),
             font: GenFont::default().create(),
+            _marker: PhantomData,
         }
     }
 }
@@ -124,9 +138,9 @@ impl Context2D<'_> {
     }
 }
 
-impl<'a> Context2D<'a> {
+impl Context2D<'_> {
     fn update_pen(&mut self) {
-        self.pen = self.pen_data.create_by_ref().unwrap();
+        self.pen = create_pen(&self.pen_data.as_pen()).unwrap();
         unsafe {
             SelectObject(self.hdc(), self.pen.handle() as _);
         }
@@ -142,8 +156,8 @@ impl<'a> Context2D<'a> {
         self.update_pen();
     }
 
-    pub fn set_line_style(&mut self, style: LineStyle<'a>) {
-        self.pen_data.line_style = style;
+    pub fn set_line_style(&mut self, style: LineStyle) {
+        self.pen_data.line_style = style.into();
         self.update_pen();
     }
 
@@ -172,30 +186,73 @@ impl<'a> Context2D<'a> {
 
 impl Context2D<'_> {
     fn update_brush(&mut self) {
-        self.brush = self.brush_data.create_by_ref();
+        let mut rect = unsafe { std::mem::zeroed::<RECT>() };
+        unsafe { GetClientRect(self.hwnd, &mut rect) };
+        let area = Sizei::new(
+            (rect.right - rect.left).max(0) as _,
+            (rect.bottom - rect.top).max(0) as _,
+        );
+        let brush = match self.brush_data {
+            GenBrush::Solid(_) | GenBrush::Hatch(..) => {
+                Rc::new(create_brush(&self.brush_data, area))
+            }
+            _ => self.cached_brush(area),
+        };
+        // 先选入新画刷，旧画刷在未被选中时才能被删除
         unsafe {
-            SelectObject(self.hdc, self.brush.handle() as _);
+            SelectObject(self.hdc, brush.handle() as _);
         }
+        self.brush = brush;
+    }
+
+    /// 从缓存中取出当前的渐变或图案画刷，未命中时绘制并加入缓存
+    fn cached_brush(&mut self, area: Sizei) -> Rc<Brush> {
+        let cache = &mut self.brush_cache;
+        let entry = match cache
+            .iter()
+            .position(|(data, size, _)| *size == area && *data == self.brush_data)
+        {
+            Some(index) => cache.remove(index),
+            None => {
+                if cache.len() >= BRUSH_CACHE_SIZE {
+                    cache.remove(0);
+                }
+                let brush = Rc::new(create_brush(&self.brush_data, area));
+                (self.brush_data.clone(), area, brush)
+            }
+        };
+        let brush = entry.2.clone();
+        cache.push(entry);
+        brush
     }
 
+    /// 设置画刷的颜色，渐变画刷被替换为纯色画刷
     pub fn set_brush_color(&mut self, color: Color) {
         self.brush_data = match self.brush_data {
-            GenBrush::Solid(_) => GenBrush::Solid(color),
             GenBrush::Hatch(_, style) => GenBrush::Hatch(color, style),
+            _ => GenBrush::Solid(color),
         };
         self.update_brush();
     }
 
+    /// 设置画刷的阴影线样式，渐变画刷以其第一个色标的颜色绘制阴影线
     pub fn set_brush_hatch(&mut self, style: HatchStyle) {
-        self.brush_data = match self.brush_data {
-            GenBrush::Solid(color) => GenBrush::Hatch(color, style),
-            GenBrush::Hatch(color, _) => GenBrush::Hatch(color, style),
-        };
+        self.brush_data = GenBrush::Hatch(self.brush_data.color(), style);
         self.update_brush();
     }
 
+    /// 设置自 `start` 至 `end` 的线性渐变画刷
+    pub fn set_brush_linear_gradient(&mut self, start: Point, end: Point, gradient: Gradient) {
+        Canvas::set_brush_linear_gradient(self, start, end, gradient);
+    }
+
+    /// 设置以 `center` 为中心、`radius` 为半径的径向渐变画刷
+    pub fn set_brush_radial_gradient(&mut self, center: Point, radius: u32, gradient: Gradient) {
+        Canvas::set_brush_radial_gradient(self, center, radius, gradient);
+    }
+
     pub fn replace_brush(&mut self, brush: Brush) {
-        self.brush = brush;
+        self.brush = Rc::new(brush);
         unsafe {
             SelectObject(self.hdc, self.brush.handle() as _);
         }
@@ -204,7 +261,7 @@ impl Context2D<'_> {
 
 impl Context2D<'_> {
     fn update_font(&mut self) {
-        self.font = self.font_data.create_by_ref();
+        self.font = create_font(&self.font_data);
         unsafe {
             SelectObject(self.hdc, self.font.handle() as _);
         }
@@ -4,85 +4,43 @@ use winapi::um::wingdi::*;
 
 use crate::{SystemError, check_error};
 
-use ponsic_color::Color;
-
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
-pub enum LineStyle<'a> {
-    #[default]
-    Solid,
-    Dash,
-    Dot,
-    DashDot,
-    DashDotDot,
-    Null,
-    UserDef(&'a [u32]),
-}
-
-impl LineStyle<'_> {
-    fn to_sys_enum(self) -> u32 {
-        match self {
-            LineStyle::Solid => PS_SOLID,
-            LineStyle::Dash => PS_DASH,
-            LineStyle::Dot => PS_DOT,
-            LineStyle::DashDot => PS_DASHDOT,
-            LineStyle::DashDotDot => PS_DASHDOTDOT,
-            LineStyle::Null => PS_NULL,
-            LineStyle::UserDef(_) => 0,
-        }
+pub use ponsic_graphics::{
+    CapStyle, GenPen, GenPenBuf, JoinStyle, LineStyle, LineStyleBuf, PenStyle,
+};
+
+fn line_style_to_sys(style: LineStyle) -> u32 {
+    match style {
+        LineStyle::Solid => PS_SOLID,
+        LineStyle::Dash => PS_DASH,
+        LineStyle::Dot => PS_DOT,
+        LineStyle::DashDot => PS_DASHDOT,
+        LineStyle::DashDotDot => PS_DASHDOTDOT,
+        LineStyle::Null => PS_NULL,
+        LineStyle::UserDef(_) => PS_USERSTYLE,
     }
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
-pub enum JoinStyle {
-    Bevel,
-    #[default]
-    Miter,
-    Round,
-}
-
-impl JoinStyle {
-    fn to_sys_enum(self) -> u32 {
-        match self {
-            JoinStyle::Bevel => PS_JOIN_BEVEL,
-            JoinStyle::Miter => PS_JOIN_MITER,
-            JoinStyle::Round => PS_JOIN_ROUND,
-        }
+fn join_style_to_sys(style: JoinStyle) -> u32 {
+    match style {
+        JoinStyle::Bevel => PS_JOIN_BEVEL,
+        JoinStyle::Miter => PS_JOIN_MITER,
+        JoinStyle::Round => PS_JOIN_ROUND,
     }
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
-pub enum CapStyle {
-    Round,
-    Square,
-    #[default]
-    Flat,
-}
-
-impl CapStyle {
-    fn to_sys_enum(self) -> u32 {
-        match self {
-            CapStyle::Round => PS_ENDCAP_ROUND,
-            CapStyle::Square => PS_ENDCAP_SQUARE,
-            CapStyle::Flat => PS_ENDCAP_FLAT,
-        }
+fn cap_style_to_sys(style: CapStyle) -> u32 {
+    match style {
+        CapStyle::Round => PS_ENDCAP_ROUND,
+        CapStyle::Square => PS_ENDCAP_SQUARE,
+        CapStyle::Flat => PS_ENDCAP_FLAT,
     }
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
-pub enum PenStyle {
-    Null,
-    #[default]
-    Solid,
-    Hollow,
-}
-
-impl PenStyle {
-    fn to_sys_enum(self) -> u32 {
-        match self {
-            PenStyle::Null => BS_NULL,
-            PenStyle::Solid => BS_SOLID,
-            PenStyle::Hollow => BS_HOLLOW,
-        }
+fn pen_style_to_sys(style: PenStyle) -> u32 {
+    match style {
+        PenStyle::Null => BS_NULL,
+        PenStyle::Solid => BS_SOLID,
+        PenStyle::Hollow => BS_HOLLOW,
     }
 }
 
@@ -103,98 +61,39 @@ impl Pen {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct GenPen<'a> {
-    pub line_style: LineStyle<'a>,
-    pub join_style: JoinStyle,
-    pub cap_style: CapStyle,
-    pub pen_style: PenStyle,
-    pub width: usize,
-    pub color: Color,
-}
-
-impl Default for GenPen<'static> {
-    fn default() -> Self {
-        GenPen {
-            line_style: Default::default(),
-            join_style: Default::default(),
-            cap_style: Default::default(),
-            pen_style: Default::default(),
-            width: Default::default(),
-            color: Color::BLACK,
-        }
-    }
-}
-
-impl<'a> GenPen<'a> {
-    pub fn set_line_style(mut self, line_style: LineStyle<'a>) {
-        self.line_style = line_style;
+/// 由画笔描述创建 GDI 画笔
+impl From<GenPen<'_>> for Pen {
+    fn from(pen: GenPen<'_>) -> Self {
+        create_pen(&pen).unwrap()
     }
 }
 
-impl GenPen<'_> {
-    pub fn set_cap_style(mut self, cap_style: CapStyle) -> Self {
-        self.cap_style = cap_style;
-        self
-    }
-    pub fn set_join_style(mut self, join_style: JoinStyle) -> Self {
-        self.join_style = join_style;
-        self
-    }
-    pub fn set_pen_style(mut self, pen_style: PenStyle) -> Self {
-        self.pen_style = pen_style;
-        self
-    }
-    pub fn set_width(mut self, width: usize) -> Self {
-        self.width = width;
-        self
-    }
-    pub fn set_color(mut self, color: Color) -> Self {
-        self.color = color;
-        self
-    }
-
-    pub fn create(self) -> Pen {
-        self.create_by_ref().unwrap()
-    }
-
-    pub(crate) fn create_by_ref(&self) -> Result<Pen, SystemError> {
-        let brush = LOGBRUSH {
-            lbStyle: self.pen_style.to_sys_enum(),
-            lbColor: self.color.into(),
-            lbHatch: BS_SOLID as _,
-        };
-        let hpen = if let LineStyle::UserDef(array) = self.line_style {
-            unsafe {
-                ExtCreatePen(
-                    PS_GEOMETRIC
-                        | self.line_style.to_sys_enum()
-                        | self.join_style.to_sys_enum()
-                        | self.cap_style.to_sys_enum(),
-                    self.width.max(1) as _,
-                    &brush as _,
-                    array.len() as _,
-                    array.as_ptr() as _,
-                )
-            }
-        } else {
-            unsafe {
-                ExtCreatePen(
-                    PS_GEOMETRIC
-                        | self.line_style.to_sys_enum()
-                        | self.join_style.to_sys_enum()
-                        | self.cap_style.to_sys_enum(),
-                    self.width.max(1) as _,
-                    &brush as _,
-                    0,
-                    null(),
-                )
-            }
-        };
-
-        if hpen.is_null() {
-            check_error()?;
+pub(crate) fn create_pen(pen: &GenPen) -> Result<Pen, SystemError> {
+    let brush = LOGBRUSH {
+        lbStyle: pen_style_to_sys(pen.pen_style),
+        lbColor: pen.color.into(),
+        lbHatch: BS_SOLID as _,
+    };
+    let style = PS_GEOMETRIC
+        | line_style_to_sys(pen.line_style)
+        | join_style_to_sys(pen.join_style)
+        | cap_style_to_sys(pen.cap_style);
+    let hpen = if let LineStyle::UserDef(array) = pen.line_style {
+        unsafe {
+            ExtCreatePen(
+                style,
+                pen.width.max(1) as _,
+                &brush as _,
+                array.len() as _,
+                array.as_ptr() as _,
+            )
         }
-        Ok(Pen { pen: hpen })
+    } else {
+        unsafe { ExtCreatePen(style, pen.width.max(1) as _, &brush as _, 0, null()) }
+    };
+
+    if hpen.is_null() {
+        check_error()?;
     }
+    Ok(Pen { pen: hpen })
 }
@@ -3,6 +3,10 @@ use winapi::{
     um::{wingdi::*, winuser::*},
 };
 
+pub use ponsic_graphics::{
+    CharSet, ClipPrecision, FontFamily, FontWeight, GenFont, OutPrecision, Pitch, Quality,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DrawTextMode {
     Bottom,
@@ -77,258 +81,91 @@ impl Font {
     }
 }
 
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum FontWeight {
-    Dontcare = 0,
-    Thin = 100,
-    ExtraLight = 200,
-    Light = 300,
-    #[default]
-    Normal = 400,
-    Medium = 500,
-    SemiBold = 600,
-    Bold = 700,
-    ExtraBold = 800,
-    Heavy = 900,
-}
-
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum CharSet {
-    Ansi = ANSI_CHARSET,
-    Baltic = BALTIC_CHARSET,
-    ChineseBig5 = CHINESEBIG5_CHARSET,
-    #[default]
-    Default = DEFAULT_CHARSET,
-    Easteurope = EASTEUROPE_CHARSET,
-    Gb2312 = GB2312_CHARSET,
-    Greek = GREEK_CHARSET,
-    Hangul = HANGUL_CHARSET,
-    Mac = MAC_CHARSET,
-    Oem = OEM_CHARSET,
-    Russian = RUSSIAN_CHARSET,
-    Shiftjis = SHIFTJIS_CHARSET,
-    Symbol = SYMBOL_CHARSET,
-    Turkish = TURKISH_CHARSET,
-    Vietnamese = VIETNAMESE_CHARSET,
-    Johab = JOHAB_CHARSET,
-    Arabic = ARABIC_CHARSET,
-    Hebrew = HEBREW_CHARSET,
-    Thai = THAI_CHARSET,
-}
-
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum OutPrecision {
-    Character = OUT_CHARACTER_PRECIS,
-    #[default]
-    Default = OUT_DEFAULT_PRECIS,
-    Device = OUT_DEVICE_PRECIS,
-    Outline = OUT_OUTLINE_PRECIS,
-    PostScriptOnly = OUT_PS_ONLY_PRECIS,
-    Raster = OUT_RASTER_PRECIS,
-    String = OUT_STRING_PRECIS,
-    Stroke = OUT_STROKE_PRECIS,
-    TrueTypeOnly = OUT_TT_ONLY_PRECIS,
-    TrueType = OUT_TT_PRECIS,
-}
-
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum ClipPrecision {
-    Character = CLIP_CHARACTER_PRECIS,
-    #[default]
-    Default = CLIP_DEFAULT_PRECIS,
-    DfaDisable = CLIP_DFA_DISABLE,
-    Embedded = CLIP_EMBEDDED,
-    LhAngles = CLIP_LH_ANGLES,
-    Mask = CLIP_MASK,
-    Stroke = CLIP_STROKE_PRECIS,
-    TtAlways = CLIP_TT_ALWAYS,
-}
-
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum Quality {
-    Antialiased = ANTIALIASED_QUALITY,
-    Cleartype = CLEARTYPE_QUALITY,
-    #[default]
-    Default = DEFAULT_QUALITY,
-    Draft = DRAFT_QUALITY,
-    NonAntialiased = NONANTIALIASED_QUALITY,
-    Proof = PROOF_QUALITY,
-}
-
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum Pitch {
-    #[default]
-    Default = DEFAULT_PITCH,
-    Fixed = FIXED_PITCH,
-    Variable = VARIABLE_PITCH,
-}
-
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum FontFamily {
-    Decorative = FF_DECORATIVE,
-    #[default]
-    Dontcare = FF_DONTCARE,
-    Modern = FF_MODERN,
-    Roman = FF_ROMAN,
-    Script = FF_SCRIPT,
-    Swiss = FF_SWISS,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct GenFont {
-    pub height: i32,
-    pub widht: i32,
-    pub escapement: i32,
-    pub orientation: i32,
-    pub weight: FontWeight,
-    pub italic: bool,
-    pub underline: bool,
-    pub strikeout: bool,
-    pub charset: CharSet,
-    pub outprecision: OutPrecision,
-    pub clipprecision: ClipPrecision,
-    pub quality: Quality,
-    pub pitch: Pitch,
-    pub family: FontFamily,
-    pub name: String,
-}
-
-impl Default for GenFont {
-    fn default() -> Self {
-        Self {
-            height: 0,
-            widht: 0,
-            escapement: 0,
-            orientation: 0,
-            weight: FontWeight::default(),
-            italic: false,
-            underline: false,
-            strikeout: false,
-            charset: CharSet::default(),
-            outprecision: OutPrecision::default(),
-            clipprecision: ClipPrecision::default(),
-            quality: Quality::default(),
-            pitch: Pitch::default(),
-            family: FontFamily::default(),
-            name: String::default(),
-        }
-    }
-}
-
-impl GenFont {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub fn set_height(mut self, height: i32) -> Self {
-        self.height = height;
-        self
-    }
-
-    pub fn set_width(mut self, width: i32) -> Self {
-        self.widht = width;
-        self
-    }
-
-    pub fn set_escapement(mut self, escapement: i32) -> Self {
-        self.escapement = escapement;
-        self
-    }
-
-    pub fn set_orientation(mut self, orientation: i32) -> Self {
-        self.orientation = orientation;
-        self
-    }
-
-    pub fn set_weight(mut self, weight: FontWeight) -> Self {
-        self.weight = weight;
-        self
-    }
-
-    pub fn set_italic(mut self, italic: bool) -> Self {
-        self.italic = italic;
-        self
-    }
-
-    pub fn set_underline(mut self, underline: bool) -> Self {
-        self.underline = underline;
-        self
-    }
-
-    pub fn set_strikeout(mut self, strikeout: bool) -> Self {
-        self.strikeout = strikeout;
-        self
-    }
-
-    pub fn set_charset(mut self, charset: CharSet) -> Self {
-        self.charset = charset;
-        self
-    }
-
-    pub fn set_outprecision(mut self, outprecision: OutPrecision) -> Self {
-        self.outprecision = outprecision;
-        self
-    }
-
-    pub fn set_clipprecision(mut self, clipprecision: ClipPrecision) -> Self {
-        self.clipprecision = clipprecision;
-        self
-    }
-
-    pub fn set_quality(mut self, quality: Quality) -> Self {
-        self.quality = quality;
-        self
-    }
-
-    pub fn set_pitch(mut self, pitch: Pitch) -> Self {
-        self.pitch = pitch;
-        self
-    }
-
-    pub fn set_family(mut self, family: FontFamily) -> Self {
-        self.family = family;
-        self
-    }
-
-    pub fn set_name(mut self, name: String) -> Self {
-        self.name = name;
-        self
-    }
+// 字体描述中各枚举的取值须与 GDI 常量一致
+const _: () = {
+    assert!(CharSet::Ansi as u32 == ANSI_CHARSET);
+    assert!(CharSet::Baltic as u32 == BALTIC_CHARSET);
+    assert!(CharSet::ChineseBig5 as u32 == CHINESEBIG5_CHARSET);
+    assert!(CharSet::Default as u32 == DEFAULT_CHARSET);
+    assert!(CharSet::Easteurope as u32 == EASTEUROPE_CHARSET);
+    assert!(CharSet::Gb2312 as u32 == GB2312_CHARSET);
+    assert!(CharSet::Greek as u32 == GREEK_CHARSET);
+    assert!(CharSet::Hangul as u32 == HANGUL_CHARSET);
+    assert!(CharSet::Mac as u32 == MAC_CHARSET);
+    assert!(CharSet::Oem as u32 == OEM_CHARSET);
+    assert!(CharSet::Russian as u32 == RUSSIAN_CHARSET);
+    assert!(CharSet::Shiftjis as u32 == SHIFTJIS_CHARSET);
+    assert!(CharSet::Symbol as u32 == SYMBOL_CHARSET);
+    assert!(CharSet::Turkish as u32 == TURKISH_CHARSET);
+    assert!(CharSet::Vietnamese as u32 == VIETNAMESE_CHARSET);
+    assert!(CharSet::Johab as u32 == JOHAB_CHARSET);
+    assert!(CharSet::Arabic as u32 == ARABIC_CHARSET);
+    assert!(CharSet::Hebrew as u32 == HEBREW_CHARSET);
+    assert!(CharSet::Thai as u32 == THAI_CHARSET);
+    assert!(OutPrecision::Character as u32 == OUT_CHARACTER_PRECIS);
+    assert!(OutPrecision::Default as u32 == OUT_DEFAULT_PRECIS);
+    assert!(OutPrecision::Device as u32 == OUT_DEVICE_PRECIS);
+    assert!(OutPrecision::Outline as u32 == OUT_OUTLINE_PRECIS);
+    assert!(OutPrecision::PostScriptOnly as u32 == OUT_PS_ONLY_PRECIS);
+    assert!(OutPrecision::Raster as u32 == OUT_RASTER_PRECIS);
+    assert!(OutPrecision::String as u32 == OUT_STRING_PRECIS);
+    assert!(OutPrecision::Stroke as u32 == OUT_STROKE_PRECIS);
+    assert!(OutPrecision::TrueTypeOnly as u32 == OUT_TT_ONLY_PRECIS);
+    assert!(OutPrecision::TrueType as u32 == OUT_TT_PRECIS);
+    assert!(ClipPrecision::Character as u32 == CLIP_CHARACTER_PRECIS);
+    assert!(ClipPrecision::Default as u32 == CLIP_DEFAULT_PRECIS);
+    assert!(ClipPrecision::DfaDisable as u32 == CLIP_DFA_DISABLE);
+    assert!(ClipPrecision::Embedded as u32 == CLIP_EMBEDDED);
+    assert!(ClipPrecision::LhAngles as u32 == CLIP_LH_ANGLES);
+    assert!(ClipPrecision::Mask as u32 == CLIP_MASK);
+    assert!(ClipPrecision::Stroke as u32 == CLIP_STROKE_PRECIS);
+    assert!(ClipPrecision::TtAlways as u32 == CLIP_TT_ALWAYS);
+    assert!(Quality::Antialiased as u32 == ANTIALIASED_QUALITY);
+    assert!(Quality::Cleartype as u32 == CLEARTYPE_QUALITY);
+    assert!(Quality::Default as u32 == DEFAULT_QUALITY);
+    assert!(Quality::Draft as u32 == DRAFT_QUALITY);
+    assert!(Quality::NonAntialiased as u32 == NONANTIALIASED_QUALITY);
+    assert!(Quality::Proof as u32 == PROOF_QUALITY);
+    assert!(Pitch::Default as u32 == DEFAULT_PITCH);
+    assert!(Pitch::Fixed as u32 == FIXED_PITCH);
+    assert!(Pitch::Variable as u32 == VARIABLE_PITCH);
+    assert!(FontFamily::Decorative as u32 == FF_DECORATIVE);
+    assert!(FontFamily::Dontcare as u32 == FF_DONTCARE);
+    assert!(FontFamily::Modern as u32 == FF_MODERN);
+    assert!(FontFamily::Roman as u32 == FF_ROMAN);
+    assert!(FontFamily::Script as u32 == FF_SCRIPT);
+    assert!(FontFamily::Swiss as u32 == FF_SWISS);
+};
 
-    pub fn create(self) -> Font {
-        let ret = self.create_by_ref();
+/// 由字体描述创建 GDI 字体
+impl From<GenFont> for Font {
+    fn from(font: GenFont) -> Self {
+        let ret = create_font(&font);
         assert!(!ret.font.is_null());
         ret
     }
+}
 
-    pub(crate) fn create_by_ref(&self) -> Font {
-        let name: Vec<u16> = self.name.encode_utf16().chain(Some(0)).collect();
-        unsafe {
-            Font {
-                font: CreateFontW(
-                    self.height,
-                    self.widht,
-                    self.escapement,
-                    self.orientation,
-                    self.weight as _,
-                    self.italic as _,
-                    self.underline as _,
-                    self.strikeout as _,
-                    self.charset as _,
-                    self.outprecision as _,
-                    self.clipprecision as _,
-                    self.quality as _,
-                    self.pitch as u32 | self.family as u32,
-                    name.as_ptr() as _,
-                ),
-            }
+pub(crate) fn create_font(font: &GenFont) -> Font {
+    let name: Vec<u16> = font.name.encode_utf16().chain(Some(0)).collect();
+    unsafe {
+        Font {
+            font: CreateFontW(
+                font.height,
+                font.widht,
+                font.escapement,
+                font.orientation,
+                font.weight as _,
+                font.italic as _,
+                font.underline as _,
+                font.strikeout as _,
+                font.charset as _,
+                font.outprecision as _,
+                font.clipprecision as _,
+                font.quality as _,
+                font.pitch as u32 | font.family as u32,
+                name.as_ptr() as _,
+            ),
         }
     }
 }
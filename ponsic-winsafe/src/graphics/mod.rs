@@ -2,3 +2,4 @@ pub mod context;
 pub mod context_2d;
 
 pub use context::*;
+pub use ponsic_graphics::*;
@@ -1,4 +1,4 @@
-use ponsic_types::Recti;
+use ponsic_types::{Recti, Region};
 use std::fmt::Debug;
 use std::ptr::{null, null_mut};
 use winapi::shared::windef::*;
@@ -219,6 +219,24 @@ pub trait WindowManager {
         }
     }
 
+    /// 指示窗口客户区中的指定区域应该被重新绘制，区域坐标相对于客户区
+    ///
+    /// # Note
+    /// 与 `redraw` 不同，区域外已绘制的部分不会被重新绘制
+    fn redraw_region(&self, region: &Region) {
+        let handle = self.get_handle() as HWND;
+        for rect in region {
+            let rect = RECT {
+                left: rect.left(),
+                top: rect.top(),
+                right: rect.right(),
+                bottom: rect.bottom(),
+            };
+            unsafe { RedrawWindow(handle, &rect, null_mut(), RDW_INVALIDATE) };
+        }
+        unsafe { RedrawWindow(handle, null(), null_mut(), RDW_UPDATENOW) };
+    }
+
     /// 获取窗口所在的矩形区域坐标
     fn get_rect(&self) -> Recti {
         let mut rect = unsafe { std::mem::zeroed::<RECT>() };
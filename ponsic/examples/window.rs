@@ -8,7 +8,9 @@ use ponsic::{
 #[cfg(not(target_os = "windows"))]
 fn main() {}
 
+#[cfg(target_os = "windows")]
 struct MyWindow {}
+#[cfg(target_os = "windows")]
 impl Proc for MyWindow {
     fn handle(&mut self, Events { event, .. }: Events) -> Return {
         if let Event::Window(WindowEvent::Destroy) = event {
@@ -1,4 +1,7 @@
-use ponsic_winsafe::{graphics::Context, *};
+use ponsic_winsafe::{
+    graphics::{Canvas, Context, context_2d::Context2D},
+    *,
+};
 use std::{
     any::type_name,
     ops::{Deref, DerefMut},
@@ -21,7 +24,18 @@ fn window_proc(events: Events, mut data: The<WindowData>) -> Return {
 
 pub trait Proc {
     fn handle(&mut self, events: Events) -> Return;
-    fn draw(&mut self, context: Context);
+
+    /// 绘制窗口内容，默认在 [`Context2D`] 上调用 [`Proc::paint`]
+    fn draw(&mut self, context: Context) {
+        let mut context = Context2D::new(context);
+        self.paint(&mut context);
+    }
+
+    /// 在任意绘图表面上绘制窗口内容
+    ///
+    /// # Note
+    /// 此方法不依赖窗口，可借助 [`Snapshot`](ponsic_winsafe::graphics::Snapshot) 在离屏表面上测试
+    fn paint(&mut self, _canvas: &mut dyn Canvas) {}
 }
 
 pub struct WindowData {
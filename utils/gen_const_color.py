@@ -35,4 +35,9 @@ with open(const_file, "w", encoding="utf-8") as f:
         f.write(
             f"    pub const {name.upper()}: Self = Self::new(0x{hex_code[0:2]}, 0x{hex_code[2:4]}, 0x{hex_code[4:]});\n"
         )
-    f.write("}\n")
+    f.write("}\n\n")
+    f.write("/// CSS 颜色名称及其对应的颜色，按 CSS 规范中的顺序排列\n")
+    f.write("pub const NAMED_COLORS: &[(&str, Color)] = &[\n")
+    for name in result:
+        f.write(f'    ("{name}", Color::{name.upper()}),\n')
+    f.write("];\n")